@@ -0,0 +1,117 @@
+//! Every day in one binary, or only those picked with cargo features: `day1` to `day25` and
+//! `day7-2`, all on by default.
+//!
+//! ```text
+//! advent22 <day> [input] [-v | --log <filter>]
+//! ```
+//!
+//! The arguments after the day are those of the day's own binary.
+
+use aoc_common::input;
+use aoc_common::options::Mode;
+use std::env;
+use std::process;
+
+struct Day {
+    /// Name used on the command line, e.g. `16` or `7-2`.
+    name: &'static str,
+    /// Stem of the puzzle input file under `input/`.
+    input: &'static str,
+    /// Takes the day's own options out of the arguments, and picks what they ask for.
+    mode: fn(&mut Vec<String>) -> Result<Mode, String>,
+}
+
+macro_rules! day {
+    ($feature:literal, $name:literal, $input:literal, $krate:ident) => {
+        day!($feature, $name, $input, |_| {
+            Ok(Mode::Solve(Box::new($krate::solve)))
+        })
+    };
+    ($feature:literal, $name:literal, $input:literal, $mode:expr) => {
+        #[cfg(feature = $feature)]
+        Day {
+            name: $name,
+            input: $input,
+            mode: $mode,
+        }
+    };
+}
+
+// the days built in, in order
+const DAYS: &[Day] = &[
+    day!("day1", "1", "day1", day1::mode),
+    day!("day2", "2", "day2", day2::mode),
+    day!("day3", "3", "day3", day3::mode),
+    day!("day4", "4", "day4", day4),
+    day!("day5", "5", "day5", day5),
+    day!("day6", "6", "day6", day6),
+    day!("day7", "7", "day7", day7),
+    day!("day7-2", "7-2", "day7", day7_2),
+    day!("day8", "8", "day8", day8),
+    day!("day9", "9", "day9", day9),
+    day!("day10", "10", "day10", day10),
+    day!("day11", "11", "day11", day11),
+    day!("day12", "12", "day12", day12),
+    day!("day13", "13", "day13", day13),
+    day!("day14", "14", "day14", day14),
+    day!("day15", "15", "day15", day15),
+    day!("day16", "16", "day16", day16),
+    day!("day17", "17", "day17", day17),
+    day!("day18", "18", "day18", day18),
+    day!("day19", "19", "day19", day19),
+    day!("day20", "20", "day20", day20),
+    day!("day21", "21", "day21", day21),
+    day!("day22", "22", "day22", day22),
+    day!("day23", "23", "day23", day23),
+    day!("day24", "24", "day24", day24),
+    day!("day25", "25", "day25", day25),
+];
+
+// whether `name` is a day of the calendar, built in or not
+fn exists(name: &str) -> bool {
+    name == "7-2" || name.parse().is_ok_and(|day: u32| (1..=25).contains(&day))
+}
+
+fn names() -> String {
+    let names: Vec<_> = DAYS.iter().map(|day| day.name).collect();
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args[0].starts_with('-') {
+        eprintln!("usage: advent22 <day> [input] [-v | --log <filter>]");
+        eprintln!("days built in: {}", names());
+        process::exit(2);
+    }
+    let name = args.remove(0);
+    let Some(day) = DAYS.iter().find(|day| day.name == name) else {
+        if exists(&name) {
+            eprintln!("error: day {name} is not built in, build with `--features day{name}`");
+        } else {
+            eprintln!("error: unknown day `{name}`");
+        }
+        eprintln!("days built in: {}", names());
+        process::exit(2);
+    };
+    input::run_with_args(day.input, day.mode, args);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days() {
+        assert!(exists("7-2") && exists("25"));
+        assert!(!exists("0") && !exists("26") && !exists("7-3"));
+        for (i, day) in DAYS.iter().enumerate() {
+            assert!(exists(day.name), "day {}", day.name);
+            assert!(DAYS[..i].iter().all(|other| other.name != day.name));
+        }
+    }
+}
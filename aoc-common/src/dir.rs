@@ -0,0 +1,118 @@
+use crate::Point2;
+
+/// One of the four orthogonal directions. `N` points towards decreasing `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir4 {
+    /// Clockwise, starting from `N`.
+    pub const ALL: [Dir4; 4] = [Dir4::N, Dir4::E, Dir4::S, Dir4::W];
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Dir4::N => Point2::new(0, -1),
+            Dir4::E => Point2::new(1, 0),
+            Dir4::S => Point2::new(0, 1),
+            Dir4::W => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions. `N` points towards decreasing `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// Clockwise, starting from `N`.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Dir8::N => Point2::new(0, -1),
+            Dir8::NE => Point2::new(1, -1),
+            Dir8::E => Point2::new(1, 0),
+            Dir8::SE => Point2::new(1, 1),
+            Dir8::S => Point2::new(0, 1),
+            Dir8::SW => Point2::new(-1, 1),
+            Dir8::W => Point2::new(-1, 0),
+            Dir8::NW => Point2::new(-1, -1),
+        }
+    }
+
+    // 45 degrees clockwise
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    // 45 degrees counterclockwise
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(d: Dir4) -> Self {
+        Dir8::ALL[d as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::N.turn_right(), Dir4::E);
+        assert_eq!(Dir4::N.turn_left(), Dir4::W);
+        assert_eq!(Dir4::W.turn_right(), Dir4::N);
+        assert_eq!(Dir4::E.reverse(), Dir4::W);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::NW.turn_right(), Dir8::N);
+        assert_eq!(Dir8::SE.reverse(), Dir8::NW);
+        assert_eq!(Dir8::from(Dir4::S), Dir8::S);
+        for d in Dir4::ALL {
+            assert_eq!(d.offset(), Dir8::from(d).offset());
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.offset() + d.reverse().offset(), Point2::ORIGIN);
+        }
+    }
+}
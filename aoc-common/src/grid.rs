@@ -0,0 +1,266 @@
+use crate::{parse, ParseError, Point2};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row. Points outside of it are simply absent: `get` returns
+/// `None` and the neighbor iterators skip them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "ragged rows");
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// One row per line, one cell per char. Lines shorter than the longest one are padded with
+    /// `f(' ')`, which is how the puzzle inputs leave out the empty space on the right.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let width = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut cells = vec![];
+        let mut height = 0;
+        for line in input.lines() {
+            let mut len = 0;
+            for c in line.chars() {
+                cells.push(f(c));
+                len += 1;
+            }
+            for _ in len..width {
+                cells.push(f(' '));
+            }
+            height += 1;
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Like `parse`, but fails at the first char `f` rejects, which includes the padding of
+    /// short lines unless `f` accepts `' '`.
+    pub fn try_parse(
+        input: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut cells = vec![];
+        let mut height = 0;
+        for line in parse::lines(input) {
+            let mut len = 0;
+            for (i, c) in line.text.char_indices() {
+                cells.push(f(c).ok_or_else(|| line.error(&line.text[i..], expected))?);
+                len += 1;
+            }
+            for _ in len..width {
+                cells.push(f(' ').ok_or_else(|| line.error(line.end(), expected))?);
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    pub fn get(&self, p: Point2) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.y as usize * self.width + p.x as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point2) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y as usize * self.width + p.x as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks_exact(0) panics, an empty grid has no rows anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// All points of the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2::from((x, y))))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Point2> {
+        self.iter().find(|(_, t)| pred(t)).map(|(p, _)| p)
+    }
+
+    /// The orthogonal neighbors of `p` that lie inside the grid.
+    pub fn neighbors4(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        p.neighbors4().into_iter().filter(|q| self.contains(*q))
+    }
+
+    /// The orthogonal and diagonal neighbors of `p` that lie inside the grid.
+    pub fn neighbors8(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        p.neighbors8().into_iter().filter(|q| self.contains(*q))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            for y in 0..self.height {
+                cells.push(self.cells[y * self.width + x].clone());
+            }
+        }
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// The inverse of `parse`: one line per row, without a trailing newline.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                s.push('\n');
+            }
+            s.extend(row.iter().map(&mut f));
+        }
+        s
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+    fn index(&self, p: Point2) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, p: Point2) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside of the grid"))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width, "x = {x} is outside of the grid");
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width, "x = {x} is outside of the grid");
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_render() {
+        let grid = Grid::parse("ab\ncde\n", |c| c);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], ' ');
+        assert_eq!(grid[Point2::new(2, 1)], 'e');
+        assert_eq!(grid.to_string(), "ab \ncde");
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let digits = |c: char| c.to_digit(10);
+        let grid = Grid::try_parse("12\n34", "a digit", digits).unwrap();
+        assert_eq!(grid[(1, 1)], 4);
+        let err = Grid::try_parse("12\n3x", "a digit", digits).unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, "a digit"));
+        let err = Grid::try_parse("12\n3", "a digit", digits).unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, "a digit"));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let grid = Grid::new(3, 2, 0u8);
+        assert_eq!(grid.get(Point2::new(-1, 0)), None);
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.get(Point2::new(2, 1)), Some(&0));
+        assert_eq!(grid.neighbors4(Point2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8(Point2::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point2::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = Grid::parse("abc\ndef", |c| c);
+        let t = grid.transpose();
+        assert_eq!(t.to_string(), "ad\nbe\ncf");
+        assert_eq!(t.transpose(), grid);
+    }
+}
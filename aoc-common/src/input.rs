@@ -0,0 +1,163 @@
+//! Where a puzzle input comes from: a file, stdin, or the day's default file under `input/` at the
+//! root of the workspace. Inputs compressed with gzip are decompressed as they are read, and
+//! inputs saved with other line endings or a byte order mark are normalized.
+
+use flate2::read::GzDecoder;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::options::Mode;
+use crate::{logger, parse, Answer, ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    /// The source named by a command line argument: `-` for stdin, anything else a path. Without
+    /// an argument, the default input of the day whose file stem is `stem`, e.g. `day11`.
+    pub fn new(arg: Option<&str>, stem: &str) -> Source {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+            None => Source::Path(default_path(stem)),
+        }
+    }
+
+    /// Reads the whole input, decompressing it if it starts with the gzip magic number, and
+    /// [normalizes](parse::normalize) it.
+    pub fn read(&self) -> io::Result<String> {
+        let mut bytes = vec![];
+        match self {
+            Source::Stdin => io::stdin().lock().read_to_end(&mut bytes)?,
+            Source::Path(path) => fs::File::open(path)?.read_to_end(&mut bytes)?,
+        };
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut decompressed = vec![];
+            GzDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed)?;
+            bytes = decompressed;
+        }
+        let text =
+            String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(parse::normalize(&text).into_owned())
+    }
+}
+
+/// `<stdin>` or the path, as used in front of error messages.
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => f.write_str("<stdin>"),
+            Source::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// `input/{stem}.txt` at the root of the workspace, or `input/{stem}.txt.gz` if only the
+/// compressed input is there.
+pub fn default_path(stem: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("input");
+    let plain = dir.join(format!("{stem}.txt"));
+    let gz = dir.join(format!("{stem}.txt.gz"));
+    if !plain.exists() && gz.exists() {
+        gz
+    } else {
+        plain
+    }
+}
+
+/// The `main` of a day's binary: solves the input named by the first argument (see
+/// [`Source::new`]) and prints both parts, or reports the error and exits with status 1. Logging
+/// is set up from `-v` and `--log` (see [`logger::extract_args`]).
+pub fn run(stem: &str, solve: fn(&str) -> Result<(Answer, Answer), ParseError>) {
+    run_with(stem, |_| Ok(Mode::Solve(Box::new(solve))));
+}
+
+/// [`run`] for a day with options of its own: `mode` takes them out of the arguments (see
+/// [`options`](crate::options)) and returns what they ask for, or an error that exits with status
+/// 2.
+pub fn run_with(stem: &str, mode: impl FnOnce(&mut Vec<String>) -> Result<Mode, String>) {
+    run_with_args(stem, mode, std::env::args().skip(1).collect());
+}
+
+/// [`run_with`] with the given arguments instead of those of the process, for binaries that take
+/// some of their own first.
+pub fn run_with_args(
+    stem: &str,
+    mode: impl FnOnce(&mut Vec<String>) -> Result<Mode, String>,
+    mut args: Vec<String>,
+) {
+    let mode = logger::extract_args(&mut args)
+        .map(logger::init)
+        .and_then(|()| mode(&mut args))
+        .unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            process::exit(2);
+        });
+    let source = Source::new(args.first().map(String::as_str), stem);
+    let input = source.read().unwrap_or_else(|e| {
+        eprintln!("{source}: {e}");
+        process::exit(1);
+    });
+    let done = match mode {
+        Mode::Solve(solve) => solve(&input).map(|(part1, part2)| {
+            println!("{part1}");
+            println!("{part2}");
+        }),
+        Mode::Report(report) => report(&input).map(|report| print!("{report}")),
+    };
+    if let Err(e) = done {
+        eprintln!("{source}:{e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    #[test]
+    fn test_source_new() {
+        assert_eq!(Source::new(Some("-"), "day1"), Source::Stdin);
+        assert_eq!(
+            Source::new(Some("in.txt"), "day1"),
+            Source::Path("in.txt".into())
+        );
+        let Source::Path(default) = Source::new(None, "day7") else {
+            panic!("the default input is a file");
+        };
+        assert!(default.ends_with("input/day7.txt"));
+    }
+
+    #[test]
+    fn test_read_gzip() {
+        let path = std::env::temp_dir().join(format!("aoc-input-{}.txt.gz", process::id()));
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(b"1000\n2000\n").unwrap();
+        fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let read = Source::Path(path.clone()).read();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(read.unwrap(), "1000\n2000\n");
+    }
+
+    #[test]
+    fn test_read_normalizes() {
+        let path = std::env::temp_dir().join(format!("aoc-input-{}.txt", process::id()));
+        fs::write(&path, "\u{feff}1000\r\n\r\n2000").unwrap();
+        let read = Source::Path(path.clone()).read();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(read.unwrap(), "1000\n\n2000\n");
+    }
+}
@@ -0,0 +1,14 @@
+mod answer;
+mod dir;
+mod grid;
+pub mod input;
+pub mod logger;
+pub mod options;
+pub mod parse;
+mod point;
+
+pub use answer::Answer;
+pub use dir::{Dir4, Dir8};
+pub use grid::Grid;
+pub use parse::{Line, ParseError};
+pub use point::{Point2, Point3};
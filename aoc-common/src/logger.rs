@@ -0,0 +1,170 @@
+//! Diagnostics go through the `log` macros to stderr, so that stdout only carries answers.
+//!
+//! Every day logs under its crate name as the target, so a [`Filter`] can raise the level of one
+//! day without drowning it in the others. By convention `info` reports progress, `debug` the steps
+//! of an algorithm (day 17 finding a cycle, day 21 undoing an operation) and `trace` draws the
+//! state of a puzzle (the chamber of day 17, the elves of day 23, the valley of day 24).
+
+use log::{LevelFilter, Log, Metadata, Record};
+use std::str::FromStr;
+
+/// The most verbose level to log, for every target or for targets starting with a prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: LevelFilter,
+    // the longest matching prefix wins
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::verbosity(0)
+    }
+}
+
+impl Filter {
+    /// `info` for no `-v`, `debug` for `-v` and `trace` for `-vv` or more.
+    pub fn verbosity(count: usize) -> Filter {
+        let default = match count {
+            0 => LevelFilter::Info,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        };
+        Filter {
+            default,
+            targets: vec![],
+        }
+    }
+
+    fn level(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| {
+                target == prefix
+                    || target
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, |a, b| a.max(b))
+    }
+}
+
+/// Parses a comma-separated list of `level` or `target=level`, e.g. `warn,day17=trace`.
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+        for directive in s.split(',').filter(|d| !d.is_empty()) {
+            let level = |s: &str| {
+                s.parse::<LevelFilter>()
+                    .map_err(|_| format!("unknown log level `{s}`"))
+            };
+            match directive.split_once('=') {
+                Some((target, l)) => filter.targets.push((target.to_string(), level(l)?)),
+                None => filter.default = level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+struct Stderr {
+    filter: Filter,
+}
+
+impl Log for Stderr {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level(metadata.target())
+    }
+
+    // prefixed with the target, which is the crate of the day that logged it
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the stderr logger. Does nothing if a logger is already installed.
+pub fn init(filter: Filter) {
+    let max_level = filter.max_level();
+    // the logger lives for the rest of the program
+    let logger = Box::leak(Box::new(Stderr { filter }));
+    if log::set_logger(logger).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+/// Takes `-v`, `-vv` (or more) and `--log <filter>` out of the command line arguments, and
+/// returns the filter they describe.
+pub fn extract_args(args: &mut Vec<String>) -> Result<Filter, String> {
+    let mut verbosity = 0;
+    let mut spec = None;
+    let mut i = 0;
+    while i < args.len() {
+        let arg = &args[i];
+        if arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v') {
+            verbosity += arg.len() - 1;
+            args.remove(i);
+        } else if arg == "--log" {
+            args.remove(i);
+            if i == args.len() {
+                return Err("--log expects a filter such as `day17=debug`".to_string());
+            }
+            spec = Some(args.remove(i));
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut filter = match spec {
+        Some(spec) => spec.parse()?,
+        None => Filter::default(),
+    };
+    if verbosity > 0 {
+        filter.default = filter.default.max(Filter::verbosity(verbosity).default);
+    }
+    Ok(filter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter: Filter = "warn,day17=trace,day1=debug".parse().unwrap();
+        assert_eq!(filter.level("day17"), LevelFilter::Trace);
+        assert_eq!(filter.level("day17::chamber"), LevelFilter::Trace);
+        assert_eq!(filter.level("day1"), LevelFilter::Debug);
+        assert_eq!(filter.level("day19"), LevelFilter::Warn);
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+        assert!("day17=loud".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn test_extract_args() {
+        let mut args: Vec<String> = ["run", "-vv", "17", "--log", "day21=debug"]
+            .map(String::from)
+            .to_vec();
+        let filter = extract_args(&mut args).unwrap();
+        assert_eq!(args, ["run", "17"]);
+        assert_eq!(filter.level("day17"), LevelFilter::Trace);
+        assert_eq!(filter.level("day21"), LevelFilter::Debug);
+
+        let mut args = vec!["-".to_string()];
+        assert_eq!(extract_args(&mut args).unwrap(), Filter::default());
+        assert_eq!(args, ["-"]);
+    }
+}
@@ -0,0 +1,83 @@
+//! The options of a day's own on the command line, such as day 1's `--width`, taken out of the
+//! arguments before the input is read, for the day's binary and the `aoc` runner alike.
+
+use std::str::FromStr;
+
+use crate::{Answer, ParseError};
+
+/// Solves an input as a day's options ask, e.g. with rules read from a file they name.
+pub type Solver = Box<dyn Fn(&str) -> Result<(Answer, Answer), ParseError>>;
+
+/// Reports on an input instead of solving it, e.g. day 2's `--analyze`.
+pub type Reporter = Box<dyn Fn(&str) -> Result<String, ParseError>>;
+
+/// What a day's binary does with its input, as its options ask.
+pub enum Mode {
+    /// Prints the answers of both parts.
+    Solve(Solver),
+    /// Prints a report on the input instead of the answers.
+    Report(Reporter),
+}
+
+/// Takes `name <value>` out of the command line arguments, and returns the value parsed, None
+/// without it. If `name` is given more than once, the last value wins. A missing or invalid value
+/// fails with `{name} expects {expects}`, e.g. `--group-size expects a positive number`.
+pub fn extract<T: FromStr>(
+    args: &mut Vec<String>,
+    name: &str,
+    expects: &str,
+) -> Result<Option<T>, String> {
+    let mut value = None;
+    while let Some(i) = args.iter().position(|arg| arg == name) {
+        args.remove(i);
+        match args.get(i).map(|arg| arg.parse()) {
+            Some(Ok(parsed)) => {
+                args.remove(i);
+                value = Some(parsed);
+            }
+            _ => return Err(format!("{name} expects {expects}")),
+        }
+    }
+    Ok(value)
+}
+
+/// Takes every `name` out of the command line arguments, and returns whether there was one.
+pub fn extract_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let before = args.len();
+    args.retain(|arg| arg != name);
+    args.len() < before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::NonZeroUsize;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_extract() {
+        let mut rest = args("input.txt --size 4 -v --size 5");
+        let size: Option<usize> = extract(&mut rest, "--size", "a number").unwrap();
+        assert_eq!(size, Some(5));
+        assert_eq!(rest, ["input.txt", "-v"]);
+        assert_eq!(extract::<usize>(&mut rest, "--size", "a number"), Ok(None));
+
+        for line in ["--size", "--size 0", "--size -v"] {
+            assert_eq!(
+                extract::<NonZeroUsize>(&mut args(line), "--size", "a positive number"),
+                Err("--size expects a positive number".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_flag() {
+        let mut rest = args("--analyze - --analyze");
+        assert!(extract_flag(&mut rest, "--analyze"));
+        assert_eq!(rest, ["-"]);
+        assert!(!extract_flag(&mut rest, "--analyze"));
+    }
+}
@@ -0,0 +1,163 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input. Lines and columns count from 1, columns in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// The input ended before something that was `expected`.
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(input.lines().count() + 1, 1, expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of the input that knows where it is, so parsers can point at the offending token.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Line { number, text }
+    }
+
+    /// The column at which `token` starts. Tokens that are not a slice of this line are placed
+    /// right after its end.
+    pub fn column(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let at = token.as_ptr() as usize;
+        if (start..=start + self.text.len()).contains(&at) {
+            at - start + 1
+        } else {
+            self.text.len() + 1
+        }
+    }
+
+    /// The empty slice at the end of the line, for reporting missing tokens.
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column(token), expected)
+    }
+
+    /// Unwraps a token that may be missing, e.g. the result of `split_once` or `nth`.
+    pub fn require<T>(&self, token: Option<T>, expected: &str) -> Result<T, ParseError> {
+        token.ok_or_else(|| self.error(self.end(), expected))
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Strips a literal prefix from `token`, which must be a slice of this line.
+    pub fn literal(&self, token: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        token
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(token, format!("'{prefix}'")))
+    }
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// The input as the parsers expect it, however it was saved: without a byte order mark, with
+/// `\n` line endings, without whitespace at the end of its lines or blank lines at its end, and
+/// ending with a newline. Borrows `input` when it is like that already.
+///
+/// Lines keep their numbers, so that errors point at the same place in the original.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let text = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut out = String::with_capacity(text.len() + 1);
+    // `lines` drops the `\r` of `\r\n`, and `trim_end` any other whitespace
+    for line in text.lines().map(str::trim_end) {
+        out.push_str(line);
+        out.push('\n');
+    }
+    let len = out.trim_end_matches('\n').len();
+    out.truncate(len);
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    if out == input {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert!(matches!(normalize("1\n\n2\n"), Cow::Borrowed("1\n\n2\n")));
+        assert_eq!(normalize("\u{feff}1\r\n\r\n2\r\n"), "1\n\n2\n");
+        assert_eq!(normalize("    [D] \t\n 1   2 \n"), "    [D]\n 1   2\n");
+        assert_eq!(normalize("10R5L\n\n  \n"), "10R5L\n");
+        assert_eq!(normalize("no newline"), "no newline\n");
+        assert_eq!(normalize("\n \r\n"), "");
+    }
+
+    #[test]
+    fn test_line_errors() {
+        let line = Line::new(3, "  Operation: new = old ^ 5");
+        let (_, op) = line.text.split_once("= ").unwrap();
+        let token = op.split(' ').nth(1).unwrap();
+        assert_eq!(
+            line.error(token, "operator '+' or '*'").to_string(),
+            "3:24: expected operator '+' or '*'"
+        );
+        assert_eq!(line.column(line.end()), 27);
+        assert_eq!(line.column("elsewhere"), 27);
+        assert_eq!(
+            line.parse::<u32>(token, "a number"),
+            Err(line.error(token, "a number"))
+        );
+        assert_eq!(
+            line.literal(line.text, "  Operation"),
+            Ok(": new = old ^ 5")
+        );
+        assert!(line.literal(line.text, "Operation").is_err());
+        assert_eq!(
+            line.require::<&str>(None, "more"),
+            Err(ParseError::new(3, 27, "more"))
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{Dir4, Dir8};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on a 2D grid. `y` grows downwards, like the rows of the puzzle inputs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(&self, other: Point2) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // a covering b counts as distance 0, touching diagonally as 1
+    pub fn chebyshev(&self, other: Point2) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn signum(&self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn neighbors4(&self) -> [Point2; 4] {
+        Dir4::ALL.map(|d| *self + d)
+    }
+
+    pub fn neighbors8(&self) -> [Point2; 8] {
+        Dir8::ALL.map(|d| *self + d)
+    }
+}
+
+impl From<(i32, i32)> for Point2 {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point2 {
+            x: x as i32,
+            y: y as i32,
+        }
+    }
+}
+
+impl Add for Point2 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Point2 {
+    type Output = Self;
+    fn mul(self, rhs: i32) -> Self {
+        Point2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl Add<Dir4> for Point2 {
+    type Output = Self;
+    fn add(self, rhs: Dir4) -> Self {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign<Dir4> for Point2 {
+    fn add_assign(&mut self, rhs: Dir4) {
+        *self = *self + rhs;
+    }
+}
+
+impl Add<Dir8> for Point2 {
+    type Output = Self;
+    fn add(self, rhs: Dir8) -> Self {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign<Dir8> for Point2 {
+    fn add_assign(&mut self, rhs: Dir8) {
+        *self = *self + rhs;
+    }
+}
+
+/// A point in 3D space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: Point3) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    // the six points sharing a face with this one
+    pub fn neighbors6(&self) -> [Point3; 6] {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, -1),
+        ]
+        .map(|d| *self + d)
+    }
+}
+
+impl From<[i32; 3]> for Point3 {
+    fn from([x, y, z]: [i32; 3]) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Point3 {
+    type Output = Self;
+    fn mul(self, rhs: i32) -> Self {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
@@ -0,0 +1,334 @@
+//! A local store of the verdicts given on answers, as the puzzle site would give them: the
+//! accepted answer of each part, and the answers known to be wrong.
+//!
+//! Each day has a file `day{name}.toml` in the store, with a table per part:
+//!
+//! ```toml
+//! [part1]
+//! accepted = "1651"
+//!
+//! [part2]
+//! too_high = 2500
+//! too_low = 1900
+//! wrong = ["DCBA"]
+//! ```
+//!
+//! `too_high` is the lowest answer known to be too high, and `too_low` the highest known to be too
+//! low, so that any answer past either is wrong too.
+
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// What the site says of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Incorrect,
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "incorrect" => Ok(Verdict::Incorrect),
+            _ => Err(format!(
+                "unknown verdict `{s}`, expected correct, too-high, too-low or incorrect"
+            )),
+        }
+    }
+}
+
+/// `correct`, `too high`, `too low` or `incorrect`.
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Incorrect => "incorrect",
+        })
+    }
+}
+
+/// What the store knows of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    /// Wrong, and which way if known.
+    Incorrect(Option<Bound>),
+    /// Neither accepted nor known to be wrong.
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    TooHigh,
+    TooLow,
+}
+
+/// `correct`, `incorrect (too high)`, `incorrect` or `unknown`.
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Check::Correct => f.write_str("correct"),
+            Check::Incorrect(Some(Bound::TooHigh)) => f.write_str("incorrect (too high)"),
+            Check::Incorrect(Some(Bound::TooLow)) => f.write_str("incorrect (too low)"),
+            Check::Incorrect(None) => f.write_str("incorrect"),
+            Check::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+/// The verdicts given on the answers to one part.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Part {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+}
+
+impl Part {
+    /// Records the verdict on `answer`.
+    ///
+    /// Fails if the verdict contradicts what is known already, e.g. a second accepted answer, or
+    /// if it says too high or too low of an answer that is not a number.
+    pub fn record(&mut self, answer: &Answer, verdict: Verdict) -> Result<(), String> {
+        let text = text(answer)?;
+        let check = self.check(answer);
+        let contradicts = match (verdict, check) {
+            (_, Check::Unknown) => false,
+            (Verdict::Correct, check) => check != Check::Correct,
+            (_, Check::Correct) => true,
+            (Verdict::TooHigh, Check::Incorrect(Some(Bound::TooLow)))
+            | (Verdict::TooLow, Check::Incorrect(Some(Bound::TooHigh))) => true,
+            (_, Check::Incorrect(_)) => false,
+        };
+        if contradicts {
+            return Err(format!("`{text}` is known to be {check}"));
+        }
+
+        match verdict {
+            Verdict::Correct => self.accepted = Some(text),
+            Verdict::TooHigh | Verdict::TooLow => {
+                let Answer::Int(n) = *answer else {
+                    return Err(format!(
+                        "`{text}` is not a number to be too high or too low"
+                    ));
+                };
+                // keep the tightest bounds
+                if verdict == Verdict::TooHigh {
+                    self.too_high = Some(self.too_high.map_or(n, |high| high.min(n)));
+                } else {
+                    self.too_low = Some(self.too_low.map_or(n, |low| low.max(n)));
+                }
+            }
+            Verdict::Incorrect => {
+                if !self.wrong.contains(&text) {
+                    self.wrong.push(text);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// What is known of `answer`. An answer other than the accepted one is wrong, and too high
+    /// or too low if both are numbers.
+    pub fn check(&self, answer: &Answer) -> Check {
+        let Ok(text) = text(answer) else {
+            return Check::Unknown;
+        };
+        let n = match answer {
+            Answer::Int(n) => Some(*n),
+            _ => None,
+        };
+        if let Some(accepted) = &self.accepted {
+            if *accepted == text {
+                return Check::Correct;
+            }
+            let bound = match (n, accepted.parse::<i64>()) {
+                (Some(n), Ok(accepted)) if n > accepted => Some(Bound::TooHigh),
+                (Some(_), Ok(_)) => Some(Bound::TooLow),
+                _ => None,
+            };
+            return Check::Incorrect(bound);
+        }
+        match n {
+            Some(n) if self.too_high.is_some_and(|high| n >= high) => {
+                Check::Incorrect(Some(Bound::TooHigh))
+            }
+            Some(n) if self.too_low.is_some_and(|low| n <= low) => {
+                Check::Incorrect(Some(Bound::TooLow))
+            }
+            _ if self.wrong.contains(&text) => Check::Incorrect(None),
+            _ => Check::Unknown,
+        }
+    }
+}
+
+// the answer as stored
+fn text(answer: &Answer) -> Result<String, String> {
+    match answer {
+        Answer::Missing => Err("the part has no answer".to_string()),
+        answer => Ok(answer.to_string()),
+    }
+}
+
+/// The verdicts on both parts of a day, as stored in its file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "is_empty")]
+    pub part1: Part,
+    #[serde(default, skip_serializing_if = "is_empty")]
+    pub part2: Part,
+}
+
+fn is_empty(part: &Part) -> bool {
+    *part == Part::default()
+}
+
+impl DayAnswers {
+    pub fn part(&self, part: u8) -> &Part {
+        if part == 1 {
+            &self.part1
+        } else {
+            &self.part2
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut Part {
+        if part == 1 {
+            &mut self.part1
+        } else {
+            &mut self.part2
+        }
+    }
+}
+
+/// A directory holding a file of verdicts per day.
+#[derive(Debug, Clone)]
+pub struct Store {
+    dir: PathBuf,
+}
+
+impl Store {
+    pub fn new(dir: impl Into<PathBuf>) -> Store {
+        Store { dir: dir.into() }
+    }
+
+    /// The store under `answers/` at the root of the workspace.
+    pub fn default_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("answers")
+    }
+
+    fn path(&self, day: &str) -> PathBuf {
+        self.dir.join(format!("day{day}.toml"))
+    }
+
+    /// The verdicts on the answers of `day`, none if it has no file yet.
+    pub fn load(&self, day: &str) -> io::Result<DayAnswers> {
+        let path = self.path(day);
+        match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {e}", path.display()),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(DayAnswers::default()),
+            Err(e) => Err(io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+        }
+    }
+
+    pub fn save(&self, day: &str, answers: &DayAnswers) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let text = toml::to_string(answers).map_err(io::Error::other)?;
+        fs::write(self.path(day), text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+
+    #[test]
+    fn test_bounds() {
+        let mut part = Part::default();
+        part.record(&Answer::Int(2500), Verdict::TooHigh).unwrap();
+        part.record(&Answer::Int(3000), Verdict::TooHigh).unwrap();
+        part.record(&Answer::Int(1900), Verdict::TooLow).unwrap();
+        assert_eq!((part.too_high, part.too_low), (Some(2500), Some(1900)));
+
+        assert_eq!(
+            part.check(&Answer::Int(2600)),
+            Check::Incorrect(Some(Bound::TooHigh))
+        );
+        assert_eq!(
+            part.check(&Answer::Int(1900)),
+            Check::Incorrect(Some(Bound::TooLow))
+        );
+        assert_eq!(part.check(&Answer::Int(2000)), Check::Unknown);
+        assert!(part.record(&"ABC".into(), Verdict::TooLow).is_err());
+    }
+
+    #[test]
+    fn test_accepted() {
+        let mut part = Part::default();
+        part.record(&"DCBA".into(), Verdict::Incorrect).unwrap();
+        assert_eq!(part.check(&"DCBA".into()), Check::Incorrect(None));
+        part.record(&"ABCD".into(), Verdict::Correct).unwrap();
+        assert_eq!(part.check(&"ABCD".into()), Check::Correct);
+        assert_eq!(part.check(&"ABCE".into()), Check::Incorrect(None));
+        // once accepted, an answer stays so
+        assert!(part.record(&"ABCE".into(), Verdict::Correct).is_err());
+        assert!(part.record(&"ABCD".into(), Verdict::TooHigh).is_err());
+
+        let mut part = Part::default();
+        part.record(&Answer::Int(1651), Verdict::Correct).unwrap();
+        assert_eq!(
+            part.check(&Answer::Int(1700)),
+            Check::Incorrect(Some(Bound::TooHigh))
+        );
+        assert_eq!(part.check(&Answer::Missing), Check::Unknown);
+    }
+
+    #[test]
+    fn test_store() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+        let store = Store::new(&dir);
+        assert_eq!(store.load("16").unwrap(), DayAnswers::default());
+
+        let mut answers = DayAnswers::default();
+        answers.part1.accepted = Some("1651".to_string());
+        answers.part2.too_high = Some(2500);
+        answers.part2.wrong = vec!["1700".to_string()];
+        store.save("16", &answers).unwrap();
+        let text = fs::read_to_string(dir.join("day16.toml")).unwrap();
+        assert_eq!(
+            text,
+            "[part1]\naccepted = \"1651\"\n\n[part2]\ntoo_high = 2500\nwrong = [\"1700\"]\n"
+        );
+        assert_eq!(store.load("16").unwrap(), answers);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,204 @@
+//! Timing of the parse and solve stages of a day, and JSON baselines to compare timings against.
+
+use aoc_common::{Answer, ParseError};
+use serde::{Deserialize, Serialize};
+use std::hint;
+use std::time::{Duration, Instant};
+
+/// How long each stage of a day took.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// The answers to both parts and how long each stage took.
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub timings: Timings,
+}
+
+/// Parses the input and solves both parts, timing each stage on its own.
+pub fn time<'a, T>(
+    input: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    part1: impl FnOnce(&T) -> Answer,
+    part2: impl FnOnce(&T) -> Answer,
+) -> Result<Solution, ParseError> {
+    let start = Instant::now();
+    let parsed = parse(hint::black_box(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer1 = hint::black_box(part1(&parsed));
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    let answer2 = hint::black_box(part2(&parsed));
+    let part2 = start.elapsed();
+
+    Ok(Solution {
+        part1: answer1,
+        part2: answer2,
+        timings: Timings {
+            parse,
+            part1,
+            part2,
+        },
+    })
+}
+
+/// The median of each stage over several runs, which is less sensitive to a stray slow run than
+/// the mean.
+pub fn median(runs: &[Timings]) -> Timings {
+    let stage = |f: fn(&Timings) -> Duration| {
+        let mut durations: Vec<_> = runs.iter().map(f).collect();
+        durations.sort();
+        durations
+            .get(durations.len() / 2)
+            .copied()
+            .unwrap_or_default()
+    };
+    Timings {
+        parse: stage(|t| t.parse),
+        part1: stage(|t| t.part1),
+        part2: stage(|t| t.part2),
+    }
+}
+
+/// A saved benchmark, written by `aoc bench --save` and read by `aoc bench --compare`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Number of runs each median was taken over.
+    pub runs: usize,
+    pub days: Vec<Record>,
+}
+
+/// The median timings of one day, in nanoseconds.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: String,
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    pub part2_ns: u64,
+}
+
+impl Record {
+    pub fn new(day: &str, timings: &Timings) -> Record {
+        let ns = |d: Duration| d.as_nanos().try_into().unwrap_or(u64::MAX);
+        Record {
+            day: day.to_string(),
+            parse_ns: ns(timings.parse),
+            part1_ns: ns(timings.part1),
+            part2_ns: ns(timings.part2),
+        }
+    }
+
+    fn stages(&self) -> [(&'static str, u64); 3] {
+        [
+            ("parse", self.parse_ns),
+            ("part 1", self.part1_ns),
+            ("part 2", self.part2_ns),
+        ]
+    }
+}
+
+/// Stages that got slower than the baseline by more than `threshold` (0.1 for 10%).
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: String,
+    pub stage: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+// Stages this fast vary by more than any sensible threshold from one run to the next.
+const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// Compares every day of `current` that is also in `baseline`.
+pub fn regressions(baseline: &Baseline, current: &Baseline, threshold: f64) -> Vec<Regression> {
+    let mut found = vec![];
+    for record in &current.days {
+        let Some(old) = baseline.days.iter().find(|r| r.day == record.day) else {
+            continue;
+        };
+        for ((stage, before), (_, after)) in old.stages().into_iter().zip(record.stages()) {
+            let (before, after) = (Duration::from_nanos(before), Duration::from_nanos(after));
+            if after > before.mul_f64(1.0 + threshold) && after - before > NOISE_FLOOR {
+                found.push(Regression {
+                    day: record.day.clone(),
+                    stage,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: &str, parse_ns: u64, part1_ns: u64, part2_ns: u64) -> Record {
+        Record {
+            day: day.to_string(),
+            parse_ns,
+            part1_ns,
+            part2_ns,
+        }
+    }
+
+    #[test]
+    fn test_median() {
+        let ms = Duration::from_millis;
+        let runs = [3, 1, 2].map(|n| Timings {
+            parse: ms(n),
+            part1: ms(10 * n),
+            part2: ms(100 * n),
+        });
+        let median = median(&runs);
+        assert_eq!(median.parse, ms(2));
+        assert_eq!(median.part1, ms(20));
+        assert_eq!(median.part2, ms(200));
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = Baseline {
+            runs: 5,
+            days: vec![record("1", 1_000, 10_000_000, 10_000_000)],
+        };
+        let current = Baseline {
+            runs: 5,
+            days: vec![
+                // parse doubled but stays under the noise floor, part 1 is within 10%
+                record("1", 2_000, 10_500_000, 12_000_000),
+                record("2", 1, 1, 1),
+            ],
+        };
+        assert_eq!(
+            regressions(&baseline, &current, 0.1),
+            [Regression {
+                day: "1".to_string(),
+                stage: "part 2",
+                before: Duration::from_millis(10),
+                after: Duration::from_millis(12),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline {
+            runs: 3,
+            days: vec![record("7-2", 1, 2, 3)],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
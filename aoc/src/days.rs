@@ -0,0 +1,159 @@
+use crate::bench::{self, Solution};
+#[cfg(feature = "profile")]
+use crate::profile;
+use aoc_common::options::Reporter;
+use aoc_common::{Answer, ParseError};
+
+/// Times a day as its options ask, like [`Day::time`].
+pub type Timer = Box<dyn Fn(&str) -> Result<Solution, ParseError>>;
+
+/// What `aoc run` does with a day's input, as the day's options ask.
+pub enum Mode {
+    Time(Timer),
+    /// Writes a report on the input instead of the answers.
+    Report(Reporter),
+}
+
+pub struct Day {
+    /// Name used on the command line, e.g. `16` or `7-2`.
+    pub name: &'static str,
+    /// Stem of the puzzle input file under `input/`.
+    pub input: &'static str,
+    pub solve: fn(&str) -> Result<(Answer, Answer), ParseError>,
+    /// Only parses the input, without solving either part.
+    pub parse: fn(&str) -> Result<(), ParseError>,
+    /// Like `solve`, but also times parsing and each part separately.
+    pub time: fn(&str) -> Result<Solution, ParseError>,
+    /// Takes the day's own options out of the arguments, e.g. day 1's `--width`, and returns
+    /// what they ask for. Days without options leave the arguments as they are.
+    pub options: fn(&mut Vec<String>) -> Result<Mode, String>,
+    /// Like `solve`, but also counts the heap usage of parsing and each part separately.
+    #[cfg(feature = "profile")]
+    pub profile: fn(&str) -> Result<profile::Profile, ParseError>,
+}
+
+macro_rules! day {
+    ($name:literal, $input:literal, $krate:ident) => {
+        day!($name, $input, $krate, |_| {
+            Ok(Mode::Time(Box::new(|input| {
+                bench::time(
+                    input,
+                    $krate::parse,
+                    |x| $krate::part1(x),
+                    |x| $krate::part2(x),
+                )
+            })))
+        })
+    };
+    ($name:literal, $input:literal, $krate:ident, $options:expr) => {
+        Day {
+            name: $name,
+            input: $input,
+            solve: $krate::solve,
+            parse: |input| $krate::parse(input).map(drop),
+            time: |input| {
+                bench::time(
+                    input,
+                    $krate::parse,
+                    |x| $krate::part1(x),
+                    |x| $krate::part2(x),
+                )
+            },
+            options: $options,
+            #[cfg(feature = "profile")]
+            profile: |input| {
+                profile::profile(
+                    input,
+                    $krate::parse,
+                    |x| $krate::part1(x),
+                    |x| $krate::part2(x),
+                )
+            },
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!("1", "day1", day1, day1_options),
+    day!("2", "day2", day2, day2_options),
+    day!("3", "day3", day3, day3_options),
+    day!("4", "day4", day4),
+    day!("5", "day5", day5),
+    day!("6", "day6", day6),
+    day!("7", "day7", day7),
+    day!("7-2", "day7", day7_2),
+    day!("8", "day8", day8),
+    day!("9", "day9", day9),
+    day!("10", "day10", day10),
+    day!("11", "day11", day11),
+    day!("12", "day12", day12),
+    day!("13", "day13", day13),
+    day!("14", "day14", day14),
+    day!("15", "day15", day15),
+    day!("16", "day16", day16),
+    day!("17", "day17", day17),
+    day!("18", "day18", day18),
+    day!("19", "day19", day19),
+    day!("20", "day20", day20),
+    day!("21", "day21", day21),
+    day!("22", "day22", day22),
+    day!("23", "day23", day23),
+    day!("24", "day24", day24),
+    day!("25", "day25", day25),
+];
+
+// day 1 adds calories up in the type `--width` picks, and those of `--top` elves in part 2
+fn day1_options(args: &mut Vec<String>) -> Result<Mode, String> {
+    struct Time;
+
+    impl day1::Dispatch for Time {
+        type Output = Timer;
+
+        fn call<C: day1::Calories>(self, k: usize) -> Timer {
+            Box::new(move |input| {
+                bench::time(
+                    input,
+                    day1::parse_as::<C>,
+                    |x| day1::part1(x),
+                    |x| day1::part2_with(x, k),
+                )
+            })
+        }
+    }
+
+    Ok(Mode::Time(day1::Options::extract(args)?.dispatch(Time)))
+}
+
+// day 2 plays the game of the rules given with `--rules`, or reports on it with `--analyze`
+fn day2_options(args: &mut Vec<String>) -> Result<Mode, String> {
+    let day2::Options { game, analyze } = day2::Options::extract(args)?;
+    if analyze {
+        return Ok(Mode::Report(game.reporter()));
+    }
+    Ok(Mode::Time(Box::new(move |input| {
+        bench::time(
+            input,
+            |input| game.parse(input),
+            |x| game.part1(x),
+            |x| game.part2(x),
+        )
+    })))
+}
+
+// day 3 groups as many elves as `--group-size` asks in part 2
+fn day3_options(args: &mut Vec<String>) -> Result<Mode, String> {
+    let group_size = day3::Options::extract(args)?.group_size.get();
+    Ok(Mode::Time(Box::new(move |input| {
+        bench::time(
+            input,
+            day3::parse,
+            |x| day3::part1(x),
+            |x| day3::part2_with(x, group_size),
+        )
+    })))
+}
+
+pub fn find(name: &str) -> Option<&'static Day> {
+    let name = name.strip_prefix("day").unwrap_or(name);
+    DAYS.iter().find(|day| day.name == name)
+}
@@ -0,0 +1,295 @@
+//! How the runner writes answers: a listing for people to read, or one record per part of a day
+//! for scripts, as JSON Lines or tab-separated values.
+
+use aoc_common::Answer;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("unknown format `{s}`, expected json, tsv or human")),
+        }
+    }
+}
+
+/// Why a part has no answer, when `run --all` gave up on its day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// The input did not parse.
+    Error,
+    Panic,
+    Timeout,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Failure::Error => "ERROR",
+            Failure::Panic => "PANIC",
+            Failure::Timeout => "TIMEOUT",
+        })
+    }
+}
+
+/// The answer to one part of a day.
+#[derive(Debug)]
+pub struct Record<'a> {
+    pub day: &'a str,
+    pub part: u8,
+    pub answer: Result<Answer, Failure>,
+    /// Time spent solving the part, not counting parsing the input.
+    pub elapsed: Duration,
+}
+
+// The JSON form of a record, whose fields keep this order.
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    day: &'a str,
+    part: u8,
+    answer: Value,
+    elapsed_ms: f64,
+    // only for parts without an answer, in lowercase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure: Option<String>,
+}
+
+impl Record<'_> {
+    fn elapsed_ms(&self) -> f64 {
+        self.elapsed.as_secs_f64() * 1000.0
+    }
+
+    // the answer, or the failure in its place
+    fn answer_text(&self) -> String {
+        match &self.answer {
+            Ok(answer) => answer.to_string(),
+            Err(failure) => failure.to_string(),
+        }
+    }
+}
+
+/// Writes the records, which are grouped by day and ordered by part.
+pub fn write(out: &mut impl Write, format: Format, records: &[Record]) -> io::Result<()> {
+    match format {
+        Format::Human => write_human(out, records),
+        Format::Json => {
+            for record in records {
+                let answer = match &record.answer {
+                    Ok(Answer::Int(n)) => Value::from(*n),
+                    Ok(Answer::Text(s)) => Value::from(s.as_str()),
+                    Ok(Answer::Missing) | Err(_) => Value::Null,
+                };
+                let line = JsonRecord {
+                    day: record.day,
+                    part: record.part,
+                    answer,
+                    elapsed_ms: record.elapsed_ms(),
+                    failure: record
+                        .answer
+                        .as_ref()
+                        .err()
+                        .map(|f| f.to_string().to_lowercase()),
+                };
+                serde_json::to_writer(&mut *out, &line)?;
+                writeln!(out)?;
+            }
+            Ok(())
+        }
+        Format::Tsv => {
+            writeln!(out, "day\tpart\tanswer\telapsed_ms")?;
+            for record in records {
+                // a missing answer is an empty field, and multi-line answers stay on one row
+                let answer = match &record.answer {
+                    Ok(Answer::Missing) => String::new(),
+                    _ => record
+                        .answer_text()
+                        .replace('\\', "\\\\")
+                        .replace('\t', "\\t")
+                        .replace('\n', "\\n"),
+                };
+                writeln!(
+                    out,
+                    "{}\t{}\t{answer}\t{:.3}",
+                    record.day,
+                    record.part,
+                    record.elapsed_ms()
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// A single part prints just the answer, the parts of a single day are labelled, and several days
+// make a table.
+fn write_human(out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+    match records {
+        [] => Ok(()),
+        [record] => writeln!(out, "{}", record.answer_text()),
+        [first, ..] if records.iter().all(|r| r.day == first.day) => {
+            for record in records {
+                writeln!(out, "part {}: {}", record.part, record.answer_text())?;
+            }
+            Ok(())
+        }
+        _ => write_table(out, records),
+    }
+}
+
+// Multi-line answers (the day 10 CRT image) continue on the following rows of their column.
+fn write_table(out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+    let mut rows: Vec<(&str, String, String)> = vec![];
+    for record in records {
+        if rows.last().is_none_or(|row| row.0 != record.day) {
+            rows.push((record.day, String::new(), String::new()));
+        }
+        let row = rows.last_mut().unwrap();
+        let cell = if record.part == 1 {
+            &mut row.1
+        } else {
+            &mut row.2
+        };
+        *cell = record.answer_text();
+    }
+
+    let width = rows
+        .iter()
+        .flat_map(|(_, part1, _)| part1.lines().map(str::len))
+        .max()
+        .unwrap_or(0)
+        .max("part 1".len());
+
+    writeln!(out, "{:<5}{:<width$}  part 2", "day", "part 1")?;
+    for (name, part1, part2) in &rows {
+        let mut lines1 = part1.lines();
+        let mut lines2 = part2.lines();
+        let mut name = Some(name);
+        loop {
+            let (a, b) = (lines1.next(), lines2.next());
+            if a.is_none() && b.is_none() {
+                break;
+            }
+            let a = a.unwrap_or_default();
+            let b = b.unwrap_or_default();
+            let name = name.take().unwrap_or(&"");
+            writeln!(out, "{name:<5}{a:<width$}  {b}")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record<'static>> {
+        vec![
+            Record {
+                day: "10",
+                part: 1,
+                answer: Ok(Answer::Int(13140)),
+                elapsed: Duration::from_micros(1500),
+            },
+            Record {
+                day: "10",
+                part: 2,
+                answer: Ok(Answer::Text("##..\n.##.".to_string())),
+                elapsed: Duration::from_micros(250),
+            },
+            Record {
+                day: "25",
+                part: 2,
+                answer: Ok(Answer::Missing),
+                elapsed: Duration::ZERO,
+            },
+        ]
+    }
+
+    fn output(format: Format, records: &[Record]) -> String {
+        let mut out = vec![];
+        write(&mut out, format, records).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            output(Format::Json, &records()),
+            r###"{"day":"10","part":1,"answer":13140,"elapsed_ms":1.5}
+{"day":"10","part":2,"answer":"##..\n.##.","elapsed_ms":0.25}
+{"day":"25","part":2,"answer":null,"elapsed_ms":0.0}
+"###
+        );
+    }
+
+    #[test]
+    fn test_tsv() {
+        assert_eq!(
+            output(Format::Tsv, &records()),
+            "day\tpart\tanswer\telapsed_ms\n\
+             10\t1\t13140\t1.500\n\
+             10\t2\t##..\\n.##.\t0.250\n\
+             25\t2\t\t0.000\n"
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        let records: Vec<Record> = [Failure::Timeout, Failure::Panic]
+            .into_iter()
+            .enumerate()
+            .map(|(i, failure)| Record {
+                day: "16",
+                part: i as u8 + 1,
+                answer: Err(failure),
+                elapsed: Duration::ZERO,
+            })
+            .collect();
+        assert_eq!(
+            output(Format::Human, &records),
+            "part 1: TIMEOUT\npart 2: PANIC\n"
+        );
+        assert_eq!(
+            output(Format::Json, &records[..1]),
+            "{\"day\":\"16\",\"part\":1,\"answer\":null,\"elapsed_ms\":0.0,\"failure\":\"timeout\"}\n"
+        );
+        assert_eq!(
+            output(Format::Tsv, &records[1..]),
+            "day\tpart\tanswer\telapsed_ms\n16\t2\tPANIC\t0.000\n"
+        );
+    }
+
+    #[test]
+    fn test_human() {
+        let records = records();
+        assert_eq!(output(Format::Human, &records[..1]), "13140\n");
+        assert_eq!(
+            output(Format::Human, &records[..2]),
+            "part 1: 13140\npart 2: ##..\n.##.\n"
+        );
+        assert_eq!(
+            output(Format::Human, &records),
+            "day  part 1  part 2\n\
+             10   13140   ##..\n\
+             \x20    \x20       .##.\n\
+             25           -\n"
+        );
+    }
+}
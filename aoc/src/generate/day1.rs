@@ -0,0 +1,25 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+/// `size` elves, at least three, each carrying a few snacks.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let elves: Vec<Vec<u32>> = (0..size.max(3))
+        .map(|_| {
+            let snacks = rng.gen_range(1..=8);
+            (0..snacks).map(|_| rng.gen_range(1000..=70000)).collect()
+        })
+        .collect();
+    let input = elves
+        .iter()
+        .map(super::lines)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut totals: Vec<u32> = elves.iter().map(|snacks| snacks.iter().sum()).collect();
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    Generated {
+        input,
+        part1: totals[0].into(),
+        part2: totals[..3].iter().sum::<u32>().into(),
+    }
+}
@@ -0,0 +1,46 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+/// A program running for at least 240 cycles, and `size` more.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut program = vec![];
+    // the value of the register during each cycle
+    let mut during = vec![];
+    let mut x = 1i32;
+    while during.len() < 240 + size {
+        if rng.gen_bool(0.3) {
+            program.push("noop".to_string());
+            during.push(x);
+        } else {
+            // keep the sprite around the screen
+            let v = rng.gen_range(-2 - x..=42 - x).clamp(-20, 20);
+            program.push(format!("addx {v}"));
+            during.extend([x, x]);
+            x += v;
+        }
+    }
+
+    let part1: i32 = (20..=220)
+        .step_by(40)
+        .map(|cycle| cycle as i32 * during[cycle - 1])
+        .sum();
+    let rows: Vec<String> = during[..240]
+        .chunks(40)
+        .map(|row| {
+            (0..40)
+                .map(|pixel| {
+                    if (row[pixel] - pixel as i32).abs() <= 1 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    Generated {
+        input: super::lines(program),
+        part1: part1.into(),
+        part2: rows.join("\n").into(),
+    }
+}
@@ -0,0 +1,113 @@
+use super::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<u64>,
+    // the operand of `*` or `+`, or none for `old * old`
+    operation: (char, Option<u64>),
+    divisor: u64,
+    targets: [usize; 2],
+}
+
+impl Monkey {
+    fn inspect(&self, old: u64) -> Option<u64> {
+        let operand = self.operation.1.unwrap_or(old);
+        match self.operation.0 {
+            '+' => old.checked_add(operand),
+            _ => old.checked_mul(operand),
+        }
+    }
+}
+
+// The product of the two largest numbers of inspections after `rounds` rounds, or none if a
+// worry level no longer fits.
+fn monkey_business(
+    mut monkeys: Vec<Monkey>,
+    rounds: usize,
+    relief: impl Fn(u64) -> u64,
+) -> Option<u64> {
+    let mut inspected = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for m in 0..monkeys.len() {
+            for old in std::mem::take(&mut monkeys[m].items) {
+                inspected[m] += 1;
+                let new = relief(monkeys[m].inspect(old)?);
+                let [yes, no] = monkeys[m].targets;
+                let target = if new.is_multiple_of(monkeys[m].divisor) {
+                    yes
+                } else {
+                    no
+                };
+                monkeys[target].items.push(new);
+            }
+        }
+    }
+    inspected.sort_unstable();
+    Some(inspected.iter().rev().take(2).product())
+}
+
+fn note(i: usize, monkey: &Monkey) -> String {
+    let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+    let operand = match monkey.operation.1 {
+        Some(n) => n.to_string(),
+        None => "old".to_string(),
+    };
+    format!(
+        "Monkey {i}:\n  Starting items: {}\n  Operation: new = old {} {operand}\n  \
+         Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+        items.join(", "),
+        monkey.operation.0,
+        monkey.divisor,
+        monkey.targets[0],
+        monkey.targets[1],
+    )
+}
+
+/// `size` monkeys, between two and eight, with distinct prime divisors. Monkeys never throw to
+/// themselves, and whatever worries the first 20 rounds reach fits in 64 bits.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.clamp(2, 8);
+    loop {
+        let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+        primes.shuffle(rng);
+        let monkeys: Vec<Monkey> = (0..count)
+            .map(|i| {
+                let items = (0..rng.gen_range(1..=6))
+                    .map(|_| rng.gen_range(50..100))
+                    .collect();
+                let operation = match rng.gen_range(0..5) {
+                    0 => ('*', None),
+                    1 | 2 => ('+', Some(rng.gen_range(1..=8))),
+                    _ => ('*', Some(rng.gen_range(2..=19))),
+                };
+                let mut others: Vec<usize> = (0..count).filter(|m| *m != i).collect();
+                others.shuffle(rng);
+                let no = *others.last().unwrap();
+                Monkey {
+                    items,
+                    operation,
+                    divisor: primes[i],
+                    targets: [others[0], no],
+                }
+            })
+            .collect();
+
+        let product: u64 = monkeys.iter().map(|m| m.divisor).product();
+        let Some(part1) = monkey_business(monkeys.clone(), 20, |worry| worry / 3) else {
+            continue;
+        };
+        let part2 = monkey_business(monkeys.clone(), 10000, |worry| worry % product).unwrap();
+        let notes: Vec<String> = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, m)| note(i, m))
+            .collect();
+        return Generated {
+            input: notes.join("\n"),
+            part1: part1.into(),
+            part2: part2.into(),
+        };
+    }
+}
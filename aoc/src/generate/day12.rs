@@ -0,0 +1,105 @@
+use super::{Generated, Rng};
+use aoc_common::Answer;
+use rand::seq::index;
+use rand::Rng as _;
+
+// The fewest steps from any of `starts` to `end`, relaxing every step until none gets shorter.
+fn fewest_steps(heights: &[Vec<u8>], starts: &[(usize, usize)], end: (usize, usize)) -> Answer {
+    let (h, w) = (heights.len(), heights[0].len());
+    let mut steps = vec![vec![usize::MAX; w]; h];
+    for (y, x) in starts {
+        steps[*y][*x] = 0;
+    }
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for y in 0..h {
+            for x in 0..w {
+                if steps[y][x] == usize::MAX {
+                    continue;
+                }
+                let neighbors = [
+                    (y + 1, x),
+                    (y.wrapping_sub(1), x),
+                    (y, x + 1),
+                    (y, x.wrapping_sub(1)),
+                ];
+                for (ny, nx) in neighbors {
+                    if ny < h
+                        && nx < w
+                        && heights[ny][nx] <= heights[y][x] + 1
+                        && steps[ny][nx] > steps[y][x] + 1
+                    {
+                        steps[ny][nx] = steps[y][x] + 1;
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+    match steps[end.0][end.1] {
+        usize::MAX => Answer::Missing,
+        n => n.into(),
+    }
+}
+
+/// A heightmap of `size` by `size` squares, at least six by six, with a path from `S` to `E`.
+///
+/// The path snakes along the rows from `S`, climbing one letter at a time until it reaches `E` at
+/// `z`; the squares after it have any height.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let n = size.max(6);
+    let mut snake = vec![];
+    for y in 0..n {
+        for x in 0..n {
+            snake.push(if y % 2 == 0 { (y, x) } else { (y, n - 1 - x) });
+        }
+    }
+    let end = rng.gen_range(25..snake.len());
+    let climbs = index::sample(rng, end, 25);
+
+    let mut heights = vec![vec![0u8; n]; n];
+    let mut height = 0;
+    for (i, (y, x)) in snake.iter().enumerate() {
+        if i > end {
+            heights[*y][*x] = rng.gen_range(0..26);
+            continue;
+        }
+        if i > 0 && climbs.iter().any(|c| c + 1 == i) {
+            height += 1;
+        }
+        heights[*y][*x] = height;
+    }
+    // turn the snake around any corner
+    if rng.gen() {
+        heights.reverse();
+        snake.iter_mut().for_each(|(y, _)| *y = n - 1 - *y);
+    }
+    if rng.gen() {
+        heights.iter_mut().for_each(|row| row.reverse());
+        snake.iter_mut().for_each(|(_, x)| *x = n - 1 - *x);
+    }
+    let (start, end) = (snake[0], snake[end]);
+
+    let rows = heights.iter().enumerate().map(|(y, row)| {
+        row.iter()
+            .enumerate()
+            .map(|(x, h)| match (y, x) {
+                _ if (y, x) == start => 'S',
+                _ if (y, x) == end => 'E',
+                _ => (b'a' + h) as char,
+            })
+            .collect::<String>()
+    });
+    let input = super::lines(rows);
+
+    let lowest: Vec<(usize, usize)> = (0..n)
+        .flat_map(|y| (0..n).map(move |x| (y, x)))
+        .filter(|(y, x)| heights[*y][*x] == 0)
+        .collect();
+    Generated {
+        input,
+        part1: fewest_steps(&heights, &[start], end),
+        part2: fewest_steps(&heights, &lowest, end),
+    }
+}
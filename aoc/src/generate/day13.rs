@@ -0,0 +1,92 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+use std::cmp::Ordering;
+use std::fmt;
+
+#[derive(Clone)]
+enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Packet::Int(n) => write!(f, "{n}"),
+            Packet::List(items) => {
+                let items: Vec<String> = items.iter().map(Packet::to_string).collect();
+                write!(f, "[{}]", items.join(","))
+            }
+        }
+    }
+}
+
+fn compare(left: &Packet, right: &Packet) -> Ordering {
+    match (left, right) {
+        (Packet::Int(a), Packet::Int(b)) => a.cmp(b),
+        (Packet::Int(_), Packet::List(_)) => compare(&Packet::List(vec![left.clone()]), right),
+        (Packet::List(_), Packet::Int(_)) => compare(left, &Packet::List(vec![right.clone()])),
+        (Packet::List(a), Packet::List(b)) => {
+            for (a, b) in a.iter().zip(b) {
+                match compare(a, b) {
+                    Ordering::Equal => continue,
+                    unequal => return unequal,
+                }
+            }
+            a.len().cmp(&b.len())
+        }
+    }
+}
+
+fn list(rng: &mut Rng, depth: u32) -> Packet {
+    let items = (0..rng.gen_range(0..=4))
+        .map(|_| match depth {
+            0 => Packet::Int(rng.gen_range(0..=10)),
+            _ if rng.gen_bool(0.6) => Packet::Int(rng.gen_range(0..=10)),
+            _ => list(rng, depth - 1),
+        })
+        .collect();
+    Packet::List(items)
+}
+
+fn divider(n: u32) -> Packet {
+    Packet::List(vec![Packet::List(vec![Packet::Int(n)])])
+}
+
+/// `size` pairs of packets, no two of which (dividers included) are in no particular order.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut packets = vec![divider(2), divider(6)];
+    while packets.len() < 2 + 2 * size.max(1) {
+        let packet = list(rng, 3);
+        if packets
+            .iter()
+            .all(|p| compare(p, &packet) != Ordering::Equal)
+        {
+            packets.push(packet);
+        }
+    }
+    let packets = packets.split_off(2);
+
+    let pairs: Vec<String> = packets
+        .chunks(2)
+        .map(|pair| format!("{}\n{}\n", pair[0], pair[1]))
+        .collect();
+    let part1: usize = packets
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, pair)| compare(&pair[0], &pair[1]) == Ordering::Less)
+        .map(|(i, _)| i + 1)
+        .sum();
+    // a divider's index is one more than the number of packets before it
+    let before = |divider: &Packet| {
+        packets
+            .iter()
+            .filter(|p| compare(p, divider) == Ordering::Less)
+            .count()
+    };
+    Generated {
+        input: pairs.join("\n"),
+        part1: part1.into(),
+        part2: ((before(&divider(2)) + 1) * (before(&divider(6)) + 2)).into(),
+    }
+}
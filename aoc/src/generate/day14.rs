@@ -0,0 +1,69 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+use std::collections::HashSet;
+
+const SOURCE: (i32, i32) = (500, 0);
+
+// The units of sand that come to rest, stopping at the first that falls past `bottom` or, with a
+// floor, at the one that blocks the source.
+fn pour(rock: &HashSet<(i32, i32)>, bottom: i32, floor: bool) -> usize {
+    let mut blocked = rock.clone();
+    let mut rested = 0;
+    while !blocked.contains(&SOURCE) {
+        let (mut x, mut y) = SOURCE;
+        loop {
+            if y == bottom + 1 {
+                if !floor {
+                    return rested;
+                }
+                break;
+            }
+            match [x, x - 1, x + 1]
+                .into_iter()
+                .find(|x| !blocked.contains(&(*x, y + 1)))
+            {
+                Some(next) => (x, y) = (next, y + 1),
+                None => break,
+            }
+        }
+        blocked.insert((x, y));
+        rested += 1;
+    }
+    rested
+}
+
+/// `size` paths of rock below and around the source of the sand.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut paths = vec![];
+    let mut rock = HashSet::new();
+    for _ in 0..size.max(1) {
+        let (mut x, mut y) = (rng.gen_range(480..=520), rng.gen_range(2..=30));
+        let mut points = vec![(x, y)];
+        let mut horizontal = rng.gen();
+        for _ in 0..rng.gen_range(1..=5) {
+            let (x0, y0) = (x, y);
+            let step = rng.gen_range(1..=8) * if rng.gen() { 1 } else { -1 };
+            if horizontal {
+                x += step;
+            } else {
+                y = (y + step).max(1);
+            }
+            for rx in x0.min(x)..=x0.max(x) {
+                for ry in y0.min(y)..=y0.max(y) {
+                    rock.insert((rx, ry));
+                }
+            }
+            horizontal = !horizontal;
+            points.push((x, y));
+        }
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+        paths.push(points.join(" -> "));
+    }
+
+    let bottom = rock.iter().map(|(_, y)| *y).max().unwrap();
+    Generated {
+        input: super::lines(paths),
+        part1: pour(&rock, bottom, false).into(),
+        part2: pour(&rock, bottom, true).into(),
+    }
+}
@@ -0,0 +1,69 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+const ROW: i64 = 2_000_000;
+const SIZE: i64 = 4_000_000;
+
+fn distance((ax, ay): (i64, i64), (bx, by): (i64, i64)) -> i64 {
+    (ax - bx).abs() + (ay - by).abs()
+}
+
+/// `size` sensors in the search area plus four far away that, between them, see every position of
+/// the area but one: the distress beacon.
+///
+/// The four sit on the diagonals of the beacon, as far from it as the area is wide, and see
+/// everything up to one position short of it. The others see less than the beacon's distance.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let distress = (rng.gen_range(0..=SIZE), rng.gen_range(0..=SIZE));
+    let mut sensors = vec![];
+    for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let sensor = (distress.0 + sx * SIZE, distress.1 + sy * SIZE);
+        let beacon = (sensor.0 + sx * (2 * SIZE - 1), sensor.1);
+        sensors.push((sensor, beacon));
+    }
+    while sensors.len() < 4 + size {
+        let sensor = (rng.gen_range(0..=SIZE), rng.gen_range(0..=SIZE));
+        let reach = distance(sensor, distress) - 1;
+        if reach < 1 {
+            continue;
+        }
+        let range = rng.gen_range(1..=reach.min(1_000_000));
+        let dx = rng.gen_range(-range..=range);
+        let dy = (range - dx.abs()) * if rng.gen() { 1 } else { -1 };
+        sensors.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+    }
+    let lines = sensors.iter().map(|((x, y), (bx, by))| {
+        format!("Sensor at x={x}, y={y}: closest beacon is at x={bx}, y={by}")
+    });
+
+    // try every position of the row any sensor could see
+    let reaches: Vec<_> = sensors
+        .iter()
+        .map(|(sensor, beacon)| (*sensor, distance(*sensor, *beacon)))
+        .collect();
+    let lo = reaches
+        .iter()
+        .map(|((x, _), reach)| x - reach)
+        .min()
+        .unwrap();
+    let hi = reaches
+        .iter()
+        .map(|((x, _), reach)| x + reach)
+        .max()
+        .unwrap();
+    let seen = (lo..=hi)
+        .filter(|x| {
+            let position = (*x, ROW);
+            reaches
+                .iter()
+                .any(|(sensor, reach)| distance(*sensor, position) <= *reach)
+                && !sensors.iter().any(|(_, beacon)| *beacon == position)
+        })
+        .count();
+
+    Generated {
+        input: super::lines(lines),
+        part1: seen.into(),
+        part2: (distress.0 * SIZE + distress.1).into(),
+    }
+}
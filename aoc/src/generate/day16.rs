@@ -0,0 +1,151 @@
+use super::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+use std::collections::{HashMap, VecDeque};
+
+struct Valve {
+    name: String,
+    flow: u32,
+    tunnels: Vec<usize>,
+}
+
+// The most pressure released by opening valves from `at` on, for every set of valves opened.
+// Tries every order of the valves worth opening.
+#[allow(clippy::too_many_arguments)]
+fn explore(
+    valves: &[Valve],
+    distances: &[Vec<u32>],
+    useful: &[usize],
+    at: usize,
+    time: u32,
+    opened: u32,
+    released: u32,
+    best: &mut HashMap<u32, u32>,
+) {
+    let entry = best.entry(opened).or_default();
+    *entry = (*entry).max(released);
+    for (bit, valve) in useful.iter().enumerate() {
+        let cost = distances[at][*valve] + 1;
+        if opened & 1 << bit != 0 || cost >= time {
+            continue;
+        }
+        let time = time - cost;
+        let released = released + time * valves[*valve].flow;
+        explore(
+            valves,
+            distances,
+            useful,
+            *valve,
+            time,
+            opened | 1 << bit,
+            released,
+            best,
+        );
+    }
+}
+
+/// `size` valves, at most 12, connected by tunnels, the first of them `AA`.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.clamp(2, 12);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < count {
+        let name: String = (0..2).map(|_| rng.gen_range('A'..='Z')).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let mut valves: Vec<Valve> = names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| Valve {
+            name,
+            flow: if i > 0 && rng.gen_bool(0.6) {
+                rng.gen_range(1..=25)
+            } else {
+                0
+            },
+            tunnels: vec![],
+        })
+        .collect();
+    // a tree of tunnels, and a few more
+    let connect = |valves: &mut Vec<Valve>, a: usize, b: usize| {
+        if a != b && !valves[a].tunnels.contains(&b) {
+            valves[a].tunnels.push(b);
+            valves[b].tunnels.push(a);
+        }
+    };
+    for i in 1..count {
+        let j = rng.gen_range(0..i);
+        connect(&mut valves, i, j);
+    }
+    for _ in 0..count / 3 {
+        let (a, b) = (rng.gen_range(0..count), rng.gen_range(0..count));
+        connect(&mut valves, a, b);
+    }
+
+    let mut lines: Vec<String> = valves
+        .iter()
+        .map(|valve| {
+            let mut tunnels: Vec<&str> = valve
+                .tunnels
+                .iter()
+                .map(|t| valves[*t].name.as_str())
+                .collect();
+            tunnels.shuffle(rng);
+            let tunnels = match tunnels.as_slice() {
+                [one] => format!("tunnel leads to valve {one}"),
+                many => format!("tunnels lead to valves {}", many.join(", ")),
+            };
+            format!(
+                "Valve {} has flow rate={}; {tunnels}",
+                valve.name, valve.flow
+            )
+        })
+        .collect();
+    lines.shuffle(rng);
+
+    let distances: Vec<Vec<u32>> = (0..count)
+        .map(|from| {
+            let mut distance = vec![u32::MAX; count];
+            distance[from] = 0;
+            let mut queue = VecDeque::from([from]);
+            while let Some(at) = queue.pop_front() {
+                for next in &valves[at].tunnels {
+                    if distance[*next] == u32::MAX {
+                        distance[*next] = distance[at] + 1;
+                        queue.push_back(*next);
+                    }
+                }
+            }
+            distance
+        })
+        .collect();
+    let useful: Vec<usize> = (0..count).filter(|v| valves[*v].flow > 0).collect();
+
+    let mut alone = HashMap::new();
+    explore(&valves, &distances, &useful, 0, 30, 0, 0, &mut alone);
+    let mut with_elephant = HashMap::new();
+    explore(
+        &valves,
+        &distances,
+        &useful,
+        0,
+        26,
+        0,
+        0,
+        &mut with_elephant,
+    );
+    let mut part2 = 0;
+    for (mine, a) in &with_elephant {
+        for (theirs, b) in &with_elephant {
+            if mine & theirs == 0 {
+                part2 = part2.max(a + b);
+            }
+        }
+    }
+    Generated {
+        input: super::lines(lines),
+        part1: alone.values().max().copied().unwrap_or(0).into(),
+        part2: part2.into(),
+    }
+}
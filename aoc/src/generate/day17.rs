@@ -0,0 +1,63 @@
+use super::{Generated, Rng};
+use aoc_common::Answer;
+use rand::Rng as _;
+use std::collections::HashSet;
+
+// The tiles of each rock, from its bottom-left corner.
+const ROCKS: [&[(i64, i64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+// The height of the tower after dropping `rocks` rocks, one jet and one step down at a time.
+fn tower_height(jets: &[i64], rocks: usize) -> i64 {
+    let mut settled: HashSet<(i64, i64)> = HashSet::new();
+    let mut height = 0;
+    let mut jet = 0;
+    for rock in ROCKS.iter().cycle().take(rocks) {
+        let fits = |(x, y): (i64, i64), settled: &HashSet<(i64, i64)>| {
+            rock.iter().all(|(dx, dy)| {
+                let (x, y) = (x + dx, y + dy);
+                (0..7).contains(&x) && y >= 0 && !settled.contains(&(x, y))
+            })
+        };
+        let mut pos = (2, height + 3);
+        loop {
+            let pushed = (pos.0 + jets[jet], pos.1);
+            jet = (jet + 1) % jets.len();
+            if fits(pushed, &settled) {
+                pos = pushed;
+            }
+            let fallen = (pos.0, pos.1 - 1);
+            if !fits(fallen, &settled) {
+                break;
+            }
+            pos = fallen;
+        }
+        for (dx, dy) in rock.iter() {
+            settled.insert((pos.0 + dx, pos.1 + dy));
+            height = height.max(pos.1 + dy + 1);
+        }
+    }
+    height
+}
+
+/// `size` jets of hot gas. A trillion rocks are too many to drop one by one, so part 2 has no
+/// answer.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let jets: Vec<i64> = (0..size.max(1))
+        .map(|_| if rng.gen() { 1 } else { -1 })
+        .collect();
+    let input: String = jets
+        .iter()
+        .map(|j| if *j < 0 { '<' } else { '>' })
+        .collect();
+    Generated {
+        input: input + "\n",
+        part1: tower_height(&jets, 2022).into(),
+        part2: Answer::Missing,
+    }
+}
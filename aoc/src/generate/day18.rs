@@ -0,0 +1,56 @@
+use super::{Generated, Rng};
+use aoc_common::Point3;
+use rand::Rng as _;
+use std::collections::HashSet;
+
+const SIDES: [[i32; 3]; 6] = [
+    [1, 0, 0],
+    [-1, 0, 0],
+    [0, 1, 0],
+    [0, -1, 0],
+    [0, 0, 1],
+    [0, 0, -1],
+];
+
+fn neighbors(p: Point3) -> impl Iterator<Item = Point3> {
+    SIDES.into_iter().map(move |side| p + Point3::from(side))
+}
+
+/// `size` cubes of lava, packed in a box small enough for them to trap some air.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.max(1);
+    let side = ((count * 2) as f64).cbrt().ceil() as i32 + 1;
+    let mut cubes = HashSet::new();
+    while cubes.len() < count {
+        let coord = |rng: &mut Rng| rng.gen_range(0..side);
+        cubes.insert(Point3::from([coord(rng), coord(rng), coord(rng)]));
+    }
+    let lines = cubes.iter().map(|p| format!("{},{},{}", p.x, p.y, p.z));
+
+    let surface = cubes
+        .iter()
+        .flat_map(|p| neighbors(*p))
+        .filter(|n| !cubes.contains(n))
+        .count();
+    // fill the air around the droplet, one step past it on every side
+    let outside = |p: &Point3| [p.x, p.y, p.z].iter().all(|c| (-1..=side).contains(c));
+    let mut air = HashSet::from([Point3::from([-1, -1, -1])]);
+    let mut frontier = vec![Point3::from([-1, -1, -1])];
+    while let Some(p) = frontier.pop() {
+        for n in neighbors(p) {
+            if outside(&n) && !cubes.contains(&n) && air.insert(n) {
+                frontier.push(n);
+            }
+        }
+    }
+    let exterior = cubes
+        .iter()
+        .flat_map(|p| neighbors(*p))
+        .filter(|n| air.contains(n))
+        .count();
+    Generated {
+        input: super::lines(lines),
+        part1: surface.into(),
+        part2: exterior.into(),
+    }
+}
@@ -0,0 +1,94 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+// What each robot costs in ore, clay and obsidian, for the ore, clay, obsidian and geode robots.
+type Costs = [[u32; 3]; 4];
+
+// The most geodes that can be opened in `time` minutes, trying every robot to build next, and
+// giving up on plans that could not beat the best so far even with a geode robot every minute.
+fn max_geodes(costs: &Costs, time: u32) -> u32 {
+    fn dfs(costs: &Costs, time: u32, robots: [u32; 4], stock: [u32; 4], best: &mut u32) {
+        let geodes = stock[3] + robots[3] * time;
+        *best = (*best).max(geodes);
+        if geodes + time * time.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+        for robot in 0..4 {
+            // it can never pay to produce more of a material per minute than one robot costs
+            if robot < 3 && costs.iter().all(|cost| cost[robot] <= robots[robot]) {
+                continue;
+            }
+            // wait until there is enough of everything it takes
+            let wait = (0..3).try_fold(0, |wait, m| {
+                let cost = costs[robot][m];
+                if cost <= stock[m] {
+                    Some(wait)
+                } else if robots[m] == 0 {
+                    None
+                } else {
+                    Some(wait.max((cost - stock[m]).div_ceil(robots[m])))
+                }
+            });
+            let Some(wait) = wait.filter(|wait| wait + 1 < time) else {
+                continue;
+            };
+            let mut stock = stock;
+            for m in 0..4 {
+                stock[m] += robots[m] * (wait + 1);
+                if m < 3 {
+                    stock[m] -= costs[robot][m];
+                }
+            }
+            let mut robots = robots;
+            robots[robot] += 1;
+            dfs(costs, time - wait - 1, robots, stock, best);
+        }
+    }
+
+    let mut best = 0;
+    dfs(costs, time, [1, 0, 0, 0], [0; 4], &mut best);
+    best
+}
+
+/// `size` blueprints, at least three, with costs like those of the puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let blueprints: Vec<Costs> = (0..size.max(3))
+        .map(|_| {
+            [
+                [rng.gen_range(2..=4), 0, 0],
+                [rng.gen_range(2..=4), 0, 0],
+                [rng.gen_range(2..=4), rng.gen_range(4..=20), 0],
+                [rng.gen_range(2..=4), 0, rng.gen_range(6..=20)],
+            ]
+        })
+        .collect();
+    let lines = blueprints.iter().enumerate().map(|(i, costs)| {
+        format!(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            i + 1,
+            costs[0][0],
+            costs[1][0],
+            costs[2][0],
+            costs[2][1],
+            costs[3][0],
+            costs[3][2],
+        )
+    });
+
+    let part1: u32 = blueprints
+        .iter()
+        .enumerate()
+        .map(|(i, costs)| (i as u32 + 1) * max_geodes(costs, 24))
+        .sum();
+    let part2: u32 = blueprints[..3]
+        .iter()
+        .map(|costs| max_geodes(costs, 32))
+        .product();
+    Generated {
+        input: super::lines(lines),
+        part1: part1.into(),
+        part2: part2.into(),
+    }
+}
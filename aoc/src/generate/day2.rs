@@ -0,0 +1,45 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+// rock, paper and scissors
+const SHAPES: [u32; 3] = [1, 2, 3];
+
+fn outcome(opponent: u32, me: u32) -> u32 {
+    match (opponent, me) {
+        _ if opponent == me => 3,
+        (1, 2) | (2, 3) | (3, 1) => 6,
+        _ => 0,
+    }
+}
+
+/// `size` rounds.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let rounds: Vec<(usize, usize)> = (0..size.max(1))
+        .map(|_| (rng.gen_range(0..3), rng.gen_range(0..3)))
+        .collect();
+    let input = super::lines(
+        rounds
+            .iter()
+            .map(|(a, x)| format!("{} {}", ['A', 'B', 'C'][*a], ['X', 'Y', 'Z'][*x])),
+    );
+
+    let mut part1 = 0;
+    let mut part2 = 0;
+    for (a, x) in rounds {
+        let opponent = SHAPES[a];
+        // the second column is my shape...
+        part1 += SHAPES[x] + outcome(opponent, SHAPES[x]);
+        // ...or how the round must end, so try every shape until one does
+        let wanted = [0, 3, 6][x];
+        let me = SHAPES
+            .into_iter()
+            .find(|me| outcome(opponent, *me) == wanted)
+            .unwrap();
+        part2 += me + wanted;
+    }
+    Generated {
+        input,
+        part1: part1.into(),
+        part2: part2.into(),
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+// Mixes `numbers` `rounds` times, moving each by removing it and inserting it where it belongs.
+fn mix(numbers: &[i64], rounds: usize) -> i64 {
+    let n = numbers.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    for _ in 0..rounds {
+        for (i, number) in numbers.iter().enumerate() {
+            let at = order.iter().position(|j| *j == i).unwrap();
+            order.remove(at);
+            let to = (at as i64 + number).rem_euclid(n as i64 - 1);
+            order.insert(to as usize, i);
+        }
+    }
+    let zero = order.iter().position(|i| numbers[*i] == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|k| numbers[order[(zero + k) % n]])
+        .sum()
+}
+
+/// `size` numbers, duplicates allowed, one of them zero.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut numbers: Vec<i64> = (1..size.max(2))
+        .map(|_| rng.gen_range(-10000..=10000))
+        .filter(|n| *n != 0)
+        .collect();
+    let zero = rng.gen_range(0..=numbers.len());
+    numbers.insert(zero, 0);
+    if numbers.len() < 2 {
+        numbers.push(1);
+    }
+
+    let decrypted: Vec<i64> = numbers.iter().map(|n| n * 811589153).collect();
+    Generated {
+        input: super::lines(&numbers),
+        part1: mix(&numbers, 1).into(),
+        part2: mix(&decrypted, 10).into(),
+    }
+}
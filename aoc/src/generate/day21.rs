@@ -0,0 +1,162 @@
+use super::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+use std::collections::HashMap;
+
+enum Job {
+    Number(u64),
+    // the names of the operands around the operator
+    Operation(String, char, String),
+}
+
+struct Monkeys {
+    jobs: Vec<(String, Job)>,
+    names: Vec<String>,
+}
+
+impl Monkeys {
+    fn add(&mut self, rng: &mut Rng, job: Job) -> String {
+        let name = loop {
+            let name: String = (0..4).map(|_| rng.gen_range('a'..='z')).collect();
+            if !["root", "humn"].contains(&name.as_str()) && !self.names.contains(&name) {
+                break name;
+            }
+        };
+        self.names.push(name.clone());
+        self.jobs.push((name.clone(), job));
+        name
+    }
+
+    // A tree of about `depth` levels yelling a small number, with the number it yells.
+    fn tree(&mut self, rng: &mut Rng, depth: u32) -> (String, u64) {
+        if depth == 0 || rng.gen_bool(0.3) {
+            let n = rng.gen_range(1..=20);
+            return (self.add(rng, Job::Number(n)), n);
+        }
+        let (a, x) = self.tree(rng, depth - 1);
+        let (b, y) = self.tree(rng, depth - 1);
+        let (op, value) = match rng.gen_range(0..4) {
+            0 if x * y <= 1000 => ('*', x * y),
+            1 if x >= y => ('-', x - y),
+            2 if y > 0 && x % y == 0 => ('/', x / y),
+            _ => ('+', x + y),
+        };
+        (self.add(rng, Job::Operation(a, op, b)), value)
+    }
+
+    // A tree yelling exactly `target`.
+    fn exactly(&mut self, rng: &mut Rng, target: u64) -> String {
+        let (tree, value) = self.tree(rng, 3);
+        if value <= target {
+            let rest = self.add(rng, Job::Number(target - value));
+            self.add(rng, Job::Operation(tree, '+', rest))
+        } else {
+            let rest = self.add(rng, Job::Number(value - target));
+            self.add(rng, Job::Operation(tree, '-', rest))
+        }
+    }
+}
+
+// What a monkey yells, asking the monkeys it depends on first.
+fn yell(jobs: &HashMap<&str, &Job>, name: &str) -> u64 {
+    match jobs[name] {
+        Job::Number(n) => *n,
+        Job::Operation(a, op, b) => {
+            let (a, b) = (yell(jobs, a), yell(jobs, b));
+            match op {
+                '+' => a + b,
+                '-' => a - b,
+                '*' => a * b,
+                _ => a / b,
+            }
+        }
+    }
+}
+
+/// Monkeys whose jobs form a tree, with `humn` `size` operations below `root`.
+///
+/// The operations from `humn` up to `root` keep every number whole and positive both for what
+/// `humn` yells and for the number that makes both sides of `root` equal, which is picked first.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut monkeys = Monkeys {
+        jobs: vec![],
+        names: vec![],
+    };
+    let yelled = rng.gen_range(1..=1000);
+    let answer = rng.gen_range(1..=1000);
+    monkeys.jobs.push(("humn".to_string(), Job::Number(yelled)));
+
+    // what the monkey on the path yells, when humn yells its number and when it yells the answer
+    let (mut path, mut values) = ("humn".to_string(), (yelled, answer));
+    for _ in 0..size.max(1) {
+        let (v1, v2) = values;
+        let low = v1.min(v2);
+        let (job, next) = match rng.gen_range(0..5) {
+            0 if v1.max(v2) <= 1_000_000_000 => {
+                let k = rng.gen_range(1..=5);
+                let other = monkeys.add(rng, Job::Number(k));
+                let job = if rng.gen() {
+                    Job::Operation(path, '*', other)
+                } else {
+                    Job::Operation(other, '*', path)
+                };
+                (job, (v1 * k, v2 * k))
+            }
+            1 if low > 1 => {
+                let k = rng.gen_range(1..low);
+                let other = monkeys.exactly(rng, k);
+                (Job::Operation(path, '-', other), (v1 - k, v2 - k))
+            }
+            2 => {
+                let k = rng.gen_range(v1.max(v2) + 1..=v1.max(v2) + 1000);
+                let other = monkeys.exactly(rng, k);
+                (Job::Operation(other, '-', path), (k - v1, k - v2))
+            }
+            3 if (2..=10).any(|d| v1 % d == 0 && v2 % d == 0) => {
+                let divisors: Vec<u64> = (2..=10).filter(|d| v1 % d == 0 && v2 % d == 0).collect();
+                let d = *divisors.choose(rng).unwrap();
+                let other = monkeys.add(rng, Job::Number(d));
+                (Job::Operation(path, '/', other), (v1 / d, v2 / d))
+            }
+            _ => {
+                let (other, k) = monkeys.tree(rng, 2);
+                let job = if rng.gen() {
+                    Job::Operation(path, '+', other)
+                } else {
+                    Job::Operation(other, '+', path)
+                };
+                (job, (v1 + k, v2 + k))
+            }
+        };
+        path = monkeys.add(rng, job);
+        values = next;
+    }
+    let other = monkeys.exactly(rng, values.1);
+    let root = if rng.gen() {
+        Job::Operation(path, '+', other)
+    } else {
+        Job::Operation(other, '+', path)
+    };
+    monkeys.jobs.push(("root".to_string(), root));
+
+    let mut lines: Vec<String> = monkeys
+        .jobs
+        .iter()
+        .map(|(name, job)| match job {
+            Job::Number(n) => format!("{name}: {n}"),
+            Job::Operation(a, op, b) => format!("{name}: {a} {op} {b}"),
+        })
+        .collect();
+    lines.shuffle(rng);
+
+    let jobs: HashMap<&str, &Job> = monkeys
+        .jobs
+        .iter()
+        .map(|(name, job)| (name.as_str(), job))
+        .collect();
+    Generated {
+        input: super::lines(lines),
+        part1: yell(&jobs, "root").into(),
+        part2: answer.into(),
+    }
+}
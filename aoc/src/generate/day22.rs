@@ -0,0 +1,241 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+// The side of a face of the cube, and where the faces are on the board, in faces from the top
+// left. The solver folds the net of the puzzle input, so every board has the same one.
+const FACE: usize = 50;
+const NET: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+
+// east, south, west and north, in the order of the password's facings
+const DIRS: [(i64, i64); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+enum Ins {
+    Walk(usize),
+    Turn(char),
+}
+
+struct Board {
+    rows: Vec<Vec<char>>,
+}
+
+impl Board {
+    fn tile(&self, (r, c): (i64, i64)) -> char {
+        if r < 0 || c < 0 {
+            return ' ';
+        }
+        let row = self.rows.get(r as usize);
+        row.and_then(|row| row.get(c as usize))
+            .copied()
+            .unwrap_or(' ')
+    }
+}
+
+// Follows the path from the leftmost open tile of the top row, and returns the password.
+// `next` gives the tile and facing after one step.
+fn follow(
+    board: &Board,
+    path: &[Ins],
+    next: impl Fn((i64, i64), usize) -> ((i64, i64), usize),
+) -> usize {
+    let start = board.rows[0].iter().position(|t| *t == '.').unwrap();
+    let (mut pos, mut facing) = ((0, start as i64), 0);
+    for ins in path {
+        match ins {
+            Ins::Walk(steps) => {
+                for _ in 0..*steps {
+                    let (to, turned) = next(pos, facing);
+                    if board.tile(to) == '#' {
+                        break;
+                    }
+                    (pos, facing) = (to, turned);
+                }
+            }
+            Ins::Turn('R') => facing = (facing + 1) % 4,
+            Ins::Turn(_) => facing = (facing + 3) % 4,
+        }
+    }
+    1000 * (pos.0 as usize + 1) + 4 * (pos.1 as usize + 1) + facing
+}
+
+// One step on the flat board, coming back from the other side when it leaves the board.
+fn step_flat(board: &Board, (r, c): (i64, i64), facing: usize) -> ((i64, i64), usize) {
+    let (dr, dc) = DIRS[facing];
+    if board.tile((r + dr, c + dc)) != ' ' {
+        return ((r + dr, c + dc), facing);
+    }
+    let (mut r, mut c) = (r, c);
+    while board.tile((r - dr, c - dc)) != ' ' {
+        (r, c) = (r - dr, c - dc);
+    }
+    ((r, c), facing)
+}
+
+type V3 = [i64; 3];
+
+fn add(a: V3, b: V3) -> V3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: V3, k: i64) -> V3 {
+    a.map(|x| x * k)
+}
+
+fn dot(a: V3, b: V3) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// A face folded onto the cube: its corner, the directions of its columns and rows, and the
+// direction it faces. Coordinates are doubled so that the centers of tiles are whole.
+#[derive(Clone, Copy)]
+struct Frame {
+    origin: V3,
+    right: V3,
+    down: V3,
+    normal: V3,
+}
+
+// Folds the net around the cube, starting from the first face and turning each neighbor down
+// over the edge they share.
+fn fold() -> Vec<Frame> {
+    let side = 2 * FACE as i64;
+    let mut frames: Vec<Option<Frame>> = vec![None; NET.len()];
+    frames[0] = Some(Frame {
+        origin: [0, 0, 0],
+        right: [1, 0, 0],
+        down: [0, 1, 0],
+        normal: [0, 0, -1],
+    });
+    let mut queue = vec![0];
+    while let Some(i) = queue.pop() {
+        let f = frames[i].unwrap();
+        let (row, col) = NET[i];
+        let inward = scale(f.normal, -1);
+        let neighbors = [
+            (
+                (row, col + 1),
+                Frame {
+                    origin: add(f.origin, scale(f.right, side)),
+                    right: inward,
+                    down: f.down,
+                    normal: f.right,
+                },
+            ),
+            (
+                (row + 1, col),
+                Frame {
+                    origin: add(f.origin, scale(f.down, side)),
+                    right: f.right,
+                    down: inward,
+                    normal: f.down,
+                },
+            ),
+            (
+                (row, col.wrapping_sub(1)),
+                Frame {
+                    origin: add(f.origin, scale(f.normal, -side)),
+                    right: f.normal,
+                    down: f.down,
+                    normal: scale(f.right, -1),
+                },
+            ),
+            (
+                (row.wrapping_sub(1), col),
+                Frame {
+                    origin: add(f.origin, scale(f.normal, -side)),
+                    right: f.right,
+                    down: f.normal,
+                    normal: scale(f.down, -1),
+                },
+            ),
+        ];
+        for (at, frame) in neighbors {
+            if let Some(j) = NET.iter().position(|face| *face == at) {
+                if frames[j].is_none() {
+                    frames[j] = Some(frame);
+                    queue.push(j);
+                }
+            }
+        }
+    }
+    frames.into_iter().map(Option::unwrap).collect()
+}
+
+// One step on the cube. Within a face it is a step on the board; over an edge, it goes from the
+// center of the tile to the edge in 3D, and from there down the side of the cube onto the next face.
+fn step_cube(frames: &[Frame], (r, c): (i64, i64), facing: usize) -> ((i64, i64), usize) {
+    let n = FACE as i64;
+    let (dr, dc) = DIRS[facing];
+    if (0..n).contains(&(r % n + dr)) && (0..n).contains(&(c % n + dc)) {
+        return ((r + dr, c + dc), facing);
+    }
+
+    let i = NET
+        .iter()
+        .position(|face| *face == ((r / n) as usize, (c / n) as usize))
+        .unwrap();
+    let f = frames[i];
+    let center = add(
+        f.origin,
+        add(
+            scale(f.right, 2 * (c % n) + 1),
+            scale(f.down, 2 * (r % n) + 1),
+        ),
+    );
+    let dir = add(scale(f.right, dc), scale(f.down, dr));
+    let pos = add(add(center, dir), scale(f.normal, -1));
+    let (dir, normal) = (scale(f.normal, -1), dir);
+
+    let i = frames.iter().position(|f| f.normal == normal).unwrap();
+    let f = frames[i];
+    let rel = add(pos, scale(f.origin, -1));
+    let (row, col) = ((dot(rel, f.down) - 1) / 2, (dot(rel, f.right) - 1) / 2);
+    let facing = DIRS
+        .iter()
+        .position(|d| *d == (dot(dir, f.down), dot(dir, f.right)))
+        .unwrap();
+    let (fr, fc) = NET[i];
+    ((fr as i64 * n + row, fc as i64 * n + col), facing)
+}
+
+/// The board of the puzzle input, with walls in random places, and a path of `size` moves.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut rows = vec![vec![' '; 3 * FACE]; 4 * FACE];
+    for (fr, fc) in NET {
+        for row in &mut rows[fr * FACE..(fr + 1) * FACE] {
+            for tile in &mut row[fc * FACE..(fc + 1) * FACE] {
+                *tile = if rng.gen_bool(0.1) { '#' } else { '.' };
+            }
+        }
+    }
+    rows[0][FACE] = '.';
+    for row in &mut rows {
+        while row.last() == Some(&' ') {
+            row.pop();
+        }
+    }
+    let board = Board { rows };
+
+    let mut path = vec![Ins::Walk(rng.gen_range(1..=FACE))];
+    for _ in 0..size {
+        path.push(Ins::Turn(if rng.gen() { 'L' } else { 'R' }));
+        path.push(Ins::Walk(rng.gen_range(1..=FACE)));
+    }
+    let notes: String = path
+        .iter()
+        .map(|ins| match ins {
+            Ins::Walk(n) => n.to_string(),
+            Ins::Turn(side) => side.to_string(),
+        })
+        .collect();
+    let input = super::lines(board.rows.iter().map(|row| row.iter().collect::<String>()))
+        + "\n"
+        + &notes
+        + "\n";
+
+    let frames = fold();
+    Generated {
+        input,
+        part1: follow(&board, &path, |pos, facing| step_flat(&board, pos, facing)).into(),
+        part2: follow(&board, &path, |pos, facing| step_cube(&frames, pos, facing)).into(),
+    }
+}
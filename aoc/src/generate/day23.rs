@@ -0,0 +1,96 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+use std::collections::{HashMap, HashSet};
+
+type Elf = (i32, i32);
+
+// north, south, west and east, each with the three positions that must be free to go there
+const PROPOSALS: [[Elf; 3]; 4] = [
+    [(0, -1), (-1, -1), (1, -1)],
+    [(0, 1), (-1, 1), (1, 1)],
+    [(-1, 0), (-1, -1), (-1, 1)],
+    [(1, 0), (1, -1), (1, 1)],
+];
+
+// Plays a round with the proposals starting from `first`, and tells whether any elf moved.
+fn round(elves: &mut HashSet<Elf>, first: usize) -> bool {
+    let free = |(x, y): Elf, (dx, dy): Elf| !elves.contains(&(x + dx, y + dy));
+    let mut targets: HashMap<Elf, Vec<Elf>> = HashMap::new();
+    for elf in elves.iter() {
+        let around = (-1..=1).flat_map(|dx| (-1..=1).map(move |dy| (dx, dy)));
+        if around.filter(|d| *d != (0, 0)).all(|d| free(*elf, d)) {
+            continue;
+        }
+        for i in 0..4 {
+            let proposal = PROPOSALS[(first + i) % 4];
+            if proposal.iter().all(|d| free(*elf, *d)) {
+                let (dx, dy) = proposal[0];
+                targets
+                    .entry((elf.0 + dx, elf.1 + dy))
+                    .or_default()
+                    .push(*elf);
+                break;
+            }
+        }
+    }
+    let mut moved = false;
+    for (target, proposed) in targets {
+        if let [elf] = proposed.as_slice() {
+            elves.remove(elf);
+            elves.insert(target);
+            moved = true;
+        }
+    }
+    moved
+}
+
+// The empty tiles of the smallest rectangle holding every elf.
+fn empty_ground(elves: &HashSet<Elf>) -> usize {
+    let xs = elves.iter().map(|e| e.0);
+    let ys = elves.iter().map(|e| e.1);
+    let width = xs.clone().max().unwrap() - xs.min().unwrap() + 1;
+    let height = ys.clone().max().unwrap() - ys.min().unwrap() + 1;
+    (width * height) as usize - elves.len()
+}
+
+/// A grove of `size` by `size` tiles with elves on about a third of them.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let n = size.max(1);
+    let mut rows: Vec<String> = (0..n)
+        .map(|_| {
+            (0..n)
+                .map(|_| if rng.gen_bool(0.35) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    if !rows.iter().any(|row| row.contains('#')) {
+        rows[0].replace_range(0..1, "#");
+    }
+    let mut elves: HashSet<Elf> = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| (x as i32, y as i32))
+        })
+        .collect();
+
+    let (mut part1, mut part2) = (None, None);
+    let mut rounds = 0;
+    while part1.is_none() || part2.is_none() {
+        let moved = round(&mut elves, rounds % 4);
+        rounds += 1;
+        if rounds == 10 {
+            part1 = Some(empty_ground(&elves));
+        }
+        if !moved && part2.is_none() {
+            part2 = Some(rounds);
+        }
+    }
+    Generated {
+        input: super::lines(rows),
+        part1: part1.unwrap().into(),
+        part2: part2.unwrap().into(),
+    }
+}
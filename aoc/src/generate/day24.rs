@@ -0,0 +1,101 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+use std::collections::HashSet;
+
+struct Valley {
+    width: i64,
+    height: i64,
+    blizzards: Vec<((i64, i64), char)>,
+}
+
+impl Valley {
+    // Where the blizzards are after `t` minutes.
+    fn blizzards(&self, t: i64) -> HashSet<(i64, i64)> {
+        let (w, h) = (self.width, self.height);
+        self.blizzards
+            .iter()
+            .map(|((x, y), dir)| match dir {
+                '>' => ((x + t).rem_euclid(w), *y),
+                '<' => ((x - t).rem_euclid(w), *y),
+                'v' => (*x, (y + t).rem_euclid(h)),
+                _ => (*x, (y - t).rem_euclid(h)),
+            })
+            .collect()
+    }
+
+    // The minute of arrival at `to` when leaving `from` at minute `t`, following every position
+    // that can be reached minute by minute. There is none if it takes longer than there are
+    // states of the valley.
+    fn cross(&self, from: (i64, i64), to: (i64, i64), t: i64) -> Option<i64> {
+        let (w, h) = (self.width, self.height);
+        let limit = t + (w * h + 2) * w * h;
+        let mut reached = HashSet::from([from]);
+        for t in t + 1..=limit {
+            let blizzards = self.blizzards(t);
+            reached = reached
+                .iter()
+                .flat_map(|(x, y)| [(*x, *y), (x + 1, *y), (x - 1, *y), (*x, y + 1), (*x, y - 1)])
+                .filter(|p| {
+                    let inside = (0..w).contains(&p.0) && (0..h).contains(&p.1);
+                    (inside || *p == from || *p == to) && !blizzards.contains(p)
+                })
+                .collect();
+            if reached.contains(&to) {
+                return Some(t);
+            }
+        }
+        None
+    }
+}
+
+/// A valley `size` tiles wide and two thirds as high, with blizzards on about a third of the
+/// tiles, none of them blowing through the entrance or the exit. It can always be crossed.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (width, height) = (size.max(2) as i64, (size * 2 / 3).max(2) as i64);
+    loop {
+        let mut blizzards = vec![];
+        for y in 0..height {
+            for x in 0..width {
+                if rng.gen_bool(0.65) {
+                    continue;
+                }
+                let dirs: &[char] = if x == 0 || x == width - 1 {
+                    &['<', '>']
+                } else {
+                    &['<', '>', '^', 'v']
+                };
+                blizzards.push(((x, y), dirs[rng.gen_range(0..dirs.len())]));
+            }
+        }
+        let valley = Valley {
+            width,
+            height,
+            blizzards,
+        };
+
+        let (start, goal) = ((0, -1), (width - 1, height));
+        let Some(there) = valley.cross(start, goal, 0) else {
+            continue;
+        };
+        let back = valley.cross(goal, start, there).unwrap();
+        let again = valley.cross(start, goal, back).unwrap();
+
+        let wall = "#".repeat(width as usize);
+        let mut rows = vec![format!("#.{wall}")];
+        for y in 0..height {
+            let row: String = (0..width)
+                .map(|x| {
+                    let blizzard = valley.blizzards.iter().find(|(p, _)| *p == (x, y));
+                    blizzard.map_or('.', |(_, dir)| *dir)
+                })
+                .collect();
+            rows.push(format!("#{row}#"));
+        }
+        rows.push(format!("{wall}.#"));
+        return Generated {
+            input: super::lines(rows),
+            part1: there.into(),
+            part2: again.into(),
+        };
+    }
+}
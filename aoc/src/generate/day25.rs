@@ -0,0 +1,50 @@
+use super::{Generated, Rng};
+use aoc_common::Answer;
+use rand::Rng as _;
+
+const DIGITS: [char; 5] = ['=', '-', '0', '1', '2'];
+
+fn decode(snafu: &str) -> i64 {
+    snafu.chars().fold(0, |n, c| {
+        5 * n + DIGITS.iter().position(|d| *d == c).unwrap() as i64 - 2
+    })
+}
+
+// Picks the digits from the highest place down, each leaving a remainder the places below can
+// still make up.
+fn encode(n: i64) -> String {
+    let mut len = 1;
+    while 5i64.pow(len) / 2 < n {
+        len += 1;
+    }
+    let mut digits = vec![];
+    let mut rest = n;
+    for place in (0..len).rev() {
+        let unit = 5i64.pow(place);
+        // the digit that leaves what the lower places can still make up
+        let reach = unit / 2;
+        let digit = (-2..=2).find(|d| (rest - d * unit).abs() <= reach).unwrap();
+        digits.push(DIGITS[(digit + 2) as usize]);
+        rest -= digit * unit;
+    }
+    let snafu: String = digits.into_iter().collect();
+    snafu.trim_start_matches('0').to_string()
+}
+
+/// `size` fuel requirements, written in SNAFU.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let numbers: Vec<i64> = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.gen_range(1..=12);
+            rng.gen_range(1..=10i64.pow(digits))
+        })
+        .collect();
+    let lines: Vec<String> = numbers.iter().map(|n| encode(*n)).collect();
+    debug_assert!(lines.iter().zip(&numbers).all(|(s, n)| decode(s) == *n));
+
+    Generated {
+        input: super::lines(lines),
+        part1: encode(numbers.iter().sum()).into(),
+        part2: Answer::Missing,
+    }
+}
@@ -0,0 +1,74 @@
+use super::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn priority(item: char) -> u32 {
+    ITEMS.find(item).unwrap() as u32 + 1
+}
+
+// The items of `pool` with `wanted` among them, in random order.
+fn fill(rng: &mut Rng, pool: &[char], wanted: &[char], len: usize) -> Vec<char> {
+    let mut items = wanted.to_vec();
+    while items.len() < len {
+        items.push(*pool.choose(rng).unwrap());
+    }
+    items.shuffle(rng);
+    items
+}
+
+// A rucksack holding `badge` whose compartments only share one item, drawn from `pool`.
+fn rucksack(rng: &mut Rng, mut pool: Vec<char>, badge: char) -> String {
+    pool.push(badge);
+    pool.shuffle(rng);
+    let common = pool.pop().unwrap();
+    let (left, right) = pool.split_at(pool.len() / 2);
+    let len = rng.gen_range(2..=16);
+    let mut wanted = (vec![common], vec![common]);
+    if common != badge {
+        if left.contains(&badge) {
+            wanted.0.push(badge);
+        } else {
+            wanted.1.push(badge);
+        }
+    }
+    let mut items = fill(rng, left, &wanted.0, len);
+    items.extend(fill(rng, right, &wanted.1, len));
+    items.into_iter().collect()
+}
+
+/// `size` groups of three elves, whose only item in common is their badge.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut bags = vec![];
+    for _ in 0..size.max(1) {
+        let mut items: Vec<char> = ITEMS.chars().collect();
+        items.shuffle(rng);
+        let badge = items.pop().unwrap();
+        // the elves of a group share no other item
+        for pool in items.chunks(items.len() / 3) {
+            bags.push(rucksack(rng, pool.to_vec(), badge));
+        }
+    }
+    let input = super::lines(&bags);
+
+    // compare every item with every other
+    let mut part1 = 0;
+    for bag in &bags {
+        let (left, right) = bag.split_at(bag.len() / 2);
+        let common = left.chars().find(|a| right.chars().any(|b| *a == b));
+        part1 += priority(common.unwrap());
+    }
+    let mut part2 = 0;
+    for group in bags.chunks(3) {
+        let badge = ITEMS
+            .chars()
+            .find(|item| group.iter().all(|bag| bag.contains(*item)));
+        part2 += priority(badge.unwrap());
+    }
+    Generated {
+        input,
+        part1: part1.into(),
+        part2: part2.into(),
+    }
+}
@@ -0,0 +1,30 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+use std::collections::HashSet;
+
+/// `size` pairs of elves, assigned sections up to 99.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut range = || {
+        let start = rng.gen_range(1..100u32);
+        (start, rng.gen_range(start..100))
+    };
+    let pairs: Vec<_> = (0..size.max(1)).map(|_| (range(), range())).collect();
+    let input = super::lines(
+        pairs
+            .iter()
+            .map(|((a, b), (c, d))| format!("{a}-{b},{c}-{d}")),
+    );
+
+    let (mut part1, mut part2) = (0u32, 0u32);
+    for ((a, b), (c, d)) in pairs {
+        let first: HashSet<u32> = (a..=b).collect();
+        let second: HashSet<u32> = (c..=d).collect();
+        part1 += (first.is_subset(&second) || second.is_subset(&first)) as u32;
+        part2 += !first.is_disjoint(&second) as u32;
+    }
+    Generated {
+        input,
+        part1: part1.into(),
+        part2: part2.into(),
+    }
+}
@@ -0,0 +1,68 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+fn tops(stacks: &[Vec<char>]) -> String {
+    stacks
+        .iter()
+        .map(|stack| stack.last().copied().unwrap_or(' '))
+        .collect()
+}
+
+/// Two to nine stacks of crates and `size` moves, none taking more crates than its stack holds.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = rng.gen_range(2..=9);
+    let stacks: Vec<Vec<char>> = (0..count)
+        .map(|i| {
+            let height = rng.gen_range((i == 0) as usize..=8);
+            (0..height).map(|_| rng.gen_range('A'..='Z')).collect()
+        })
+        .collect();
+
+    let mut drawing = vec![];
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_string(),
+            })
+            .collect();
+        drawing.push(row.join(" "));
+    }
+    let numbers: Vec<String> = (1..=count).map(|i| format!(" {i} ")).collect();
+    drawing.push(numbers.join(" "));
+
+    // the first machine moves crates one at a time, the second all at once
+    let mut first = stacks.clone();
+    let mut second = stacks;
+    let mut moves = vec![];
+    for _ in 0..size.max(1) {
+        let from = loop {
+            let from = rng.gen_range(0..count);
+            if !first[from].is_empty() {
+                break from;
+            }
+        };
+        let to = (from + rng.gen_range(1..count)) % count;
+        let n = rng.gen_range(1..=first[from].len());
+        for _ in 0..n {
+            let c = first[from].pop().unwrap();
+            first[to].push(c);
+        }
+        let mut lifted = vec![];
+        for _ in 0..n {
+            lifted.push(second[from].pop().unwrap());
+        }
+        while let Some(c) = lifted.pop() {
+            second[to].push(c);
+        }
+        moves.push(format!("move {n} from {} to {}", from + 1, to + 1));
+    }
+
+    Generated {
+        input: super::lines(drawing) + "\n" + &super::lines(moves),
+        part1: tops(&first).into(),
+        part2: tops(&second).into(),
+    }
+}
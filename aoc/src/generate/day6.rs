@@ -0,0 +1,31 @@
+use super::{Generated, Rng};
+use aoc_common::Answer;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+fn marker(stream: &[char], len: usize) -> Answer {
+    (len..=stream.len())
+        .find(|end| {
+            let window = &stream[end - len..*end];
+            // every pair of characters differs
+            (0..len).all(|i| (i + 1..len).all(|j| window[i] != window[j]))
+        })
+        .map_or(Answer::Missing, Answer::from)
+}
+
+/// `size` characters with few letters to choose from, ending with a start-of-message marker.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let letters = rng.gen_range(4..=26);
+    let mut stream: Vec<char> = (0..size)
+        .map(|_| (b'a' + rng.gen_range(0..letters)) as char)
+        .collect();
+    let mut end: Vec<char> = ('a'..='z').collect();
+    end.shuffle(rng);
+    stream.extend(&end[..14]);
+
+    Generated {
+        input: stream.iter().collect::<String>() + "\n",
+        part1: marker(&stream, 4),
+        part2: marker(&stream, 14),
+    }
+}
@@ -0,0 +1,126 @@
+use super::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+const DISK: u32 = 70000000;
+const UNUSED: u32 = 30000000;
+
+struct Dir {
+    name: String,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    files: Vec<(u32, String)>,
+}
+
+// A name that none of `taken` has.
+fn name(rng: &mut Rng, taken: &[&str]) -> String {
+    loop {
+        let len = rng.gen_range(1..=8);
+        let name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+        if !taken.contains(&name.as_str()) {
+            return name;
+        }
+    }
+}
+
+// The terminal session listing `dir` and then exploring its directories.
+fn explore(dirs: &[Dir], dir: usize, rng: &mut Rng, session: &mut Vec<String>) {
+    session.push("$ ls".to_string());
+    let mut listing: Vec<String> = dirs[dir]
+        .children
+        .iter()
+        .map(|child| format!("dir {}", dirs[*child].name))
+        .chain(
+            dirs[dir]
+                .files
+                .iter()
+                .map(|(size, name)| format!("{size} {name}")),
+        )
+        .collect();
+    listing.shuffle(rng);
+    session.extend(listing);
+    for child in &dirs[dir].children {
+        session.push(format!("$ cd {}", dirs[*child].name));
+        explore(dirs, *child, rng, session);
+        session.push("$ cd ..".to_string());
+    }
+}
+
+/// `size` directories besides the root, holding more than the 40000000 the update leaves free.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let root = Dir {
+        name: "/".to_string(),
+        parent: None,
+        children: vec![],
+        files: vec![],
+    };
+    let mut dirs = vec![root];
+    for _ in 0..size {
+        let parent = rng.gen_range(0..dirs.len());
+        let siblings: Vec<&str> = dirs[parent]
+            .children
+            .iter()
+            .map(|child| dirs[*child].name.as_str())
+            .collect();
+        let name = name(rng, &siblings);
+        let child = dirs.len();
+        dirs[parent].children.push(child);
+        dirs.push(Dir {
+            name,
+            parent: Some(parent),
+            children: vec![],
+            files: vec![],
+        });
+    }
+    for i in 0..dirs.len() {
+        for _ in 0..rng.gen_range(0..5) {
+            let mut taken: Vec<&str> = dirs[i]
+                .children
+                .iter()
+                .map(|c| dirs[*c].name.as_str())
+                .collect();
+            taken.extend(dirs[i].files.iter().map(|(_, name)| name.as_str()));
+            let file = name(rng, &taken);
+            let size = rng.gen_range(1000..=300000);
+            dirs[i].files.push((size, file));
+        }
+    }
+    let total: u32 = dirs
+        .iter()
+        .flat_map(|dir| &dir.files)
+        .map(|(size, _)| size)
+        .sum();
+    if total <= DISK - UNUSED {
+        let size = DISK - UNUSED - total + rng.gen_range(1..=1000000);
+        let taken: Vec<&str> = dirs[0]
+            .children
+            .iter()
+            .map(|c| dirs[*c].name.as_str())
+            .collect();
+        let file = name(rng, &taken) + ".dat";
+        dirs[0].files.push((size, file));
+    }
+
+    let mut session = vec!["$ cd /".to_string()];
+    explore(&dirs, 0, rng, &mut session);
+
+    // add every file to each directory it is in, all the way up to the root
+    let mut sizes = vec![0; dirs.len()];
+    for (i, dir) in dirs.iter().enumerate() {
+        for (size, _) in &dir.files {
+            let mut at = Some(i);
+            while let Some(d) = at {
+                sizes[d] += size;
+                at = dirs[d].parent;
+            }
+        }
+    }
+    let needed = sizes[0] - (DISK - UNUSED);
+    let part1: u32 = sizes.iter().filter(|size| **size <= 100000).sum();
+    let part2 = *sizes.iter().filter(|size| **size >= needed).min().unwrap();
+    Generated {
+        input: super::lines(session),
+        part1: part1.into(),
+        part2: part2.into(),
+    }
+}
@@ -0,0 +1,46 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+/// A forest of `size` by `size` trees.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let n = size.max(2);
+    let trees: Vec<Vec<u32>> = (0..n)
+        .map(|_| (0..n).map(|_| rng.gen_range(0..10)).collect())
+        .collect();
+    let input = super::lines(
+        trees
+            .iter()
+            .map(|row| row.iter().map(u32::to_string).collect::<String>()),
+    );
+
+    let mut visible = 0u32;
+    let mut best = 0u32;
+    for y in 0..n {
+        for x in 0..n {
+            let height = trees[y][x];
+            // the trees in each direction, nearest first
+            let lines: [Vec<u32>; 4] = [
+                (0..x).rev().map(|x| trees[y][x]).collect(),
+                (x + 1..n).map(|x| trees[y][x]).collect(),
+                (0..y).rev().map(|y| trees[y][x]).collect(),
+                (y + 1..n).map(|y| trees[y][x]).collect(),
+            ];
+            if lines.iter().any(|line| line.iter().all(|t| *t < height)) {
+                visible += 1;
+            }
+            let score: u32 = lines
+                .iter()
+                .map(|line| match line.iter().position(|t| *t >= height) {
+                    Some(blocked) => blocked as u32 + 1,
+                    None => line.len() as u32,
+                })
+                .product();
+            best = best.max(score);
+        }
+    }
+    Generated {
+        input,
+        part1: visible.into(),
+        part2: best.into(),
+    }
+}
@@ -0,0 +1,55 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+use std::collections::HashSet;
+
+// The positions the last knot of a rope with `knots` knots visits.
+fn tail_visits(moves: &[((i32, i32), u32)], knots: usize) -> usize {
+    let mut rope = vec![(0i32, 0i32); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+    for ((dx, dy), steps) in moves {
+        for _ in 0..*steps {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+            for i in 1..knots {
+                let (hx, hy) = rope[i - 1];
+                let (tx, ty) = rope[i];
+                // a knot only moves when it no longer touches the one ahead
+                if (hx - tx).abs() > 1 || (hy - ty).abs() > 1 {
+                    rope[i] = (tx + (hx - tx).signum(), ty + (hy - ty).signum());
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+/// `size` moves of the head of the rope.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let moves: Vec<(char, u32)> = (0..size.max(1))
+        .map(|_| {
+            (
+                ['L', 'R', 'U', 'D'][rng.gen_range(0..4)],
+                rng.gen_range(1..=20),
+            )
+        })
+        .collect();
+    let input = super::lines(moves.iter().map(|(dir, steps)| format!("{dir} {steps}")));
+
+    let moves: Vec<_> = moves
+        .into_iter()
+        .map(|(dir, steps)| {
+            let d = match dir {
+                'L' => (-1, 0),
+                'R' => (1, 0),
+                'U' => (0, 1),
+                _ => (0, -1),
+            };
+            (d, steps)
+        })
+        .collect();
+    Generated {
+        input,
+        part1: tail_visits(&moves, 2).into(),
+        part2: tail_visits(&moves, 10).into(),
+    }
+}
@@ -0,0 +1,91 @@
+//! Random puzzle inputs for every day, each with the answers of a brute-force oracle.
+//!
+//! The oracles solve the puzzle the obvious way, simulating step by step or trying every
+//! possibility, so that they share as little as possible with the solvers they check. Where even
+//! that is out of reach (day 17 dropping a trillion rocks), or the puzzle has no such part (day
+//! 25), the answer is [`Answer::Missing`]. The same day, size and seed always give the same input.
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use crate::days::Day;
+use aoc_common::Answer;
+use rand::SeedableRng;
+
+/// The random number generator behind every generator, chosen for its stable output.
+pub type Rng = rand_pcg::Pcg64;
+
+/// A generated puzzle input and its expected answers.
+#[derive(Debug)]
+pub struct Generated {
+    pub input: String,
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+/// Generates an input for `day`. What `size` counts depends on the day (elves for day 1, moves for
+/// day 9, the side of the grid for day 12...), and is raised to the smallest size the puzzle
+/// allows.
+pub fn generate(day: &Day, size: usize, seed: u64) -> Generated {
+    let mut rng = Rng::seed_from_u64(seed);
+    let generate = match day.input {
+        "day1" => day1::generate,
+        "day2" => day2::generate,
+        "day3" => day3::generate,
+        "day4" => day4::generate,
+        "day5" => day5::generate,
+        "day6" => day6::generate,
+        "day7" => day7::generate,
+        "day8" => day8::generate,
+        "day9" => day9::generate,
+        "day10" => day10::generate,
+        "day11" => day11::generate,
+        "day12" => day12::generate,
+        "day13" => day13::generate,
+        "day14" => day14::generate,
+        "day15" => day15::generate,
+        "day16" => day16::generate,
+        "day17" => day17::generate,
+        "day18" => day18::generate,
+        "day19" => day19::generate,
+        "day20" => day20::generate,
+        "day21" => day21::generate,
+        "day22" => day22::generate,
+        "day23" => day23::generate,
+        "day24" => day24::generate,
+        "day25" => day25::generate,
+        input => unreachable!("no generator for {input}"),
+    };
+    generate(&mut rng, size)
+}
+
+// One line per item, each ending with a newline like the puzzle inputs.
+fn lines<T: ToString>(items: impl IntoIterator<Item = T>) -> String {
+    items
+        .into_iter()
+        .map(|item| item.to_string() + "\n")
+        .collect()
+}
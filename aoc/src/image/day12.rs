@@ -0,0 +1,32 @@
+use super::{Frame, ACTIVE, FILL, MARK, WALL};
+use aoc_common::{ParseError, Point2};
+
+/// The search spreading over the hill, one step at a time: from the start for part 1, or from
+/// every lowest square for part 2.
+pub fn frames(input: &str, part: u8) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let hm = day12::parse(input)?;
+    let map = hm.map();
+    let starts: Vec<Point2> = if part == 1 {
+        vec![hm.start()]
+    } else {
+        map.points().filter(|p| map[*p] == 0).collect()
+    };
+    let distances = day12::distances(&starts, map);
+    let end = hm.end();
+    let last = distances.iter().filter_map(|(_, d)| *d).max().unwrap_or(0);
+
+    Ok((0..=last).map(move |step| {
+        let mut frame = Frame::new(Point2::new(0, 0), distances.width(), distances.height());
+        for (p, d) in distances.iter() {
+            let color = match d {
+                Some(d) if *d < step => FILL,
+                Some(d) if *d == step => ACTIVE,
+                Some(_) => WALL,
+                None => continue,
+            };
+            frame.set(p, color);
+        }
+        frame.set(end, MARK);
+        frame
+    }))
+}
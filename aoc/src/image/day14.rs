@@ -0,0 +1,42 @@
+use super::{Frame, ACTIVE, FILL, MARK, WALL};
+use aoc_common::{ParseError, Point2};
+use day14::{Cave, SOURCE};
+use std::iter;
+
+/// The cave after each unit of sand comes to rest: until sand falls into the abyss for part 1, or
+/// until it blocks the source for part 2.
+pub fn frames(input: &str, part: u8) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let mut cave = day14::parse(input)?;
+    let into_abyss = part == 1;
+    let mut done = false;
+    Ok(iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let sand = cave.drop_sand(into_abyss)?;
+        done = sand == SOURCE;
+        Some(draw(&cave, sand))
+    }))
+}
+
+// the cave as drawn so far, with the unit of sand at `last` standing out
+fn draw(cave: &Cave, last: day14::Point) -> Frame {
+    let text = cave.render();
+    let rows: Vec<&str> = text.lines().collect();
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let origin = Point2::new(cave.columns().0 as i32, 0);
+    let mut frame = Frame::new(origin, width, rows.len());
+    for (y, row) in rows.iter().enumerate() {
+        for (x, tile) in row.chars().enumerate() {
+            let color = match tile {
+                '#' => WALL,
+                'o' => FILL,
+                '+' => MARK,
+                _ => continue,
+            };
+            frame.set(origin + Point2::from((x, y)), color);
+        }
+    }
+    frame.set(Point2::from(last), ACTIVE);
+    frame
+}
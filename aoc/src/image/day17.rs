@@ -0,0 +1,38 @@
+use super::{Frame, ACTIVE, FILL, WALL};
+use aoc_common::{ParseError, Point2};
+use day17::{Chamber, CHAMBER_WIDTH};
+use std::iter;
+
+/// The tower after each of the 2022 rocks of part 1 comes to rest, with the floor at the bottom.
+pub fn frames(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let jets = day17::parse(input)?;
+    let mut jets = jets.into_iter().cycle();
+    let mut polys = day17::polyominos().take(2022);
+    let mut chamber = Chamber::new();
+    Ok(iter::from_fn(move || {
+        let rock = chamber.drop_rock(polys.next()?, &mut jets)?;
+        let height = chamber.tower_height() as i32;
+        // a column of wall on each side, and the floor under the tower; `y` grows downwards
+        // from the top of the tower
+        let origin = Point2::new(-1, -height);
+        let mut frame = Frame::new(origin, CHAMBER_WIDTH + 2, height as usize + 1);
+        for y in -height..=0 {
+            frame.set(Point2::new(-1, y), WALL);
+            frame.set(Point2::new(CHAMBER_WIDTH as i32, y), WALL);
+        }
+        for x in 0..CHAMBER_WIDTH as i32 {
+            frame.set(Point2::new(x, 0), WALL);
+        }
+        for (y, row) in chamber.rows().iter().enumerate().take(height as usize) {
+            for (x, tile) in row.iter().enumerate() {
+                if *tile != 0 {
+                    frame.set(Point2::new(x as i32, -(y as i32) - 1), FILL);
+                }
+            }
+        }
+        for tile in rock.tiles() {
+            frame.set(Point2::new(tile.x, -tile.y - 1), ACTIVE);
+        }
+        Some(frame)
+    }))
+}
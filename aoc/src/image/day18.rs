@@ -0,0 +1,35 @@
+use super::{Frame, FILL, MARK};
+use aoc_common::{ParseError, Point2, Point3};
+use std::collections::HashSet;
+
+/// The droplet cut into slices, one for each `z` from the bottom up, with the air trapped inside
+/// it standing out.
+pub fn frames(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let lava = day18::parse(input)?;
+    let trapped = day18::trapped(&lava);
+    let (min, max) = lava.iter().fold(
+        (
+            Point3::new(i32::MAX, i32::MAX, i32::MAX),
+            Point3::new(i32::MIN, i32::MIN, i32::MIN),
+        ),
+        |(min, max), p| {
+            (
+                Point3::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                Point3::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+            )
+        },
+    );
+    let (width, height) = ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+
+    Ok((min.z..=max.z).map(move |z| {
+        let mut frame = Frame::new(Point2::new(min.x, min.y), width, height);
+        let slice = |cubes: &HashSet<Point3>, frame: &mut Frame, color| {
+            for p in cubes.iter().filter(|p| p.z == z) {
+                frame.set(Point2::new(p.x, p.y), color);
+            }
+        };
+        slice(&lava, &mut frame, FILL);
+        slice(&trapped, &mut frame, MARK);
+        frame
+    }))
+}
@@ -0,0 +1,32 @@
+use super::{Frame, ACTIVE, FILL};
+use aoc_common::ParseError;
+use std::collections::HashSet;
+use std::iter;
+
+/// The elves before the first round and after each one: 10 rounds for part 1, until no elf moves
+/// for part 2.
+pub fn frames(input: &str, part: u8) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let mut elves = day23::parse(input)?;
+    let mut proposal_order = day23::proposal_order();
+    let limit = if part == 1 { 10 } else { usize::MAX };
+    let mut first = Some(Frame::from_points(elves.values(), FILL));
+    let mut rounds = 0;
+    let mut done = false;
+
+    let rest = iter::from_fn(move || {
+        if done || rounds == limit {
+            return None;
+        }
+        let before: HashSet<_> = elves.iter().map(|(id, p)| (*id, *p)).collect();
+        done = !day23::round(&mut elves, &mut proposal_order);
+        rounds += 1;
+        let mut frame = Frame::from_points(elves.values(), FILL);
+        for (id, p) in &elves {
+            if !before.contains(&(*id, *p)) {
+                frame.set(*p, ACTIVE);
+            }
+        }
+        Some(frame)
+    });
+    Ok(iter::from_fn(move || first.take()).chain(rest))
+}
@@ -0,0 +1,34 @@
+use super::{Frame, EMPTY, FILL, MARK, WALL};
+use aoc_common::{ParseError, Point2};
+
+/// The valley every minute until the expedition reaches the exit for part 1, or the exit again
+/// after going back for the snacks for part 2.
+pub fn frames(input: &str, part: u8) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let mut time = day24::parse(input)?;
+    let trips = if part == 1 { 1 } else { 3 };
+    let arrival = day24::crossings(&time, trips);
+    let (width, height) = (time.width(), time.height());
+
+    Ok((0..=arrival).map(move |t| {
+        let mut frame = Frame::new(Point2::new(-1, -1), width as usize + 2, height as usize + 2);
+        for x in -1..=width {
+            frame.set(Point2::new(x, -1), WALL);
+            frame.set(Point2::new(x, height), WALL);
+        }
+        for y in 0..height {
+            frame.set(Point2::new(-1, y), WALL);
+            frame.set(Point2::new(width, y), WALL);
+        }
+        // the entrance and the exit
+        frame.set(Point2::new(0, -1), EMPTY);
+        frame.set(Point2::new(width - 1, height), EMPTY);
+
+        for blizzard in time.slice(t).blizzards() {
+            let p = blizzard.pos();
+            let i = ((p.y + 1) * (width + 2) + p.x + 1) as usize;
+            // more than one blizzard on a tile
+            frame.cells[i] = if frame.cells[i] == FILL { MARK } else { FILL };
+        }
+        frame
+    }))
+}
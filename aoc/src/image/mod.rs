@@ -0,0 +1,363 @@
+//! Drawing the states of some days as PNG images and animated GIFs.
+//!
+//! A day with something to draw turns each state of its simulation into a [`Frame`]: a grid of
+//! cells, each holding the index of its color in a [`Palette`]. Every day uses the indices the
+//! same way, so that one palette suits them all:
+//!
+//! | index      | day 12        | day 14      | day 17       | day 18       | day 23       | day 24           |
+//! |------------|---------------|-------------|--------------|--------------|--------------|------------------|
+//! | [`EMPTY`]  | out of reach  | air         | air          | outside air  | ground       | ground           |
+//! | [`WALL`]   | not yet seen  | rock        | walls, floor |              |              | walls            |
+//! | [`FILL`]   | reached       | sand        | rocks        | lava         | elves        | a blizzard       |
+//! | [`ACTIVE`] | frontier      | latest sand | latest rock  |              | elves moved  |                  |
+//! | [`MARK`]   | best signal   | source      |              | trapped air  |              | several blizzards|
+//!
+//! Frames are placed by their origin in the coordinates of the puzzle, so that a GIF of a growing
+//! cave or tower keeps still what does not move.
+
+mod day12;
+mod day14;
+mod day17;
+mod day18;
+mod day23;
+mod day24;
+
+use crate::days::Day;
+use aoc_common::{ParseError, Point2};
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+pub const EMPTY: u8 = 0;
+pub const WALL: u8 = 1;
+pub const FILL: u8 = 2;
+pub const ACTIVE: u8 = 3;
+pub const MARK: u8 = 4;
+
+/// A state to draw, as the palette indices of a rectangle of cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Where the top left cell is, in the coordinates of the puzzle with `y` growing downwards.
+    pub origin: Point2,
+    pub width: usize,
+    pub height: usize,
+    /// The cells row by row from the top.
+    pub cells: Vec<u8>,
+}
+
+impl Frame {
+    /// A frame of [`EMPTY`] cells.
+    pub fn new(origin: Point2, width: usize, height: usize) -> Frame {
+        Frame {
+            origin,
+            width,
+            height,
+            cells: vec![EMPTY; width * height],
+        }
+    }
+
+    /// The smallest frame holding every point, each with the color `color`.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Point2>, color: u8) -> Frame {
+        let points: Vec<&Point2> = points.into_iter().collect();
+        let (min, max) = bounds(points.iter().map(|p| **p));
+        let size = max - min;
+        let mut frame = Frame::new(min, size.x as usize + 1, size.y as usize + 1);
+        for p in points {
+            frame.set(*p, color);
+        }
+        frame
+    }
+
+    /// Colors the cell at `p`, in the coordinates of the puzzle, if it is in the frame.
+    pub fn set(&mut self, p: Point2, color: u8) {
+        let Point2 { x, y } = p - self.origin;
+        if (0..self.width as i32).contains(&x) && (0..self.height as i32).contains(&y) {
+            self.cells[y as usize * self.width + x as usize] = color;
+        }
+    }
+
+    // the cell after the bottom right one, in the coordinates of the puzzle
+    fn end(&self) -> Point2 {
+        self.origin + Point2::new(self.width as i32, self.height as i32)
+    }
+}
+
+// the smallest and the largest coordinates of `points`
+fn bounds(points: impl Iterator<Item = Point2>) -> (Point2, Point2) {
+    points.fold(
+        (
+            Point2::new(i32::MAX, i32::MAX),
+            Point2::new(i32::MIN, i32::MIN),
+        ),
+        |(min, max), p| {
+            (
+                Point2::new(min.x.min(p.x), min.y.min(p.y)),
+                Point2::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        },
+    )
+}
+
+/// The colors of the cells, as RGB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette(Vec<[u8; 3]>);
+
+impl Palette {
+    // Indices past the end of the palette take its last color.
+    fn color(&self, index: u8) -> [u8; 3] {
+        self.0[(index as usize).min(self.0.len() - 1)]
+    }
+
+    // The palette padded with its last color to cover every index of `frames`, as encoders
+    // take it: RGB triplets one after the other.
+    fn bytes(&self, frames: &[Frame]) -> Vec<u8> {
+        let max = frames.iter().flat_map(|f| &f.cells).max().copied();
+        let len = self.0.len().max(max.map_or(0, |m| m as usize + 1));
+        (0..len).flat_map(|i| self.color(i as u8)).collect()
+    }
+}
+
+/// A dark background, grey walls, sand-colored fill, red for what is active and blue for marks.
+impl Default for Palette {
+    fn default() -> Self {
+        Palette(vec![
+            [0x1e, 0x1e, 0x2e],
+            [0x80, 0x80, 0x88],
+            [0xe8, 0xc1, 0x70],
+            [0xe0, 0x50, 0x50],
+            [0x50, 0x90, 0xe0],
+        ])
+    }
+}
+
+/// Colors separated by commas, each as `rrggbb` in hex with an optional `#`, in the order of the
+/// indices, e.g. `#000000,#ffffff`.
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let colors = s
+            .split(',')
+            .map(|color| {
+                let hex = color.trim().trim_start_matches('#');
+                let channel = |i: usize| {
+                    hex.get(i..i + 2)
+                        .and_then(|c| u8::from_str_radix(c, 16).ok())
+                };
+                match (hex.len(), channel(0), channel(2), channel(4)) {
+                    (6, Some(r), Some(g), Some(b)) => Ok([r, g, b]),
+                    _ => Err(format!("`{color}` is not a color `rrggbb`")),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Palette(colors))
+    }
+}
+
+impl fmt::Display for Palette {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, [r, g, b]) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "#{r:02x}{g:02x}{b:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The frames of part `part` of `day` on `input`, one per step of its simulation, or `None` if the
+/// day has nothing to draw.
+pub fn frames(
+    day: &Day,
+    input: &str,
+    part: u8,
+) -> Result<Option<Box<dyn Iterator<Item = Frame>>>, ParseError> {
+    let frames: Box<dyn Iterator<Item = Frame>> = match day.name {
+        "12" => Box::new(day12::frames(input, part)?),
+        "14" => Box::new(day14::frames(input, part)?),
+        "17" => Box::new(day17::frames(input)?),
+        "18" => Box::new(day18::frames(input)?),
+        "23" => Box::new(day23::frames(input, part)?),
+        "24" => Box::new(day24::frames(input, part)?),
+        _ => return Ok(None),
+    };
+    Ok(Some(frames))
+}
+
+/// Every `every`th frame, starting with the first, and always the last one.
+pub fn sample(frames: impl Iterator<Item = Frame>, every: usize) -> Vec<Frame> {
+    let mut sampled = vec![];
+    let mut last = None;
+    for (i, frame) in frames.enumerate() {
+        if i % every == 0 {
+            sampled.push(frame);
+            last = None;
+        } else {
+            last = Some(frame);
+        }
+    }
+    sampled.extend(last);
+    sampled
+}
+
+// The frames drawn on a canvas large enough for all of them, each cell `scale` pixels wide, as
+// the width and height of the canvas and the palette indices of its pixels for each frame.
+fn paint(frames: &[Frame], scale: usize) -> (usize, usize, Vec<Vec<u8>>) {
+    let (min, _) = bounds(frames.iter().map(|f| f.origin));
+    let (_, max) = bounds(frames.iter().map(|f| f.end()));
+    let (width, height) = ((max.x - min.x) as usize, (max.y - min.y) as usize);
+
+    let pixels = frames
+        .iter()
+        .map(|frame| {
+            let mut pixels = vec![EMPTY; width * height * scale * scale];
+            let offset = frame.origin - min;
+            for (i, cell) in frame.cells.iter().enumerate() {
+                let x = offset.x as usize + i % frame.width;
+                let y = offset.y as usize + i / frame.width;
+                for row in y * scale..(y + 1) * scale {
+                    let start = row * width * scale + x * scale;
+                    pixels[start..start + scale].fill(*cell);
+                }
+            }
+            pixels
+        })
+        .collect();
+    (width * scale, height * scale, pixels)
+}
+
+/// Writes `frame` as a PNG image with `palette`, each cell `scale` pixels wide.
+pub fn write_png(
+    out: impl Write,
+    frame: &Frame,
+    palette: &Palette,
+    scale: usize,
+) -> io::Result<()> {
+    let frames = [frame.clone()];
+    let (width, height, pixels) = paint(&frames, scale);
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette.bytes(&frames));
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer
+        .write_image_data(&pixels[0])
+        .map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+/// Writes `frames` as an animated GIF with `palette`, each cell `scale` pixels wide, showing each
+/// frame for `delay` hundredths of a second and looping forever.
+pub fn write_gif(
+    out: impl Write,
+    frames: &[Frame],
+    palette: &Palette,
+    scale: usize,
+    delay: u16,
+) -> io::Result<()> {
+    let (width, height, pixels) = paint(frames, scale);
+    let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(w), Ok(h)) => (w, h),
+        _ => {
+            return Err(io::Error::other(format!(
+                "a {width}x{height} image is too large for a GIF"
+            )))
+        }
+    };
+    let mut encoder =
+        gif::Encoder::new(out, width, height, &palette.bytes(frames)).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    for pixels in pixels {
+        let frame = gif::Frame {
+            width,
+            height,
+            delay,
+            buffer: Cow::Owned(pixels),
+            ..Default::default()
+        };
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn test_palette() {
+        let palette: Palette = "#000000, ff8000".parse().unwrap();
+        assert_eq!(palette, Palette(vec![[0, 0, 0], [0xff, 0x80, 0]]));
+        assert_eq!(palette.to_string(), "#000000,#ff8000");
+        assert!("#00000".parse::<Palette>().is_err());
+        assert!("#gg0000".parse::<Palette>().is_err());
+        assert_eq!(
+            Palette::default().to_string().parse(),
+            Ok(Palette::default())
+        );
+    }
+
+    #[test]
+    fn test_paint() {
+        let a = Frame::from_points(&[Point2::new(1, 1)], FILL);
+        let b = Frame::from_points(&[Point2::new(0, 0), Point2::new(1, 0)], ACTIVE);
+        let (width, height, pixels) = paint(&[a, b], 2);
+        assert_eq!((width, height), (4, 4));
+        #[rustfmt::skip]
+        assert_eq!(pixels, vec![
+            vec![0, 0, 0, 0,
+                 0, 0, 0, 0,
+                 0, 0, 2, 2,
+                 0, 0, 2, 2],
+            vec![3, 3, 3, 3,
+                 3, 3, 3, 3,
+                 0, 0, 0, 0,
+                 0, 0, 0, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_sample() {
+        let frames = (0..10).map(|i| Frame::from_points(&[Point2::new(i, 0)], FILL));
+        let xs: Vec<i32> = sample(frames, 4).iter().map(|f| f.origin.x).collect();
+        assert_eq!(xs, vec![0, 4, 8, 9]);
+    }
+
+    // Every day draws its example, and the images decode again.
+    #[test]
+    fn test_examples() {
+        for name in ["12", "14", "17", "18", "23", "24"] {
+            let day = days::find(name).unwrap();
+            let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../input/examples")
+                .join(format!("day{name}.txt"));
+            let input = fs::read_to_string(path).unwrap();
+            let frames = frames(day, &input, 1).unwrap().unwrap();
+            let frames = sample(frames, 10);
+            assert!(!frames.is_empty(), "day {name}");
+
+            let mut png = vec![];
+            write_png(&mut png, frames.last().unwrap(), &Palette::default(), 3).unwrap();
+            let decoder = png::Decoder::new(png.as_slice());
+            assert!(decoder.read_info().is_ok(), "day {name}");
+
+            let mut gif = vec![];
+            write_gif(&mut gif, &frames, &Palette::default(), 3, 5).unwrap();
+            let decoded = gif::DecodeOptions::new().read_info(gif.as_slice());
+            let mut decoded = decoded.unwrap();
+            let mut count = 0;
+            while decoded.read_next_frame().unwrap().is_some() {
+                count += 1;
+            }
+            assert_eq!(count, frames.len(), "day {name}");
+        }
+        assert!(frames(days::find("1").unwrap(), "", 1).unwrap().is_none());
+    }
+}
@@ -0,0 +1,12 @@
+//! The registry of solvers, shared by the `aoc` binary and the golden-answer tests.
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod format;
+pub mod generate;
+pub mod image;
+pub mod parallel;
+#[cfg(feature = "profile")]
+pub mod profile;
+pub mod visualize;
@@ -0,0 +1,1043 @@
+use aoc::answers::{Check, Store, Verdict};
+use aoc::bench::{self, Baseline, Solution};
+use aoc::days::{self, Day, DAYS};
+use aoc::format::{self, Failure, Format, Record};
+use aoc::generate;
+use aoc::image::{self, Palette};
+use aoc::parallel::{self, Job, Outcome};
+#[cfg(feature = "profile")]
+use aoc::profile;
+use aoc::visualize;
+use aoc_common::input::Source;
+use aoc_common::logger;
+use aoc_common::Answer;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufWriter};
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: aoc run <day> [--part <1|2>] [--input <path|->] [--format <human|json|tsv>] [<day options>]
+       aoc run --all [--format <human|json|tsv>] [--timeout <seconds>]
+       aoc run <day> --visualize [--part <1|2>] [--input <path|->] [--fps <n>]
+       aoc bench [<day>] [--runs <n>] [--save <path>] [--compare <path>] [--threshold <percent>]
+       aoc submit --local <day> --part <1|2> [--answer <answer>] [--verdict <verdict>] [--input <path|->]
+       aoc check [<day>]
+       aoc profile [<day>]
+       aoc gen <day> [--size <n>] [--seed <n>]
+       aoc image <day> --out <path.png|path.gif> [--part <1|2>] [--input <path|->] [--scale <n>]
+                 [--palette <colors>] [--every <n>] [--delay <ms>]
+
+Options for every command:
+  -v, -vv           log the steps of the solvers to stderr, -vv also draws their state
+  --log <filter>    log levels by day, e.g. `day17=debug` or `warn,day21=trace`
+
+Options of the days, after the day for `aoc run`:
+  1: --width <u32|u64|u128>   the type calories add up in
+     --top <k>                the number of elves part 2 adds up
+  2: --rules <path>           the rules of the game, rock paper scissors without them
+     --analyze                report every meaning of the second column instead of the answers
+  3: --group-size <n>         the number of elves in a group";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run {
+        day: &'static str,
+        part: Option<u8>,
+        input: Option<String>,
+        format: Format,
+        /// The arguments left after the day, for the day's own options.
+        options: Vec<String>,
+    },
+    RunAll {
+        format: Format,
+        /// How long each day may take before it is given up on.
+        timeout: Duration,
+    },
+    Visualize {
+        day: &'static str,
+        part: u8,
+        input: Option<String>,
+        fps: u32,
+    },
+    Bench {
+        day: Option<&'static str>,
+        runs: usize,
+        save: Option<String>,
+        compare: Option<String>,
+        /// Slowdown that counts as a regression, as a fraction (0.1 for 10%).
+        threshold: f64,
+    },
+    Submit {
+        day: &'static str,
+        part: u8,
+        /// The answer to record, or the solver's answer if `None`.
+        answer: Option<String>,
+        verdict: Verdict,
+        input: Option<String>,
+    },
+    Check {
+        day: Option<&'static str>,
+    },
+    Profile {
+        day: Option<&'static str>,
+    },
+    Gen {
+        day: &'static str,
+        size: usize,
+        seed: u64,
+    },
+    Image {
+        day: &'static str,
+        part: u8,
+        input: Option<String>,
+        out: String,
+        /// Pixels per cell.
+        scale: usize,
+        palette: Palette,
+        /// Keep one frame in this many.
+        every: usize,
+        /// Milliseconds per frame of a GIF.
+        delay: u32,
+    },
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().map(String::as_str);
+    match args.next() {
+        Some("run") => parse_run(args),
+        Some("bench") => parse_bench(args),
+        Some("submit") => parse_submit(args),
+        Some("check") => parse_check(args),
+        Some("profile") => parse_profile(args),
+        Some("gen") => parse_gen(args),
+        Some("image") => parse_image(args),
+        Some(cmd) => Err(format!("unknown command `{cmd}`")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_run<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::default();
+    let mut all = false;
+    let mut visualize = false;
+    let mut fps = None;
+    let mut timeout = None;
+    let mut options = vec![];
+    while let Some(arg) = args.next() {
+        match arg {
+            "--all" => all = true,
+            "--visualize" => visualize = true,
+            "--fps" => {
+                fps = match args.next().map(str::parse) {
+                    Some(Ok(n)) if n > 0 => Some(n),
+                    _ => return Err("--fps expects a positive number".to_string()),
+                }
+            }
+            "--timeout" => {
+                timeout = match args.next().map(str::parse::<f64>) {
+                    Some(Ok(secs)) if secs > 0.0 && secs.is_finite() => {
+                        Some(Duration::from_secs_f64(secs))
+                    }
+                    _ => return Err("--timeout expects a positive number of seconds".to_string()),
+                }
+            }
+            "--format" => format = args.next().ok_or("--format expects a format")?.parse()?,
+            "--part" => {
+                part = match args.next() {
+                    Some("1") => Some(1),
+                    Some("2") => Some(2),
+                    _ => return Err("--part expects 1 or 2".to_string()),
+                }
+            }
+            "--input" => {
+                let path = args.next().ok_or("--input expects a path")?;
+                input = Some(path.to_string());
+            }
+            _ if day.is_none() && !arg.starts_with('-') => {
+                let found = days::find(arg).ok_or(format!("unknown day `{arg}`"))?;
+                day = Some(found.name);
+            }
+            // the day takes its own options, and tells apart those it does not know
+            _ if day.is_some() => options.push(arg.to_string()),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    if fps.is_some() && !visualize {
+        return Err("--fps only applies to --visualize".to_string());
+    }
+    if timeout.is_some() && !all {
+        return Err("--timeout only applies to --all".to_string());
+    }
+    match (day, all) {
+        (Some(_), true) => Err("--all cannot be combined with a day".to_string()),
+        (Some(_), false) if visualize && format != Format::default() => {
+            Err("--visualize cannot be combined with --format".to_string())
+        }
+        (Some(_), false) if visualize && !options.is_empty() => Err(format!(
+            "--visualize cannot be combined with options of the day, such as `{}`",
+            options[0]
+        )),
+        (Some(day), false) if visualize => Ok(Command::Visualize {
+            day,
+            part: part.unwrap_or(1),
+            input,
+            fps: fps.unwrap_or(10),
+        }),
+        (Some(day), false) => Ok(Command::Run {
+            day,
+            part,
+            input,
+            format,
+            options,
+        }),
+        (None, true) if part.is_some() || input.is_some() || visualize => {
+            Err("--all cannot be combined with --part, --input or --visualize".to_string())
+        }
+        (None, true) => Ok(Command::RunAll {
+            format,
+            timeout: timeout.unwrap_or(Duration::from_secs(60)),
+        }),
+        (None, false) => Err("missing day".to_string()),
+    }
+}
+
+fn parse_bench<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    let mut runs = 5;
+    let mut save = None;
+    let mut compare = None;
+    let mut threshold = 0.1;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--runs" => {
+                runs = match args.next().map(str::parse) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return Err("--runs expects a positive number".to_string()),
+                }
+            }
+            "--save" => {
+                let path = args.next().ok_or("--save expects a path")?;
+                save = Some(path.to_string());
+            }
+            "--compare" => {
+                let path = args.next().ok_or("--compare expects a path")?;
+                compare = Some(path.to_string());
+            }
+            "--threshold" => {
+                threshold = match args.next().map(str::parse::<f64>) {
+                    Some(Ok(pct)) if pct >= 0.0 => pct / 100.0,
+                    _ => return Err("--threshold expects a percentage".to_string()),
+                }
+            }
+            _ if day.is_none() && !arg.starts_with('-') => {
+                let found = days::find(arg).ok_or(format!("unknown day `{arg}`"))?;
+                day = Some(found.name);
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(Command::Bench {
+        day,
+        runs,
+        save,
+        compare,
+        threshold,
+    })
+}
+
+fn parse_submit<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut answer = None;
+    let mut verdict = Verdict::Correct;
+    let mut input = None;
+    let mut local = false;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--local" => local = true,
+            "--part" => {
+                part = match args.next() {
+                    Some("1") => Some(1),
+                    Some("2") => Some(2),
+                    _ => return Err("--part expects 1 or 2".to_string()),
+                }
+            }
+            "--answer" => {
+                let text = args.next().ok_or("--answer expects an answer")?;
+                answer = Some(text.to_string());
+            }
+            "--verdict" => verdict = args.next().ok_or("--verdict expects a verdict")?.parse()?,
+            "--input" => {
+                let path = args.next().ok_or("--input expects a path")?;
+                input = Some(path.to_string());
+            }
+            _ if day.is_none() && !arg.starts_with('-') => {
+                let found = days::find(arg).ok_or(format!("unknown day `{arg}`"))?;
+                day = Some(found.name);
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    if !local {
+        return Err("submit only records answers in the local store, with --local".to_string());
+    }
+    Ok(Command::Submit {
+        day: day.ok_or("missing day")?,
+        part: part.ok_or("missing --part")?,
+        answer,
+        verdict,
+        input,
+    })
+}
+
+fn parse_check<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    for arg in args {
+        match arg {
+            _ if day.is_none() && !arg.starts_with('-') => {
+                let found = days::find(arg).ok_or(format!("unknown day `{arg}`"))?;
+                day = Some(found.name);
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(Command::Check { day })
+}
+
+fn parse_profile<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    for arg in args {
+        match arg {
+            _ if day.is_none() && !arg.starts_with('-') => {
+                let found = days::find(arg).ok_or(format!("unknown day `{arg}`"))?;
+                day = Some(found.name);
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(Command::Profile { day })
+}
+
+fn parse_gen<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    let mut size = 10;
+    let mut seed = 0;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--size" => {
+                size = match args.next().map(str::parse) {
+                    Some(Ok(n)) => n,
+                    _ => return Err("--size expects a number".to_string()),
+                }
+            }
+            "--seed" => {
+                seed = match args.next().map(str::parse) {
+                    Some(Ok(n)) => n,
+                    _ => return Err("--seed expects a number".to_string()),
+                }
+            }
+            _ if day.is_none() && !arg.starts_with('-') => {
+                let found = days::find(arg).ok_or(format!("unknown day `{arg}`"))?;
+                day = Some(found.name);
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    let day = day.ok_or("missing day")?;
+    Ok(Command::Gen { day, size, seed })
+}
+
+fn parse_image<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = 1;
+    let mut input = None;
+    let mut out = None;
+    let mut scale = 4;
+    let mut palette = Palette::default();
+    let mut every = 1;
+    let mut delay = 50;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--part" => {
+                part = match args.next() {
+                    Some("1") => 1,
+                    Some("2") => 2,
+                    _ => return Err("--part expects 1 or 2".to_string()),
+                }
+            }
+            "--input" => {
+                let path = args.next().ok_or("--input expects a path")?;
+                input = Some(path.to_string());
+            }
+            "--out" => {
+                out = match args.next() {
+                    Some(path) if path.ends_with(".png") || path.ends_with(".gif") => {
+                        Some(path.to_string())
+                    }
+                    _ => return Err("--out expects a path ending in .png or .gif".to_string()),
+                }
+            }
+            "--scale" => {
+                scale = match args.next().map(str::parse) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return Err("--scale expects a positive number".to_string()),
+                }
+            }
+            "--palette" => palette = args.next().ok_or("--palette expects colors")?.parse()?,
+            "--every" => {
+                every = match args.next().map(str::parse) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return Err("--every expects a positive number".to_string()),
+                }
+            }
+            "--delay" => {
+                delay = match args.next().map(str::parse) {
+                    Some(Ok(n)) => n,
+                    _ => return Err("--delay expects a number of milliseconds".to_string()),
+                }
+            }
+            _ if day.is_none() && !arg.starts_with('-') => {
+                let found = days::find(arg).ok_or(format!("unknown day `{arg}`"))?;
+                day = Some(found.name);
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(Command::Image {
+        day: day.ok_or("missing day")?,
+        part,
+        input,
+        out: out.ok_or("missing --out")?,
+        scale,
+        palette,
+        every,
+        delay,
+    })
+}
+
+// Reads the input and solves both parts, prefixing parse errors with the source so they read like
+// `input/day11.txt:3:24: expected operator '+' or '*'`.
+fn solve(day: &Day, path: Option<&str>) -> Result<Solution, Box<dyn Error>> {
+    let (source, input) = read_input(day, path)?;
+    (day.time)(&input).map_err(|e| format!("{source}:{e}").into())
+}
+
+// The records of the parts of a day, or only of `part`.
+fn records(day: &Day, solution: Solution, part: Option<u8>) -> Vec<Record<'static>> {
+    let Solution {
+        part1,
+        part2,
+        timings,
+    } = solution;
+    [(1, part1, timings.part1), (2, part2, timings.part2)]
+        .into_iter()
+        .filter(|(n, _, _)| part.is_none_or(|part| part == *n))
+        .map(|(part, answer, elapsed)| Record {
+            day: day.name,
+            part,
+            answer: Ok(answer),
+            elapsed,
+        })
+        .collect()
+}
+
+// `path` is `-` for stdin, and defaults to the day's input under `input/`.
+fn read_input(day: &Day, path: Option<&str>) -> Result<(Source, String), Box<dyn Error>> {
+    let source = Source::new(path, day.input);
+    let input = source.read().map_err(|e| format!("{source}: {e}"))?;
+    Ok((source, input))
+}
+
+// Solves the day as its own options ask, or writes the report they ask for instead. Arguments the
+// day does not take are errors.
+fn run(
+    day: &Day,
+    part: Option<u8>,
+    input: Option<&str>,
+    format: Format,
+    mut options: Vec<String>,
+) -> Result<(), Box<dyn Error>> {
+    let mode = (day.options)(&mut options)?;
+    if let Some(arg) = options.first() {
+        return Err(format!("unexpected argument `{arg}`").into());
+    }
+    let (source, input) = read_input(day, input)?;
+    match mode {
+        days::Mode::Time(time) => {
+            let solution = time(&input).map_err(|e| format!("{source}:{e}"))?;
+            let records = records(day, solution, part);
+            format::write(&mut io::stdout().lock(), format, &records)?;
+        }
+        days::Mode::Report(_) if part.is_some() || format != Format::default() => {
+            return Err("a report cannot be combined with --part or --format".into());
+        }
+        days::Mode::Report(report) => {
+            print!("{}", report(&input).map_err(|e| format!("{source}:{e}"))?);
+        }
+    }
+    Ok(())
+}
+
+// Solves every day at once on a thread pool, giving up on a day after `timeout`. A day that fails
+// to parse, panics or times out is listed as such, and fails the command once every day is listed.
+fn run_all(format: Format, timeout: Duration) -> Result<(), Box<dyn Error>> {
+    let mut jobs: Vec<Job<Result<Solution, String>>> = vec![];
+    for day in DAYS {
+        let (source, input) = read_input(day, None)?;
+        let source = source.to_string();
+        jobs.push(Box::new(move || {
+            (day.time)(&input).map_err(|e| format!("{source}:{e}"))
+        }));
+    }
+
+    let mut all = vec![];
+    let mut failed = 0;
+    for (day, outcome) in DAYS.iter().zip(parallel::run(jobs, 0, timeout)) {
+        let failure = match outcome {
+            Outcome::Done(Ok(solution)) => {
+                all.extend(records(day, solution, None));
+                continue;
+            }
+            Outcome::Done(Err(e)) => {
+                eprintln!("error: {e}");
+                Failure::Error
+            }
+            // the panic hook has told where already
+            Outcome::Panicked(_) => Failure::Panic,
+            Outcome::TimedOut => {
+                eprintln!("error: day {} took longer than {timeout:?}", day.name);
+                Failure::Timeout
+            }
+        };
+        failed += 1;
+        all.extend([1, 2].map(|part| Record {
+            day: day.name,
+            part,
+            answer: Err(failure),
+            elapsed: Duration::ZERO,
+        }));
+    }
+    format::write(&mut io::stdout().lock(), format, &all)?;
+    if failed > 0 {
+        return Err(format!("{failed} day(s) failed").into());
+    }
+    Ok(())
+}
+
+// Plays the simulation of the day in the terminal, once the input has been read in full: reading
+// it from stdin works too, as keys are read from the terminal.
+fn visualize(day: &Day, part: u8, input: Option<&str>, fps: u32) -> Result<(), Box<dyn Error>> {
+    let (source, input) = read_input(day, input)?;
+    let sim = visualize::simulation(day, &input, part)
+        .map_err(|e| format!("{source}:{e}"))?
+        .ok_or(format!("day {} has nothing to visualize", day.name))?;
+    let title = format!("day {} part {part}", day.name);
+    visualize::play(&title, sim, fps)?;
+    Ok(())
+}
+
+// Times every selected day `runs` times on its input and reports the median of each stage. The
+// medians can be saved as a baseline, and compared against one saved earlier.
+fn bench(
+    day: Option<&str>,
+    runs: usize,
+    save: Option<&str>,
+    compare: Option<&str>,
+    threshold: f64,
+) -> Result<(), Box<dyn Error>> {
+    let baseline: Option<Baseline> = match compare {
+        Some(path) => {
+            let json = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+            Some(serde_json::from_str(&json).map_err(|e| format!("{path}: {e}"))?)
+        }
+        None => None,
+    };
+
+    let selected: Vec<&Day> = match day {
+        Some(name) => vec![days::find(name).unwrap()],
+        None => DAYS.iter().collect(),
+    };
+
+    println!(
+        "{:<5}{:>12}{:>12}{:>12}",
+        "day", "parse", "part 1", "part 2"
+    );
+    let mut records = vec![];
+    for day in selected {
+        let (source, input) = read_input(day, None)?;
+        let timings = (0..runs)
+            .map(|_| (day.time)(&input).map(|solution| solution.timings))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("{source}:{e}"))?;
+        let median = bench::median(&timings);
+        // the Debug format picks a sensible unit, e.g. `1.25ms` or `310.20µs`
+        let [parse, part1, part2] =
+            [median.parse, median.part1, median.part2].map(|d| format!("{d:.2?}"));
+        println!("{:<5}{parse:>12}{part1:>12}{part2:>12}", day.name);
+        records.push(bench::Record::new(day.name, &median));
+    }
+    let current = Baseline {
+        runs,
+        days: records,
+    };
+
+    if let Some(path) = save {
+        let json = serde_json::to_string_pretty(&current)?;
+        fs::write(path, json + "\n").map_err(|e| format!("{path}: {e}"))?;
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = bench::regressions(&baseline, &current, threshold);
+        for r in &regressions {
+            println!(
+                "REGRESSED day {} {}: {:.2?} -> {:.2?}",
+                r.day, r.stage, r.before, r.after
+            );
+        }
+        if !regressions.is_empty() {
+            return Err(format!(
+                "{} stage(s) slower than the baseline by more than {}%",
+                regressions.len(),
+                threshold * 100.0
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+// Records the verdict on an answer in the store under `answers/`: the answer given, or else the
+// solver's answer on the input.
+fn submit(
+    day: &Day,
+    part: u8,
+    answer: Option<&str>,
+    verdict: Verdict,
+    input: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let answer = match answer {
+        Some(text) => text
+            .parse()
+            .map_or_else(|_| Answer::from(text), Answer::Int),
+        None => {
+            let solution = solve(day, input)?;
+            if part == 1 {
+                solution.part1
+            } else {
+                solution.part2
+            }
+        }
+    };
+    let store = Store::new(Store::default_dir());
+    let mut answers = store.load(day.name)?;
+    answers
+        .part_mut(part)
+        .record(&answer, verdict)
+        .map_err(|e| format!("day {} part {part}: {e}", day.name))?;
+    store.save(day.name, &answers)?;
+    println!(
+        "day {} part {part}: recorded {answer} as {verdict}",
+        day.name
+    );
+    Ok(())
+}
+
+// Solves every selected day on its input and tells which answers the store knows to be correct
+// or incorrect. Fails if any is incorrect.
+fn check(day: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let selected: Vec<&Day> = match day {
+        Some(name) => vec![days::find(name).unwrap()],
+        None => DAYS.iter().collect(),
+    };
+    let store = Store::new(Store::default_dir());
+
+    let mut incorrect = 0;
+    for day in selected {
+        let answers = store.load(day.name)?;
+        let solution = solve(day, None)?;
+        for (part, answer) in [(1, solution.part1), (2, solution.part2)] {
+            if answer == Answer::Missing {
+                continue;
+            }
+            let check = answers.part(part).check(&answer);
+            if let Check::Incorrect(_) = check {
+                incorrect += 1;
+            }
+            // multi-line answers (the day 10 CRT image) start on a line of their own
+            let answer = answer.to_string();
+            let sep = if answer.contains('\n') { "\n" } else { " " };
+            println!("day {} part {part}: {check}:{sep}{answer}", day.name);
+        }
+    }
+    if incorrect > 0 {
+        return Err(format!("{incorrect} incorrect answer(s)").into());
+    }
+    Ok(())
+}
+
+// Counts the heap usage of parsing and each part of every selected day on its input.
+#[cfg(feature = "profile")]
+fn profile(day: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let selected: Vec<&Day> = match day {
+        Some(name) => vec![days::find(name).unwrap()],
+        None => DAYS.iter().collect(),
+    };
+
+    println!(
+        "{:<5}{:<8}{:>12}{:>12}{:>13}",
+        "day", "stage", "peak", "allocated", "allocations"
+    );
+    for day in selected {
+        let (source, input) = read_input(day, None)?;
+        let profile = (day.profile)(&input).map_err(|e| format!("{source}:{e}"))?;
+        let stages = [
+            ("parse", profile.parse),
+            ("part 1", profile.part1),
+            ("part 2", profile.part2),
+        ];
+        for (stage, usage) in stages {
+            let [peak, allocated] = [usage.peak, usage.allocated].map(profile::human_bytes);
+            println!(
+                "{:<5}{stage:<8}{peak:>12}{allocated:>12}{:>13}",
+                day.name, usage.allocations
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "profile"))]
+fn profile(_day: Option<&str>) -> Result<(), Box<dyn Error>> {
+    Err("counting allocations needs the `profile` feature: \
+         cargo run --release --features profile --bin aoc -- profile"
+        .into())
+}
+
+// Writes a generated input to stdout, so that it can be piped into `aoc run <day> --input -`, and
+// the answers the oracle expects to stderr.
+fn gen(day: &Day, size: usize, seed: u64) -> Result<(), Box<dyn Error>> {
+    let generated = generate::generate(day, size, seed);
+    print!("{}", generated.input);
+    eprintln!("part 1: {}", generated.part1);
+    eprintln!("part 2: {}", generated.part2);
+    Ok(())
+}
+
+// Draws the frames of the day to `out`: the last one for a PNG, all of them for a GIF.
+#[allow(clippy::too_many_arguments)]
+fn image(
+    day: &Day,
+    part: u8,
+    input: Option<&str>,
+    out: &str,
+    scale: usize,
+    palette: &Palette,
+    every: usize,
+    delay: u32,
+) -> Result<(), Box<dyn Error>> {
+    let (source, input) = read_input(day, input)?;
+    let frames = image::frames(day, &input, part)
+        .map_err(|e| format!("{source}:{e}"))?
+        .ok_or(format!("day {} has nothing to draw", day.name))?;
+    let file = fs::File::create(out).map_err(|e| format!("{out}: {e}"))?;
+    let file = BufWriter::new(file);
+    let written = if Path::new(out).extension().is_some_and(|ext| ext == "png") {
+        // only the last frame matters
+        let last = frames
+            .last()
+            .ok_or(format!("day {} drew nothing", day.name))?;
+        image::write_png(file, &last, palette, scale)
+    } else {
+        let frames = image::sample(frames, every);
+        // GIFs count time in hundredths of a second
+        let delay = u16::try_from(delay / 10).unwrap_or(u16::MAX);
+        image::write_gif(file, &frames, palette, scale, delay)
+    };
+    written.map_err(|e| format!("{out}: {e}"))?;
+    Ok(())
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let command = match logger::extract_args(&mut args).and_then(|filter| {
+        logger::init(filter);
+        parse_args(&args)
+    }) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            options,
+        } => run(
+            days::find(day).unwrap(),
+            part,
+            input.as_deref(),
+            format,
+            options,
+        ),
+        Command::RunAll { format, timeout } => run_all(format, timeout),
+        Command::Bench {
+            day,
+            runs,
+            save,
+            compare,
+            threshold,
+        } => bench(day, runs, save.as_deref(), compare.as_deref(), threshold),
+        Command::Visualize {
+            day,
+            part,
+            input,
+            fps,
+        } => visualize(days::find(day).unwrap(), part, input.as_deref(), fps),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            verdict,
+            input,
+        } => submit(
+            days::find(day).unwrap(),
+            part,
+            answer.as_deref(),
+            verdict,
+            input.as_deref(),
+        ),
+        Command::Check { day } => check(day),
+        Command::Profile { day } => profile(day),
+        Command::Gen { day, size, seed } => gen(days::find(day).unwrap(), size, seed),
+        Command::Image {
+            day,
+            part,
+            input,
+            out,
+            scale,
+            palette,
+            every,
+            delay,
+        } => image(
+            days::find(day).unwrap(),
+            part,
+            input.as_deref(),
+            &out,
+            scale,
+            &palette,
+            every,
+            delay,
+        ),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(&args("run 16 --part 2 --input input/day16.txt")),
+            Ok(Command::Run {
+                day: "16",
+                part: Some(2),
+                input: Some("input/day16.txt".to_string()),
+                format: Format::Human,
+                options: vec![],
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run day7-2 --format json")),
+            Ok(Command::Run {
+                day: "7-2",
+                part: None,
+                input: None,
+                format: Format::Json,
+                options: vec![],
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 1 --width u64 --part 2")),
+            Ok(Command::Run {
+                day: "1",
+                part: Some(2),
+                input: None,
+                format: Format::Human,
+                options: args("--width u64"),
+            })
+        );
+        assert!(parse_args(&args("run --width u64 1")).is_err());
+        assert!(parse_args(&args("run 3 --visualize --group-size 4")).is_err());
+        assert_eq!(
+            parse_args(&args("run --all --format tsv")),
+            Ok(Command::RunAll {
+                format: Format::Tsv,
+                timeout: Duration::from_secs(60),
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run --all --timeout 2.5")),
+            Ok(Command::RunAll {
+                format: Format::Human,
+                timeout: Duration::from_millis(2500),
+            })
+        );
+        assert!(parse_args(&args("run --all --timeout 0")).is_err());
+        assert!(parse_args(&args("run 16 --timeout 5")).is_err());
+        assert!(parse_args(&args("run 1 --format xml")).is_err());
+        assert!(parse_args(&args("run 26")).is_err());
+        assert!(parse_args(&args("run 3 --part 3")).is_err());
+        assert!(parse_args(&args("run --all --part 1")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+
+        assert_eq!(
+            parse_args(&args("run 17 --visualize --part 2 --fps 30")),
+            Ok(Command::Visualize {
+                day: "17",
+                part: 2,
+                input: None,
+                fps: 30,
+            })
+        );
+        assert!(parse_args(&args("run 17 --fps 30")).is_err());
+        assert!(parse_args(&args("run 17 --visualize --format json")).is_err());
+        assert!(parse_args(&args("run --all --visualize")).is_err());
+
+        assert_eq!(
+            parse_args(&args(
+                "bench 20 --runs 10 --compare base.json --threshold 5"
+            )),
+            Ok(Command::Bench {
+                day: Some("20"),
+                runs: 10,
+                save: None,
+                compare: Some("base.json".to_string()),
+                threshold: 0.05,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("bench --save base.json")),
+            Ok(Command::Bench {
+                day: None,
+                runs: 5,
+                save: Some("base.json".to_string()),
+                compare: None,
+                threshold: 0.1,
+            })
+        );
+        assert!(parse_args(&args("bench --runs 0")).is_err());
+        assert!(parse_args(&args("bench 1 2")).is_err());
+
+        assert_eq!(
+            parse_args(&args(
+                "submit --local 16 --part 2 --answer 2500 --verdict too-high"
+            )),
+            Ok(Command::Submit {
+                day: "16",
+                part: 2,
+                answer: Some("2500".to_string()),
+                verdict: Verdict::TooHigh,
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("submit 5 --local --part 1")),
+            Ok(Command::Submit {
+                day: "5",
+                part: 1,
+                answer: None,
+                verdict: Verdict::Correct,
+                input: None,
+            })
+        );
+        assert!(parse_args(&args("submit 5 --part 1")).is_err());
+        assert!(parse_args(&args("submit --local 5")).is_err());
+        assert!(parse_args(&args("submit --local 5 --part 1 --verdict close")).is_err());
+        assert_eq!(
+            parse_args(&args("check 10")),
+            Ok(Command::Check { day: Some("10") })
+        );
+        assert_eq!(parse_args(&args("check")), Ok(Command::Check { day: None }));
+
+        assert_eq!(
+            parse_args(&args("profile 16")),
+            Ok(Command::Profile { day: Some("16") })
+        );
+        assert_eq!(
+            parse_args(&args("profile")),
+            Ok(Command::Profile { day: None })
+        );
+        assert!(parse_args(&args("profile 16 19")).is_err());
+
+        assert_eq!(
+            parse_args(&args("gen 12 --size 40 --seed 7")),
+            Ok(Command::Gen {
+                day: "12",
+                size: 40,
+                seed: 7,
+            })
+        );
+        assert!(parse_args(&args("gen --size 40")).is_err());
+        assert!(parse_args(&args("gen 9 --seed -1")).is_err());
+
+        assert_eq!(
+            parse_args(&args(
+                "image 24 --out valley.gif --part 2 --scale 8 --palette #000000,#ffffff --every 3 --delay 100"
+            )),
+            Ok(Command::Image {
+                day: "24",
+                part: 2,
+                input: None,
+                out: "valley.gif".to_string(),
+                scale: 8,
+                palette: "#000000,#ffffff".parse().unwrap(),
+                every: 3,
+                delay: 100,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("image 14 --out cave.png")),
+            Ok(Command::Image {
+                day: "14",
+                part: 1,
+                input: None,
+                out: "cave.png".to_string(),
+                scale: 4,
+                palette: Palette::default(),
+                every: 1,
+                delay: 50,
+            })
+        );
+        assert!(parse_args(&args("image 14")).is_err());
+        assert!(parse_args(&args("image 14 --out cave.jpg")).is_err());
+        assert!(parse_args(&args("image 14 --out cave.png --palette red")).is_err());
+        assert!(parse_args(&args("image 14 --out cave.png --scale 0")).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+//! Running jobs on a thread pool, giving up on those that run for too long or panic.
+//!
+//! A thread cannot be stopped from the outside, so a job that times out keeps its thread busy
+//! until it ends on its own, or until the process exits. [`run`] only waits for its result.
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How a job ended.
+#[derive(Debug, PartialEq)]
+pub enum Outcome<T> {
+    Done(T),
+    /// The job panicked, with this message.
+    Panicked(String),
+    /// The job ran for longer than the timeout.
+    TimedOut,
+}
+
+pub type Job<T> = Box<dyn FnOnce() -> T + Send>;
+
+// A job waiting for a thread, taken by the first to pick it up: after a pool is replaced, the
+// pool it replaces may still get to it.
+type Slot<T> = Arc<Mutex<Option<Job<T>>>>;
+
+enum Event<T> {
+    Started(Instant),
+    Finished(Result<T, String>),
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(s) => *s,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(s) => s.to_string(),
+            Err(_) => "(no message)".to_string(),
+        },
+    }
+}
+
+// Starts a pool of `threads` threads, or one per core for 0, and queues the jobs of `slots` on it.
+fn spawn<T: Send + 'static>(
+    threads: usize,
+    slots: &[(usize, Slot<T>)],
+    tx: &Sender<(usize, Event<T>)>,
+) -> ThreadPool {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .thread_name(|i| format!("aoc-{i}"))
+        .build()
+        .expect("failed to start the thread pool");
+    for (i, slot) in slots {
+        let (i, slot, tx) = (*i, Arc::clone(slot), tx.clone());
+        pool.spawn(move || {
+            let Some(job) = slot.lock().unwrap().take() else {
+                return;
+            };
+            // the receiver is gone once every job has an outcome
+            let _ = tx.send((i, Event::Started(Instant::now())));
+            let result = panic::catch_unwind(AssertUnwindSafe(job)).map_err(panic_message);
+            let _ = tx.send((i, Event::Finished(result)));
+        });
+    }
+    pool
+}
+
+/// Runs every job on a pool of `threads` threads, or one per core for 0, and returns how each
+/// ended, in the order of `jobs`. A job gets `timeout` from the moment a thread picks it up. Once
+/// every thread of the pool is held by a job that timed out, a new pool takes over the jobs that
+/// have not started yet.
+pub fn run<T: Send + 'static>(
+    jobs: Vec<Job<T>>,
+    threads: usize,
+    timeout: Duration,
+) -> Vec<Outcome<T>> {
+    let count = jobs.len();
+    let slots: Vec<(usize, Slot<T>)> = jobs
+        .into_iter()
+        .map(|job| Arc::new(Mutex::new(Some(job))))
+        .enumerate()
+        .collect();
+    let (tx, rx) = mpsc::channel();
+    let mut pool = spawn(threads, &slots, &tx);
+    // jobs of the last pool given up on that still hold their thread
+    let mut stuck = 0;
+
+    let mut outcomes: Vec<Option<Outcome<T>>> = (0..count).map(|_| None).collect();
+    let mut started: Vec<Option<Instant>> = vec![None; count];
+
+    while outcomes.iter().any(Option::is_none) {
+        if stuck >= pool.current_num_threads() {
+            let waiting: Vec<_> = slots
+                .iter()
+                .filter(|(i, _)| started[*i].is_none())
+                .map(|(i, slot)| (*i, Arc::clone(slot)))
+                .collect();
+            // dropping the pool leaves its threads to end once their jobs do
+            pool = spawn(threads, &waiting, &tx);
+            stuck = 0;
+        }
+
+        let deadline = (0..count)
+            .filter(|i| outcomes[*i].is_none())
+            .filter_map(|i| started[i].map(|start| start + timeout))
+            .min();
+        let event = match deadline {
+            Some(deadline) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match event {
+            Ok((i, Event::Started(start))) => started[i] = Some(start),
+            Ok((i, Event::Finished(result))) => {
+                if outcomes[i].is_none() {
+                    outcomes[i] = Some(match result {
+                        Ok(value) => Outcome::Done(value),
+                        Err(message) => Outcome::Panicked(message),
+                    });
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                for i in 0..count {
+                    if outcomes[i].is_none() && started[i].is_some_and(|s| now >= s + timeout) {
+                        outcomes[i] = Some(Outcome::TimedOut);
+                        stuck += 1;
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("the sender is kept"),
+        }
+    }
+    outcomes.into_iter().map(Option::unwrap).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_run() {
+        let jobs: Vec<Job<u32>> = vec![
+            Box::new(|| 1),
+            Box::new(|| panic!("no answer")),
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(30));
+                3
+            }),
+            Box::new(|| 4),
+        ];
+        let start = Instant::now();
+        // the jobs after the one that times out still get a thread
+        let outcomes = run(jobs, 1, Duration::from_millis(200));
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert_eq!(
+            outcomes,
+            vec![
+                Outcome::Done(1),
+                Outcome::Panicked("no answer".to_string()),
+                Outcome::TimedOut,
+                Outcome::Done(4),
+            ]
+        );
+    }
+}
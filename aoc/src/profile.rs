@@ -0,0 +1,160 @@
+//! Heap usage of the parse and solve stages of a day, counted by a global allocator.
+//!
+//! Only built with the `profile` feature, as counting slows every allocation of the process down
+//! a little. The counters are shared by all threads, so stages are measured one at a time.
+
+use aoc_common::{Answer, ParseError};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+// the system allocator, counting what goes through it
+struct Counting;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+impl Counting {
+    fn grow(&self, size: usize) {
+        let live = LIVE.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(live, Relaxed);
+        ALLOCATED.fetch_add(size, Relaxed);
+        ALLOCATIONS.fetch_add(1, Relaxed);
+    }
+}
+
+// SAFETY: every call is passed on to `System` as is, the counters aside.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Relaxed);
+    }
+
+    // A reallocation counts as freeing the old block and allocating the new one, as it may well
+    // copy it.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+            self.grow(new_size);
+        }
+        new
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// What one stage did with the heap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// The most bytes live at once, over what was live when the stage started.
+    pub peak: usize,
+    /// Bytes allocated in total, however soon they were freed.
+    pub allocated: usize,
+    /// Number of allocations, reallocations included.
+    pub allocations: usize,
+}
+
+/// The heap usage of each stage of a day.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Profile {
+    pub parse: Usage,
+    pub part1: Usage,
+    pub part2: Usage,
+}
+
+/// Runs `f`, counting its use of the heap.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+    let allocated = ALLOCATED.load(Relaxed);
+    let allocations = ALLOCATIONS.load(Relaxed);
+
+    let result = hint::black_box(f());
+
+    let usage = Usage {
+        peak: PEAK.load(Relaxed).saturating_sub(live),
+        allocated: ALLOCATED.load(Relaxed) - allocated,
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+    };
+    (result, usage)
+}
+
+/// Parses the input and solves both parts, like [`bench::time`](crate::bench::time), counting
+/// the heap usage of each stage on its own. The parsed input stays live through both parts, but
+/// only counts towards parsing.
+pub fn profile<'a, T>(
+    input: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    part1: impl FnOnce(&T) -> Answer,
+    part2: impl FnOnce(&T) -> Answer,
+) -> Result<Profile, ParseError> {
+    let (parsed, parse) = measure(|| parse(input));
+    let parsed = parsed?;
+    let (_, part1) = measure(|| part1(&parsed));
+    let (_, part2) = measure(|| part2(&parsed));
+    Ok(Profile {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// `bytes` in the largest binary unit it fills, e.g. `512 B`, `1.5 KiB` or `20.0 MiB`.
+pub fn human_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (v, usage) = measure(|| {
+            let freed = vec![0u8; 4096];
+            drop(freed);
+            vec![0u64; 100]
+        });
+        assert_eq!(v.len(), 100);
+        // other tests may allocate and free meanwhile, which throws the peak off
+        assert!(usage.allocated >= 4096 + 800);
+        assert!(usage.allocations >= 2);
+    }
+
+    #[test]
+    fn test_human_bytes() {
+        assert_eq!(human_bytes(512), "512 B");
+        assert_eq!(human_bytes(1536), "1.5 KiB");
+        assert_eq!(human_bytes(20 << 20), "20.0 MiB");
+    }
+}
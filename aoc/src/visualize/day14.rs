@@ -0,0 +1,53 @@
+use super::{crop, Simulation};
+use aoc_common::ParseError;
+use day14::{Cave, Point, SOURCE};
+
+/// The cave, one unit of sand at a time: until sand falls into the abyss for part 1, or until it
+/// blocks the source for part 2.
+pub struct Sand {
+    cave: Cave,
+    into_abyss: bool,
+    units: usize,
+    last: Point,
+    done: bool,
+}
+
+impl Sand {
+    pub fn new(input: &str, part: u8) -> Result<Sand, ParseError> {
+        Ok(Sand {
+            cave: day14::parse(input)?,
+            into_abyss: part == 1,
+            units: 0,
+            last: SOURCE,
+            done: false,
+        })
+    }
+}
+
+impl Simulation for Sand {
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        // sand falling into the abyss leaves the cave as it was
+        let Some(sand) = self.cave.drop_sand(self.into_abyss) else {
+            self.done = true;
+            return false;
+        };
+        self.units += 1;
+        self.last = sand;
+        self.done = sand == SOURCE;
+        true
+    }
+
+    // Around the last unit of sand to come to rest.
+    fn render(&self, width: usize, height: usize) -> String {
+        let (left, _) = self.cave.columns();
+        let focus = (self.last.0 - left, self.last.1);
+        crop(&self.cave.render(), width, height, focus)
+    }
+
+    fn status(&self) -> String {
+        format!("{} units of sand at rest", self.units)
+    }
+}
@@ -0,0 +1,74 @@
+use super::Simulation;
+use aoc_common::ParseError;
+use day17::{Chamber, Dir, Polyomino};
+
+/// The chamber, one push of a jet or one unit of falling at a time: 2022 rocks for part 1, and
+/// for part 2 as many as you care to watch.
+pub struct Rocks {
+    chamber: Chamber,
+    jets: Vec<Dir>,
+    jet: usize,
+    polys: Box<dyn Iterator<Item = Polyomino>>,
+    // a jet pushes the rock before each unit it falls
+    push: bool,
+    rocks: usize,
+    limit: usize,
+}
+
+impl Rocks {
+    pub fn new(input: &str, part: u8) -> Result<Rocks, ParseError> {
+        Ok(Rocks {
+            chamber: Chamber::new(),
+            jets: day17::parse(input)?,
+            jet: 0,
+            polys: Box::new(day17::polyominos()),
+            push: true,
+            rocks: 0,
+            limit: if part == 1 { 2022 } else { usize::MAX },
+        })
+    }
+}
+
+impl Simulation for Rocks {
+    fn step(&mut self) -> bool {
+        if self.chamber.rock().is_none() {
+            if self.rocks == self.limit {
+                return false;
+            }
+            let poly = self.polys.next().unwrap();
+            self.chamber.materialize_rock(poly);
+            self.push = true;
+        } else if self.push {
+            self.chamber.move_rock(self.jets[self.jet]);
+            self.jet = (self.jet + 1) % self.jets.len();
+            self.push = false;
+        } else {
+            if self.chamber.rock_at_rest() {
+                self.chamber.ossify_rock();
+                self.rocks += 1;
+            } else {
+                self.chamber.move_rock(Dir::D);
+            }
+            self.push = true;
+        }
+        true
+    }
+
+    fn render(&self, width: usize, height: usize) -> String {
+        // with room for the floor
+        let frame = self.chamber.render(height.saturating_sub(1));
+        let cropped: Vec<String> = frame
+            .lines()
+            .map(|line| line.chars().take(width).collect())
+            .collect();
+        cropped.join("\n")
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "{} rocks at rest, the tower is {} high",
+            self.rocks,
+            self.chamber.tower_height()
+        )
+    }
+}
@@ -0,0 +1,97 @@
+use super::{crop, Simulation};
+use aoc_common::{Dir4, ParseError};
+use day22::{Ins, Notes, P};
+use std::collections::HashMap;
+
+/// The path on the board, one tile or turn at a time: on the flat board for part 1, on the cube
+/// for part 2.
+pub struct Walk {
+    notes: Notes,
+    walk: fn(&day22::Map, &mut P, usize),
+    p: P,
+    // the next instruction, and the tiles left to walk of the current one
+    next: usize,
+    tiles: usize,
+    trail: HashMap<(usize, usize), Dir4>,
+}
+
+impl Walk {
+    pub fn new(input: &str, part: u8) -> Result<Walk, ParseError> {
+        let notes = day22::parse(input)?;
+        let p = P {
+            pos: notes.map().start(),
+            dir: Dir4::E,
+        };
+        Ok(Walk {
+            walk: if part == 1 {
+                day22::walk
+            } else {
+                day22::walk_cube
+            },
+            trail: HashMap::from([(p.pos, p.dir)]),
+            notes,
+            p,
+            next: 0,
+            tiles: 0,
+        })
+    }
+}
+
+impl Simulation for Walk {
+    fn step(&mut self) -> bool {
+        if self.tiles == 0 {
+            match self.notes.instructions().get(self.next) {
+                Some(Ins::Walk(n)) => self.tiles = *n,
+                Some(Ins::L) => self.p.dir = self.p.dir.turn_left(),
+                Some(Ins::R) => self.p.dir = self.p.dir.turn_right(),
+                None => return false,
+            }
+            self.next += 1;
+            self.trail.insert(self.p.pos, self.p.dir);
+            return true;
+        }
+
+        let from = self.p.pos;
+        (self.walk)(self.notes.map(), &mut self.p, 1);
+        // the rest of the tiles are against the wall
+        self.tiles = if self.p.pos == from {
+            0
+        } else {
+            self.tiles - 1
+        };
+        self.trail.insert(self.p.pos, self.p.dir);
+        true
+    }
+
+    // Around the current position, with the path drawn as in the puzzle and `@` where it ends.
+    fn render(&self, width: usize, height: usize) -> String {
+        let mut rows: Vec<Vec<char>> = self
+            .notes
+            .map()
+            .render()
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect();
+        for (&(y, x), dir) in &self.trail {
+            rows[y][x] = match dir {
+                Dir4::E => '>',
+                Dir4::S => 'v',
+                Dir4::W => '<',
+                Dir4::N => '^',
+            };
+        }
+        let (y, x) = self.p.pos;
+        rows[y][x] = '@';
+        let board: Vec<String> = rows.into_iter().map(String::from_iter).collect();
+        crop(&board.join("\n"), width, height, (x, y))
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "instruction {} of {}, password {}",
+            self.next,
+            self.notes.instructions().len(),
+            day22::password(&self.p)
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use super::{crop, Simulation};
+use aoc_common::{Dir4, ParseError, Point2};
+use std::collections::{HashMap, VecDeque};
+
+/// The elves, one round at a time: 10 rounds for part 1, until no elf moves for part 2.
+pub struct Elves {
+    elves: HashMap<i32, Point2>,
+    proposal_order: VecDeque<Dir4>,
+    rounds: usize,
+    limit: Option<usize>,
+    done: bool,
+}
+
+impl Elves {
+    pub fn new(input: &str, part: u8) -> Result<Elves, ParseError> {
+        Ok(Elves {
+            elves: day23::parse(input)?,
+            proposal_order: day23::proposal_order(),
+            rounds: 0,
+            limit: (part == 1).then_some(10),
+            done: false,
+        })
+    }
+}
+
+impl Simulation for Elves {
+    fn step(&mut self) -> bool {
+        if self.done || Some(self.rounds) == self.limit {
+            return false;
+        }
+        self.done = !day23::round(&mut self.elves, &mut self.proposal_order);
+        self.rounds += 1;
+        true
+    }
+
+    // Around the middle of the elves.
+    fn render(&self, width: usize, height: usize) -> String {
+        let grove = day23::render(&self.elves);
+        let rows = grove.lines().count();
+        let columns = grove.lines().next().map_or(0, str::len);
+        crop(&grove, width, height, (columns / 2, rows / 2))
+    }
+
+    fn status(&self) -> String {
+        let next = self.proposal_order[0];
+        if self.done {
+            format!("round {}: no elf moved", self.rounds)
+        } else {
+            format!("round {}, proposing {next:?} first", self.rounds)
+        }
+    }
+}
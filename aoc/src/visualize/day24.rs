@@ -0,0 +1,45 @@
+use super::{crop, Simulation};
+use aoc_common::ParseError;
+use day24::Time;
+
+/// The blizzards in the valley, one minute at a time, until the expedition reaches the exit for
+/// part 1, or the exit again after going back for the snacks for part 2.
+pub struct Valley {
+    time: Time,
+    minute: usize,
+    arrival: usize,
+}
+
+impl Valley {
+    pub fn new(input: &str, part: u8) -> Result<Valley, ParseError> {
+        let time = day24::parse(input)?;
+        let trips = if part == 1 { 1 } else { 3 };
+        Ok(Valley {
+            arrival: day24::crossings(&time, trips),
+            time,
+            minute: 0,
+        })
+    }
+}
+
+impl Simulation for Valley {
+    fn step(&mut self) -> bool {
+        if self.minute == self.arrival {
+            return false;
+        }
+        self.minute += 1;
+        self.time.slice(self.minute);
+        true
+    }
+
+    fn render(&self, width: usize, height: usize) -> String {
+        crop(&self.time.render(self.minute), width, height, (0, 0))
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "minute {}, the expedition arrives at minute {}",
+            self.minute, self.arrival
+        )
+    }
+}
@@ -0,0 +1,73 @@
+use super::Simulation;
+use aoc_common::{Dir4, ParseError, Point2};
+use std::collections::HashSet;
+
+/// The rope, one step of the head at a time: 2 knots for part 1, 10 for part 2.
+pub struct Rope {
+    moves: Vec<(Dir4, i32)>,
+    // the next move, and the steps left of the current one
+    next: usize,
+    steps: i32,
+    rope: Vec<Point2>,
+    visited: HashSet<Point2>,
+}
+
+impl Rope {
+    pub fn new(input: &str, part: u8) -> Result<Rope, ParseError> {
+        let knots = if part == 1 { 2 } else { 10 };
+        Ok(Rope {
+            moves: day9::parse(input)?,
+            next: 0,
+            steps: 0,
+            rope: vec![Point2::ORIGIN; knots],
+            visited: HashSet::from([Point2::ORIGIN]),
+        })
+    }
+}
+
+impl Simulation for Rope {
+    fn step(&mut self) -> bool {
+        while self.steps <= 0 {
+            match self.moves.get(self.next) {
+                Some((_, steps)) => self.steps = *steps,
+                None => return false,
+            }
+            self.next += 1;
+        }
+        day9::pull_rope(&mut self.rope, self.moves[self.next - 1].0);
+        self.visited.insert(*self.rope.last().unwrap());
+        self.steps -= 1;
+        true
+    }
+
+    // Around the head, with `H` for the head, the numbers of the other knots, `s` for the start
+    // and `#` for the positions the tail visited, as in the puzzle.
+    fn render(&self, width: usize, height: usize) -> String {
+        let head = self.rope[0];
+        let (x0, y0) = (head.x - width as i32 / 2, head.y - height as i32 / 2);
+        let mut out = String::new();
+        for y in y0..y0 + height as i32 {
+            for x in x0..x0 + width as i32 {
+                let p = Point2::new(x, y);
+                out.push(match self.rope.iter().position(|knot| *knot == p) {
+                    Some(0) => 'H',
+                    Some(i) => char::from_digit(i as u32 % 10, 10).unwrap(),
+                    None if p == Point2::ORIGIN => 's',
+                    None if self.visited.contains(&p) => '#',
+                    None => '.',
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "move {} of {}, the tail visited {} positions",
+            self.next,
+            self.moves.len(),
+            self.visited.len()
+        )
+    }
+}
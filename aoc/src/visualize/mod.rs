@@ -0,0 +1,275 @@
+//! Watching the simulations of some days step by step in the terminal.
+//!
+//! Each day with something to watch has a [`Simulation`] built on the state types of its crate:
+//! the rope of day 9, the cave of day 14, the chamber of day 17, the board of day 22, the elves of
+//! day 23 and the valley of day 24. [`play`] draws one in the terminal, with the controls listed
+//! under it: space to pause and resume, the right arrow to step, `+` and `-` for the frame rate and
+//! `q` to quit.
+
+mod day14;
+mod day17;
+mod day22;
+mod day23;
+mod day24;
+mod day9;
+
+use crate::days::Day;
+use aoc_common::ParseError;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Print, PrintStyledContent, Stylize};
+use crossterm::{cursor, queue, terminal};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// A puzzle simulated one step at a time.
+pub trait Simulation {
+    /// Takes the next step, and returns whether there was one to take.
+    fn step(&mut self) -> bool;
+
+    /// Draws the state in at most `width` columns and `height` rows.
+    fn render(&self, width: usize, height: usize) -> String;
+
+    /// A short description of the state, e.g. `rock 12, tower 20 high`.
+    fn status(&self) -> String;
+}
+
+/// The simulation of part `part` of `day` on `input`, or `None` if the day has nothing to watch.
+pub fn simulation(
+    day: &Day,
+    input: &str,
+    part: u8,
+) -> Result<Option<Box<dyn Simulation>>, ParseError> {
+    let sim: Box<dyn Simulation> = match day.name {
+        "9" => Box::new(day9::Rope::new(input, part)?),
+        "14" => Box::new(day14::Sand::new(input, part)?),
+        "17" => Box::new(day17::Rocks::new(input, part)?),
+        "22" => Box::new(day22::Walk::new(input, part)?),
+        "23" => Box::new(day23::Elves::new(input, part)?),
+        "24" => Box::new(day24::Valley::new(input, part)?),
+        _ => return Ok(None),
+    };
+    Ok(Some(sim))
+}
+
+// The `width` by `height` window of `text` around the character at `focus`, given as a column and
+// a row, keeping the window inside the text where it is large enough.
+fn crop(text: &str, width: usize, height: usize, focus: (usize, usize)) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = |focus: usize, len: usize, window: usize| {
+        focus
+            .saturating_sub(window / 2)
+            .min(len.saturating_sub(window))
+    };
+    let longest = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let (x0, y0) = (
+        start(focus.0, longest, width),
+        start(focus.1, lines.len(), height),
+    );
+    lines
+        .iter()
+        .skip(y0)
+        .take(height)
+        .map(|line| line.chars().skip(x0).take(width).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+const MAX_FPS: u32 = 1000;
+
+// Raw mode on the alternate screen for as long as it lives, so that the terminal is restored
+// however `play` returns.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        queue!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        io::stdout().flush()?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = queue!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = io::stdout().flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+// What is shown under the simulation.
+struct Controls<'a> {
+    title: &'a str,
+    playing: bool,
+    done: bool,
+    steps: usize,
+    fps: u32,
+}
+
+impl Controls<'_> {
+    fn line(&self, status: &str) -> String {
+        let state = match (self.done, self.playing) {
+            (true, _) => "done",
+            (false, true) => "playing",
+            (false, false) => "paused",
+        };
+        format!(
+            "{} | step {} | {status} | {state} at {} fps | \
+             space: play/pause, right: step, +/-: speed, q: quit",
+            self.title, self.steps, self.fps
+        )
+    }
+}
+
+fn draw(sim: &dyn Simulation, controls: &Controls) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let (width, height) = (width as usize, height as usize);
+    let frame = sim.render(width, height.saturating_sub(1));
+    let status: String = controls.line(&sim.status()).chars().take(width).collect();
+
+    let mut out = io::stdout().lock();
+    queue!(
+        out,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All)
+    )?;
+    for (row, line) in frame.lines().take(height.saturating_sub(1)).enumerate() {
+        queue!(out, cursor::MoveTo(0, row as u16), Print(line))?;
+    }
+    queue!(
+        out,
+        cursor::MoveTo(0, height.saturating_sub(1) as u16),
+        PrintStyledContent(status.reverse())
+    )?;
+    out.flush()
+}
+
+/// Plays `sim` in the terminal at `fps` steps a second until `q`, Esc or Ctrl-C is pressed. The
+/// simulation pauses when it ends, and keeps showing its last state.
+pub fn play(title: &str, mut sim: Box<dyn Simulation>, fps: u32) -> io::Result<()> {
+    let _screen = Screen::enter()?;
+    let mut controls = Controls {
+        title,
+        playing: true,
+        done: false,
+        steps: 0,
+        fps: fps.clamp(1, MAX_FPS),
+    };
+    let mut next_frame = Instant::now();
+
+    loop {
+        draw(sim.as_ref(), &controls)?;
+
+        let running = controls.playing && !controls.done;
+        // nothing changes until a key is pressed while paused
+        let timeout = if running {
+            next_frame.saturating_duration_since(Instant::now())
+        } else {
+            Duration::from_secs(3600)
+        };
+        let mut step = running && !event::poll(timeout)?;
+        if !step {
+            if let Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press,
+                ..
+            }) = event::read()?
+            {
+                match code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                        return Ok(())
+                    }
+                    KeyCode::Char(' ') => controls.playing = !controls.playing,
+                    KeyCode::Right | KeyCode::Char('n') => {
+                        controls.playing = false;
+                        step = true;
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                        controls.fps = (controls.fps * 2).min(MAX_FPS)
+                    }
+                    KeyCode::Char('-') => controls.fps = (controls.fps / 2).max(1),
+                    _ => {}
+                }
+            }
+        }
+
+        if step && !controls.done {
+            if sim.step() {
+                controls.steps += 1;
+            } else {
+                controls.done = true;
+            }
+            next_frame = Instant::now() + Duration::from_secs(1) / controls.fps;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use std::fs;
+    use std::path::Path;
+
+    fn example(day: &str) -> String {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../input/examples")
+            .join(format!("day{day}.txt"));
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn test_crop() {
+        let text = "abcd\nefgh\nijkl\nmnop";
+        assert_eq!(crop(text, 2, 2, (0, 0)), "ab\nef");
+        assert_eq!(crop(text, 2, 2, (2, 2)), "fg\njk");
+        // the window stays inside the text
+        assert_eq!(crop(text, 2, 2, (3, 3)), "kl\nop");
+        assert_eq!(crop(text, 10, 10, (3, 3)), text);
+    }
+
+    // Every simulation runs to its end on the example and draws every frame into the window.
+    // The cube of day 22 only folds the net of the puzzle input.
+    #[test]
+    fn test_examples() {
+        let sims = [
+            ("9", 1),
+            ("9", 2),
+            ("14", 1),
+            ("14", 2),
+            ("17", 1),
+            ("17", 2),
+            ("22", 1),
+            ("23", 1),
+            ("23", 2),
+            ("24", 1),
+            ("24", 2),
+        ];
+        for (name, part) in sims {
+            let day = days::find(name).unwrap();
+            let mut sim = simulation(day, &example(name), part).unwrap().unwrap();
+            let mut steps = 0;
+            loop {
+                let frame = sim.render(30, 10);
+                assert!(frame.lines().count() <= 10, "day {name}:\n{frame}");
+                assert!(frame.lines().all(|l| l.chars().count() <= 30));
+                // part 2 of day 17 drops a trillion rocks
+                if !sim.step() || steps == 100_000 {
+                    break;
+                }
+                steps += 1;
+            }
+            assert!(steps > 0, "day {name} part {part}");
+            // a step of day 14 is a unit of sand
+            if name == "14" {
+                let answers = [24, 93];
+                assert_eq!(steps, answers[part as usize - 1]);
+            }
+        }
+        assert!(simulation(days::find("1").unwrap(), "", 1)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,77 @@
+//! Runs every day on inputs from `aoc gen` and compares the answers with those of its oracle.
+
+use aoc::days;
+use aoc::generate;
+use aoc_common::Answer;
+use std::panic;
+use std::thread;
+
+const SEEDS: u64 = 4;
+
+// On a thread with the stack of a main thread, like the golden-answer tests.
+fn check(name: &'static str, size: usize) {
+    thread::Builder::new()
+        .stack_size(8 << 20)
+        .spawn(move || check_day(name, size))
+        .unwrap()
+        .join()
+        .unwrap_or_else(|e| panic::resume_unwind(e));
+}
+
+fn check_day(name: &str, size: usize) {
+    let day = days::find(name).unwrap();
+    for seed in 0..SEEDS {
+        let generated = generate::generate(day, size, seed);
+        let (part1, part2) = (day.solve)(&generated.input)
+            .unwrap_or_else(|e| panic!("day {name}, seed {seed}:{e}\n{}", generated.input));
+        for (part, answer, expected) in [(1, part1, generated.part1), (2, part2, generated.part2)] {
+            // the oracle could not tell
+            if expected == Answer::Missing {
+                continue;
+            }
+            assert_eq!(answer, expected, "day {name}, seed {seed}, part {part}");
+        }
+    }
+}
+
+macro_rules! generated {
+    ($($(#[$attr:meta])* $test:ident => ($name:literal, $size:literal),)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $test() {
+                check($name, $size);
+            }
+        )*
+    };
+}
+
+generated! {
+    day1 => ("1", 20),
+    day2 => ("2", 50),
+    day3 => ("3", 20),
+    day4 => ("4", 50),
+    day5 => ("5", 30),
+    day6 => ("6", 100),
+    day7 => ("7", 30),
+    day7_2 => ("7-2", 30),
+    day8 => ("8", 20),
+    day9 => ("9", 50),
+    day10 => ("10", 20),
+    day11 => ("11", 6),
+    day12 => ("12", 20),
+    day13 => ("13", 20),
+    day14 => ("14", 15),
+    day15 => ("15", 10),
+    day16 => ("16", 10),
+    day17 => ("17", 40),
+    day18 => ("18", 200),
+    #[ignore = "takes minutes even on small inputs, run with --ignored"]
+    day19 => ("19", 3),
+    day20 => ("20", 100),
+    day21 => ("21", 15),
+    day22 => ("22", 100),
+    day23 => ("23", 12),
+    day24 => ("24", 12),
+    day25 => ("25", 20),
+}
@@ -0,0 +1,121 @@
+//! Runs every day on its puzzle example and on its input, and compares the answers with
+//! `answers.toml` at the root of the workspace.
+
+use aoc::days::{self, DAYS};
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::thread;
+use toml::{Table, Value};
+
+fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn answers() -> Table {
+    let path = workspace_root().join("answers.toml");
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    text.parse()
+        .unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+// Solvers recurse deeply (day 24 searches depth first), so give them the stack size of a main
+// thread instead of the 2 MiB of a test thread.
+fn check(name: &'static str) {
+    thread::Builder::new()
+        .stack_size(8 << 20)
+        .spawn(move || check_day(name))
+        .unwrap()
+        .join()
+        .unwrap_or_else(|e| panic::resume_unwind(e));
+}
+
+fn check_day(name: &str) {
+    let day = days::find(name).unwrap();
+    let answers = answers();
+    let cases = answers
+        .get(&format!("day{name}"))
+        .and_then(Value::as_table)
+        .unwrap_or_else(|| panic!("answers.toml has no [day{name}] table"));
+
+    for (case, path) in [
+        ("example", format!("input/examples/{}.txt", day.input)),
+        ("input", format!("input/{}.txt", day.input)),
+    ] {
+        let expected = cases
+            .get(case)
+            .and_then(Value::as_table)
+            .unwrap_or_else(|| panic!("answers.toml has no [day{name}.{case}] table"));
+        let input = fs::read_to_string(workspace_root().join(&path))
+            .unwrap_or_else(|e| panic!("{path}: {e}"));
+        let (part1, part2) = (day.solve)(&input).unwrap_or_else(|e| panic!("{path}:{e}"));
+
+        for (part, answer) in [("part1", part1), ("part2", part2)] {
+            let Some(value) = expected.get(part) else {
+                continue;
+            };
+            let value = match value {
+                Value::String(s) => s.clone(),
+                value => value.to_string(),
+            };
+            assert_eq!(answer.to_string(), value, "day {name}, {case}, {part}");
+        }
+    }
+}
+
+#[test]
+fn every_day_has_answers() {
+    let answers = answers();
+    for day in DAYS {
+        assert!(
+            answers.contains_key(&format!("day{}", day.name)),
+            "answers.toml has no [day{}] table",
+            day.name
+        );
+    }
+}
+
+macro_rules! golden {
+    ($($(#[$attr:meta])* $test:ident => $name:literal,)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $test() {
+                check($name);
+            }
+        )*
+    };
+}
+
+golden! {
+    day1 => "1",
+    day2 => "2",
+    day3 => "3",
+    day4 => "4",
+    day5 => "5",
+    day6 => "6",
+    day7 => "7",
+    day7_2 => "7-2",
+    day8 => "8",
+    day9 => "9",
+    day10 => "10",
+    day11 => "11",
+    day12 => "12",
+    day13 => "13",
+    day14 => "14",
+    day15 => "15",
+    day16 => "16",
+    day17 => "17",
+    day18 => "18",
+    #[ignore = "takes several minutes even in release, run with --ignored"]
+    day19 => "19",
+    day20 => "20",
+    day21 => "21",
+    day22 => "22",
+    day23 => "23",
+    day24 => "24",
+    day25 => "25",
+}
@@ -0,0 +1,687 @@
+//! Property tests for the parsers of every day.
+//!
+//! Each day has a generator of valid puzzle inputs. Inputs of days whose parsed form can be
+//! written back (through `Display` or the public variants of their types) must come out exactly
+//! as they went in; the others must at least parse. Separately, no parser may panic, whether on
+//! arbitrary text or on a puzzle example with a few characters changed. Examples saved in other
+//! ways, e.g. with CRLF line endings, must parse the same once normalized.
+
+use aoc::days::{self, DAYS};
+use aoc_common::parse::normalize;
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+use std::collections::HashSet;
+use std::fs;
+use std::ops::Range;
+use std::panic;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+fn lines(lines: Vec<String>) -> String {
+    lines.join("\n")
+}
+
+// Rows of `cells`, all of the same width.
+fn grid(
+    cells: &'static str,
+    width: Range<usize>,
+    height: Range<usize>,
+) -> impl Strategy<Value = Vec<String>> {
+    (width, height).prop_flat_map(move |(width, height)| {
+        let cell = select(cells.chars().collect::<Vec<_>>());
+        let row = prop::collection::vec(cell, width).prop_map(String::from_iter);
+        prop::collection::vec(row, height)
+    })
+}
+
+// Distinct names made of `len` characters from `chars`, `names` of them.
+fn names(
+    chars: &'static str,
+    len: usize,
+    names: impl Into<prop::collection::SizeRange>,
+) -> impl Strategy<Value = Vec<String>> {
+    let name = prop::collection::vec(select(chars.chars().collect::<Vec<_>>()), len)
+        .prop_map(String::from_iter);
+    prop::collection::hash_set(name, names).prop_map(|names| names.into_iter().collect())
+}
+
+fn day1() -> impl Strategy<Value = String> {
+    let elf = prop::collection::vec(1..100_000u32, 1..6)
+        .prop_map(|calories| lines(calories.iter().map(u32::to_string).collect()));
+    prop::collection::vec(elf, 1..6).prop_map(|elves| elves.join("\n\n"))
+}
+
+fn day2() -> impl Strategy<Value = String> {
+    prop::collection::vec("[ABC] [XYZ]", 1..20).prop_map(lines)
+}
+
+fn day3() -> impl Strategy<Value = String> {
+    prop::collection::vec("[a-zA-Z]{1,16}", 1..20)
+        .prop_map(|halves| lines(halves.iter().map(|h| h.repeat(2)).collect()))
+}
+
+fn day4() -> impl Strategy<Value = String> {
+    let range = (1..100u32, 0..10u32).prop_map(|(start, len)| format!("{start}-{}", start + len));
+    prop::collection::vec((range.clone(), range), 1..20)
+        .prop_map(|pairs| lines(pairs.iter().map(|(a, b)| format!("{a},{b}")).collect()))
+}
+
+fn day5() -> impl Strategy<Value = String> {
+    let stacks = prop::collection::vec(prop::collection::vec("[A-Z]", 0..6), 1..10);
+    stacks
+        .prop_flat_map(|stacks| {
+            let n = stacks.len();
+            let op = (1..5usize, 1..=n, 1..=n);
+            (Just(stacks), prop::collection::vec(op, 1..10))
+        })
+        .prop_map(|(stacks, ops)| {
+            let height = stacks.iter().map(Vec::len).max().unwrap();
+            let mut drawing = vec![];
+            for level in (0..height).rev() {
+                let row: Vec<String> = stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(c) => format!("[{c}]"),
+                        None => "   ".to_string(),
+                    })
+                    .collect();
+                drawing.push(row.join(" "));
+            }
+            let numbers: Vec<String> = (1..=stacks.len()).map(|i| format!(" {i} ")).collect();
+            drawing.push(numbers.join(" "));
+            drawing.push(String::new());
+            for (n, from, to) in ops {
+                drawing.push(format!("move {n} from {from} to {to}"));
+            }
+            lines(drawing)
+        })
+}
+
+fn day6() -> impl Strategy<Value = String> {
+    "[a-z]{1,100}"
+}
+
+// A root with files and directories that hold files and directories of files, listed depth
+// first.
+fn day7() -> impl Strategy<Value = String> {
+    let files = || prop::collection::vec((1..300_000u32, "[a-z]{1,8}(\\.[a-z]{3})?"), 0..4);
+    let leaf = files();
+    let dir = (files(), prop::collection::vec(leaf, 0..3));
+    (files(), prop::collection::vec(dir, 0..4)).prop_map(|(files, dirs)| {
+        fn ls(session: &mut Vec<String>, files: &[(u32, String)], dirs: usize, prefix: &str) {
+            session.push("$ ls".to_string());
+            for i in 0..dirs {
+                session.push(format!("dir {prefix}{i}"));
+            }
+            for (size, name) in files {
+                session.push(format!("{size} {name}"));
+            }
+        }
+        let mut session = vec!["$ cd /".to_string()];
+        ls(&mut session, &files, dirs.len(), "d");
+        for (i, (files, subdirs)) in dirs.iter().enumerate() {
+            session.push(format!("$ cd d{i}"));
+            ls(&mut session, files, subdirs.len(), "s");
+            for (j, files) in subdirs.iter().enumerate() {
+                session.push(format!("$ cd s{j}"));
+                ls(&mut session, files, 0, "");
+                session.push("$ cd ..".to_string());
+            }
+            session.push("$ cd ..".to_string());
+        }
+        lines(session)
+    })
+}
+
+fn day8() -> impl Strategy<Value = String> {
+    grid("0123456789", 3..12usize, 3..12usize).prop_map(lines)
+}
+
+fn day9() -> impl Strategy<Value = String> {
+    prop::collection::vec("[LRUD] [1-9][0-9]?", 1..30).prop_map(lines)
+}
+
+fn day10() -> impl Strategy<Value = String> {
+    let op = prop_oneof![
+        Just("noop".to_string()),
+        (-40..40i32).prop_map(|n| format!("addx {n}")),
+    ];
+    prop::collection::vec(op, 1..50).prop_map(lines)
+}
+
+fn day11() -> impl Strategy<Value = String> {
+    (1..6usize)
+        .prop_flat_map(|count| {
+            let operation = prop_oneof![
+                (1..20u64).prop_map(|n| format!("old + {n}")),
+                (1..20u64).prop_map(|n| format!("old * {n}")),
+                Just("old * old".to_string()),
+            ];
+            let monkey = (
+                prop::collection::vec(1..100u64, 1..5),
+                operation,
+                select(vec![2u64, 3, 5, 7, 11, 13, 17, 19]),
+                0..count,
+                0..count,
+            );
+            prop::collection::vec(monkey, count)
+        })
+        .prop_map(|monkeys| {
+            let blocks: Vec<String> = monkeys
+                .iter()
+                .enumerate()
+                .map(|(i, (items, operation, divisor, yes, no))| {
+                    let items: Vec<String> = items.iter().map(u64::to_string).collect();
+                    format!(
+                        "Monkey {i}:\n  Starting items: {}\n  Operation: new = {operation}\n  \
+                         Test: divisible by {divisor}\n    If true: throw to monkey {yes}\n    \
+                         If false: throw to monkey {no}",
+                        items.join(", ")
+                    )
+                })
+                .collect();
+            blocks.join("\n\n")
+        })
+}
+
+// The start and the destination are put on two different tiles of the grid.
+fn day12() -> impl Strategy<Value = String> {
+    grid("abcdefghijklmnopqrstuvwxyz", 2..10usize, 1..10usize)
+        .prop_flat_map(|rows| {
+            let tiles = rows.len() * rows[0].len();
+            (
+                Just(rows),
+                subsequence((0..tiles).collect::<Vec<_>>(), 2),
+                any::<bool>(),
+            )
+        })
+        .prop_map(|(mut rows, tiles, start_first)| {
+            let width = rows[0].len();
+            let (s, e) = if start_first {
+                (tiles[0], tiles[1])
+            } else {
+                (tiles[1], tiles[0])
+            };
+            for (tile, c) in [(s, "S"), (e, "E")] {
+                rows[tile / width].replace_range(tile % width..tile % width + 1, c);
+            }
+            lines(rows)
+        })
+}
+
+fn packet() -> impl Strategy<Value = String> {
+    let int = (0..20u32).prop_map(|n| n.to_string());
+    let item = int.prop_recursive(4, 32, 5, |inner| {
+        prop::collection::vec(inner, 0..5).prop_map(|items| format!("[{}]", items.join(",")))
+    });
+    prop::collection::vec(item, 0..5).prop_map(|items| format!("[{}]", items.join(",")))
+}
+
+fn day13() -> impl Strategy<Value = Vec<(String, String)>> {
+    prop::collection::vec((packet(), packet()), 1..10)
+}
+
+// Paths of rock start near the source of the sand and turn at every point.
+fn day14() -> impl Strategy<Value = String> {
+    let path = (
+        (480..520usize, 1..30usize),
+        any::<bool>(),
+        prop::collection::vec(-10..=10isize, 1..6),
+    )
+        .prop_map(|((mut x, mut y), mut horizontal, steps)| {
+            let mut points = vec![format!("{x},{y}")];
+            for step in steps {
+                if horizontal {
+                    x = x.saturating_add_signed(step);
+                } else {
+                    y = y.saturating_add_signed(step).max(1);
+                }
+                horizontal = !horizontal;
+                points.push(format!("{x},{y}"));
+            }
+            points.join(" -> ")
+        });
+    prop::collection::vec(path, 1..10).prop_map(lines)
+}
+
+fn day15() -> impl Strategy<Value = String> {
+    let coord = || -1_000_000..5_000_000i32;
+    let sensor = (coord(), coord(), coord(), coord()).prop_map(|(x, y, bx, by)| {
+        format!("Sensor at x={x}, y={y}: closest beacon is at x={bx}, y={by}")
+    });
+    prop::collection::vec(sensor, 1..20).prop_map(lines)
+}
+
+// Every valve has at least one tunnel to another valve, and AA is among them.
+fn day16() -> impl Strategy<Value = String> {
+    names("ABCDEFGHIJ", 2, 1..12usize)
+        .prop_flat_map(|mut names| {
+            names.retain(|name| name != "AA");
+            names.insert(0, "AA".to_string());
+            let valves = names.len() + 1;
+            let valve = (0..25u32, prop::collection::vec(0..valves, 1..4));
+            (Just(names), prop::collection::vec(valve, valves - 1))
+        })
+        .prop_map(|(mut names, valves)| {
+            // a valve of its own for the last tunnel to lead to
+            names.push("ZZ".to_string());
+            let mut lines = vec![];
+            for (name, (flow, tunnels)) in names.iter().zip(&valves) {
+                let tunnels: Vec<&str> = tunnels.iter().map(|i| names[*i].as_str()).collect();
+                let tunnels = match tunnels.as_slice() {
+                    [one] => format!("tunnel leads to valve {one}"),
+                    many => format!("tunnels lead to valves {}", many.join(", ")),
+                };
+                lines.push(format!("Valve {name} has flow rate={flow}; {tunnels}"));
+            }
+            lines.push("Valve ZZ has flow rate=0; tunnel leads to valve AA".to_string());
+            lines.join("\n")
+        })
+}
+
+fn day17() -> impl Strategy<Value = String> {
+    "[<>]{1,100}"
+}
+
+fn day18() -> impl Strategy<Value = HashSet<(i32, i32, i32)>> {
+    prop::collection::hash_set((0..20i32, 0..20i32, 0..20i32), 1..50)
+}
+
+fn day19() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop::array::uniform6(1..30u32), 1..5).prop_map(|costs| {
+        let blueprints: Vec<String> = costs
+            .iter()
+            .enumerate()
+            .map(
+                |(i, [ore, clay, obs_ore, obs_clay, geode_ore, geode_obs])| {
+                    format!(
+                    "Blueprint {}: Each ore robot costs {ore} ore. Each clay robot costs {clay} \
+                     ore. Each obsidian robot costs {obs_ore} ore and {obs_clay} clay. Each \
+                     geode robot costs {geode_ore} ore and {geode_obs} obsidian.",
+                    i + 1
+                )
+                },
+            )
+            .collect();
+        lines(blueprints)
+    })
+}
+
+fn day20() -> impl Strategy<Value = Vec<isize>> {
+    (
+        prop::collection::vec(-10_000..10_000isize, 0..30),
+        any::<prop::sample::Index>(),
+    )
+        .prop_map(|(mut numbers, at)| {
+            numbers.insert(at.index(numbers.len() + 1), 0);
+            numbers
+        })
+}
+
+// Every monkey is `root`, `humn` or some other name, and only refers to monkeys defined before it
+// so that the jobs form a tree.
+fn day21() -> impl Strategy<Value = Vec<String>> {
+    names("abcdefghijklmnopqrstuvwxyz", 4, 1..20usize)
+        .prop_flat_map(|mut names| {
+            names.retain(|name| name != "root" && name != "humn");
+            names.insert(0, "humn".to_string());
+            names.push("root".to_string());
+            let jobs: Vec<_> = (0..names.len())
+                .map(|i| {
+                    let number = (1..100u64).prop_map(|n| n.to_string()).boxed();
+                    if i < 2 {
+                        return number;
+                    }
+                    let operation = (0..i, select(vec!['+', '-', '*', '/']), 0..i)
+                        .prop_map(|(a, op, b)| format!("{a} {op} {b}"))
+                        .boxed();
+                    if i == names.len() - 1 {
+                        operation
+                    } else {
+                        prop_oneof![number, operation].boxed()
+                    }
+                })
+                .collect();
+            (Just(names), jobs)
+        })
+        .prop_map(|(names, jobs)| {
+            names
+                .iter()
+                .zip(jobs)
+                .map(|(name, job)| {
+                    // operands were generated as indices of earlier monkeys
+                    let job = match job.split(' ').collect::<Vec<_>>().as_slice() {
+                        [a, op, b] => {
+                            let name = |i: &str| names[i.parse::<usize>().unwrap()].clone();
+                            format!("{} {op} {}", name(a), name(b))
+                        }
+                        _ => job,
+                    };
+                    format!("{name}: {job}")
+                })
+                .collect()
+        })
+}
+
+fn day22() -> impl Strategy<Value = String> {
+    let row = (0..6usize, "[.#]{1,12}").prop_map(|(offset, tiles)| " ".repeat(offset) + &tiles);
+    let path = prop::collection::vec((1..60u32, "[LR]"), 0..10)
+        .prop_flat_map(|turns| (Just(turns), 1..60u32))
+        .prop_map(|(turns, last)| {
+            let mut path = String::new();
+            for (steps, turn) in turns {
+                path += &format!("{steps}{turn}");
+            }
+            path + &last.to_string()
+        });
+    (prop::collection::vec(row, 1..10), path)
+        .prop_map(|(board, path)| format!("{}\n\n{path}", board.join("\n")))
+}
+
+fn day23() -> impl Strategy<Value = String> {
+    grid("#.", 1..12usize, 1..12usize).prop_map(lines)
+}
+
+// The entrance and the exit are gaps in the corners of the walls above and below.
+fn day24() -> impl Strategy<Value = String> {
+    grid("<>^v....", 1..10usize, 1..8usize).prop_map(|rows| {
+        let wall = "#".repeat(rows[0].len());
+        let mut valley = vec![format!("#.{wall}")];
+        valley.extend(rows.iter().map(|row| format!("#{row}#")));
+        valley.push(format!("{wall}.#"));
+        lines(valley)
+    })
+}
+
+fn day25() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec("[12][-=012]{0,12}", 1..20)
+}
+
+proptest! {
+    #[test]
+    fn day1_valid(input in day1()) {
+        prop_assert!(day1::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn day2_valid(input in day2()) {
+        prop_assert_eq!(day2::parse(&input).unwrap().len(), input.lines().count());
+    }
+
+    #[test]
+    fn day3_valid(input in day3()) {
+        prop_assert_eq!(day3::parse(&input).unwrap().len(), input.lines().count());
+    }
+
+    #[test]
+    fn day4_round_trip(input in day4()) {
+        let pairs = day4::parse(&input).unwrap();
+        let written: Vec<String> = pairs
+            .iter()
+            .map(|[[a, b], [c, d]]| format!("{a}-{b},{c}-{d}"))
+            .collect();
+        prop_assert_eq!(written.join("\n"), input);
+    }
+
+    #[test]
+    fn day5_valid(input in day5()) {
+        prop_assert!(day5::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn day6_valid(input in day6()) {
+        prop_assert_eq!(day6::parse(&input).unwrap().as_str(), input.as_str());
+    }
+
+    #[test]
+    fn day7_valid(input in day7()) {
+        prop_assert!(day7::parse(&input).is_ok());
+        prop_assert!(day7_2::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn day8_valid(input in day8()) {
+        prop_assert!(day8::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn day9_round_trip(input in day9()) {
+        use aoc_common::Dir4;
+        let moves = day9::parse(&input).unwrap();
+        let written: Vec<String> = moves
+            .iter()
+            .map(|(d, n)| {
+                let d = match d {
+                    Dir4::W => 'L',
+                    Dir4::E => 'R',
+                    Dir4::N => 'U',
+                    Dir4::S => 'D',
+                };
+                format!("{d} {n}")
+            })
+            .collect();
+        prop_assert_eq!(written.join("\n"), input);
+    }
+
+    #[test]
+    fn day10_round_trip(input in day10()) {
+        use day10::Op;
+        let program = day10::parse(&input).unwrap();
+        let written: Vec<String> = program
+            .iter()
+            .map(|op| match op {
+                Op::Noop => "noop".to_string(),
+                Op::Addx(n) => format!("addx {n}"),
+            })
+            .collect();
+        prop_assert_eq!(written.join("\n"), input);
+    }
+
+    #[test]
+    fn day11_valid(input in day11()) {
+        prop_assert_eq!(day11::parse(&input).unwrap().len(), input.split("\n\n").count());
+    }
+
+    #[test]
+    fn day12_valid(input in day12()) {
+        prop_assert!(day12::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn day13_round_trip(pairs in day13()) {
+        let input: Vec<String> = pairs.iter().map(|(a, b)| format!("{a}\n{b}")).collect();
+        let packets = day13::parse(&input.join("\n\n")).unwrap();
+        let written: Vec<String> = packets.iter().map(|p| p.to_string()).collect();
+        let expected: Vec<String> = pairs.into_iter().flat_map(|(a, b)| [a, b]).collect();
+        prop_assert_eq!(written, expected);
+    }
+
+    #[test]
+    fn day14_valid(input in day14()) {
+        prop_assert!(day14::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn day15_round_trip(input in day15()) {
+        let sensors = day15::parse(&input).unwrap();
+        let written: Vec<String> = sensors.iter().map(|s| s.to_string()).collect();
+        prop_assert_eq!(written.join("\n"), input);
+    }
+
+    #[test]
+    fn day16_valid(input in day16()) {
+        prop_assert!(day16::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn day17_round_trip(input in day17()) {
+        use day17::Dir;
+        let jets = day17::parse(&input).unwrap();
+        let written: String = jets
+            .iter()
+            .map(|jet| if *jet == Dir::L { '<' } else { '>' })
+            .collect();
+        prop_assert_eq!(written, input);
+    }
+
+    #[test]
+    fn day18_round_trip(cubes in day18()) {
+        let input: Vec<String> = cubes.iter().map(|(x, y, z)| format!("{x},{y},{z}")).collect();
+        let parsed = day18::parse(&input.join("\n")).unwrap();
+        let parsed: HashSet<_> = parsed.iter().map(|p| (p.x, p.y, p.z)).collect();
+        prop_assert_eq!(parsed, cubes);
+    }
+
+    #[test]
+    fn day19_round_trip(input in day19()) {
+        let blueprints = day19::parse(&input).unwrap();
+        let written: Vec<String> = blueprints.iter().map(|b| b.to_string()).collect();
+        prop_assert_eq!(written.join("\n"), input);
+    }
+
+    #[test]
+    fn day20_round_trip(numbers in day20()) {
+        let input: Vec<String> = numbers.iter().map(isize::to_string).collect();
+        prop_assert_eq!(day20::parse(&input.join("\n")).unwrap(), numbers);
+    }
+
+    #[test]
+    fn day21_round_trip(input in day21()) {
+        let monkeys = day21::parse(&input.join("\n")).unwrap();
+        prop_assert_eq!(monkeys.len(), input.len());
+        for line in &input {
+            let (name, _) = line.split_once(": ").unwrap();
+            prop_assert_eq!(&format!("{name}: {}", monkeys[name]), line);
+        }
+    }
+
+    #[test]
+    fn day22_round_trip(input in day22()) {
+        prop_assert_eq!(day22::parse(&input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn day23_valid(input in day23()) {
+        prop_assert_eq!(
+            day23::parse(&input).unwrap().len(),
+            input.chars().filter(|c| *c == '#').count()
+        );
+    }
+
+    #[test]
+    fn day24_valid(input in day24()) {
+        prop_assert!(day24::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn day25_round_trip(numbers in day25()) {
+        let input = numbers.join("\n");
+        prop_assert_eq!(day25::parse(&input).unwrap(), numbers);
+    }
+
+    #[test]
+    fn parsers_never_panic_on_text(input in "\\PC{0,200}") {
+        for day in DAYS {
+            let result = panic::catch_unwind(|| (day.parse)(&input));
+            prop_assert!(result.is_ok(), "day {} panicked", day.name);
+        }
+    }
+}
+
+// The puzzle examples, by day.
+fn examples() -> &'static [String] {
+    static EXAMPLES: OnceLock<Vec<String>> = OnceLock::new();
+    EXAMPLES.get_or_init(|| {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../input/examples");
+        DAYS.iter()
+            .map(|day| fs::read_to_string(dir.join(format!("{}.txt", day.input))).unwrap())
+            .collect()
+    })
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Delete(prop::sample::Index),
+    Insert(prop::sample::Index, char),
+    Truncate(prop::sample::Index),
+}
+
+// Changes that keep most of an input intact, inserting characters that mean something to one day
+// or another.
+fn edit() -> impl Strategy<Value = Edit> {
+    let special = select(
+        "0123456789-+=,:;[]<>^v.#@ \nabxyzLRSE"
+            .chars()
+            .collect::<Vec<_>>(),
+    );
+    prop_oneof![
+        any::<prop::sample::Index>().prop_map(Edit::Delete),
+        (any::<prop::sample::Index>(), special).prop_map(|(i, c)| Edit::Insert(i, c)),
+        any::<prop::sample::Index>().prop_map(Edit::Truncate),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn parsers_never_panic_on_edited_examples(
+        day in 0..DAYS.len(),
+        edits in prop::collection::vec(edit(), 1..16),
+    ) {
+        let mut input: Vec<char> = examples()[day].chars().collect();
+        for edit in &edits {
+            match edit {
+                Edit::Delete(i) if !input.is_empty() => {
+                    input.remove(i.index(input.len()));
+                }
+                Edit::Delete(_) => {}
+                Edit::Insert(i, c) => input.insert(i.index(input.len() + 1), *c),
+                Edit::Truncate(i) => input.truncate(i.index(input.len() + 1)),
+            }
+        }
+        let input: String = input.into_iter().collect();
+        let day = &DAYS[day];
+        let result = panic::catch_unwind(|| (day.parse)(&input));
+        prop_assert!(result.is_ok(), "day {} panicked on {:?}", day.name, input);
+    }
+}
+
+// The example as saved by another editor: with a byte order mark, CRLF line endings, trailing
+// spaces, no final newline or extra blank lines at the end.
+fn resaved(example: &str) -> Vec<String> {
+    let crlf = example.replace('\n', "\r\n");
+    let spaces: String = example.lines().map(|line| format!("{line}  \n")).collect();
+    vec![
+        format!("\u{feff}{example}"),
+        crlf,
+        spaces,
+        example.trim_end_matches('\n').to_string(),
+        format!("{example}\n\n"),
+    ]
+}
+
+#[test]
+fn examples_parse_however_saved() {
+    for (day, example) in DAYS.iter().zip(examples()) {
+        for input in resaved(example) {
+            let input = normalize(&input);
+            assert_eq!(input, normalize(example), "day {}", day.name);
+            assert!((day.parse)(&input).is_ok(), "day {}: {input:?}", day.name);
+        }
+    }
+
+    // the parsers that used to depend on the exact layout of the end of the input
+    for name in ["1", "22"] {
+        let day = days::find(name).unwrap();
+        let example = &examples()[DAYS.iter().position(|d| d.name == name).unwrap()];
+        let expected = (day.solve)(example).unwrap();
+        for input in resaved(example).into_iter().skip(3) {
+            assert_eq!(
+                (day.solve)(&input),
+                Ok(expected.clone()),
+                "day {name}: {input:?}"
+            );
+        }
+    }
+}
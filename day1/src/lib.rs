@@ -0,0 +1,578 @@
+//! Counting the calories the elves carry.
+//!
+//! [`Elves`] reads the elves one at a time, with where in the input their snacks are listed, and
+//! [`parse()`] checks them without keeping them. [`TopK`] keeps the elves carrying the most
+//! calories out of a stream of any length in memory bounded by how many it keeps: one for part 1,
+//! and three for part 2 unless `--top` asks for more. [`Summary`] describes how the calories of all
+//! the elves are spread, which takes their totals, and is only worked out for `-v`.
+//!
+//! Calories add up in any of the unsigned types that implement [`Calories`]: `u32` by default, or
+//! `u64` and `u128` for inventories that would overflow it, picked with `--width` (see
+//! [`Options`]). A total that overflows its type is an error naming the elf.
+
+use aoc_common::options::{self, Mode, Solver};
+use aoc_common::parse::{self, Line};
+use aoc_common::{Answer, ParseError};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// An unsigned integer type that totals of calories add up in.
+pub trait Calories:
+    Copy + Ord + Default + FromStr + fmt::Display + fmt::Debug + Into<u128> + 'static
+{
+    /// The name of the type, e.g. `u32`.
+    const NAME: &'static str;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_calories {
+    ($($t:ty),*) => {
+        $(
+            impl Calories for $t {
+                const NAME: &'static str = stringify!($t);
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_calories!(u32, u64, u128);
+
+/// The number of elves whose calories part 2 adds up in the puzzle.
+pub const TOP: usize = 3;
+
+/// The integer type calories add up in, as picked on the command line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Width {
+    #[default]
+    U32,
+    U64,
+    U128,
+}
+
+impl FromStr for Width {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u32" => Ok(Width::U32),
+            "u64" => Ok(Width::U64),
+            "u128" => Ok(Width::U128),
+            _ => Err(format!("unknown width `{s}`, expected u32, u64 or u128")),
+        }
+    }
+}
+
+/// The options of day 1 on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// `--width <u32|u64|u128>`, `u32` without it.
+    pub width: Width,
+    /// `--top <k>`, the number of elves part 2 adds up, [`TOP`] without it.
+    pub top: NonZeroUsize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            width: Width::default(),
+            top: NonZeroUsize::new(TOP).unwrap(),
+        }
+    }
+}
+
+impl Options {
+    /// Takes the options out of the command line arguments.
+    pub fn extract(args: &mut Vec<String>) -> Result<Options, String> {
+        let default = Options::default();
+        let width = options::extract(args, "--width", "u32, u64 or u128")?;
+        let top = options::extract(args, "--top", "a positive number")?;
+        Ok(Options {
+            width: width.unwrap_or(default.width),
+            top: top.unwrap_or(default.top),
+        })
+    }
+}
+
+/// Builds something for the type of calories and the number of top elves the options pick, e.g.
+/// a solver, through [`Options::dispatch`].
+pub trait Dispatch {
+    type Output;
+
+    /// Builds it for calories adding up in `C`, and the `k` elves carrying the most in part 2.
+    fn call<C: Calories>(self, k: usize) -> Self::Output;
+}
+
+impl Options {
+    /// Calls `f` with the type of calories of the width picked.
+    pub fn dispatch<F: Dispatch>(self, f: F) -> F::Output {
+        let k = self.top.get();
+        match self.width {
+            Width::U32 => f.call::<u32>(k),
+            Width::U64 => f.call::<u64>(k),
+            Width::U128 => f.call::<u128>(k),
+        }
+    }
+}
+
+// the solver of the binaries
+struct Solve;
+
+impl Dispatch for Solve {
+    type Output = Solver;
+
+    fn call<C: Calories>(self, k: usize) -> Solver {
+        Box::new(move |input| solve_as::<C>(input, k))
+    }
+}
+
+/// Takes the options out of the command line arguments, and returns the solver they ask for.
+pub fn mode(args: &mut Vec<String>) -> Result<Mode, String> {
+    Ok(Mode::Solve(Options::extract(args)?.dispatch(Solve)))
+}
+
+/// An elf and the calories of its snacks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf<C = u32> {
+    /// Position of the elf in the input, counting from 1.
+    pub index: usize,
+    /// Lines of the input listing its snacks, counting from 1.
+    pub lines: RangeInclusive<usize>,
+    pub total: C,
+}
+
+/// `elf #43 (lines 512-520): 71,023`.
+impl<C: Calories> fmt::Display for Elf<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "elf #{} (", self.index)?;
+        if self.lines.start() == self.lines.end() {
+            write!(f, "line {}", self.lines.start())?;
+        } else {
+            write!(f, "lines {}-{}", self.lines.start(), self.lines.end())?;
+        }
+        write!(f, "): {}", thousands(self.total))
+    }
+}
+
+// `n` with its digits grouped by three, e.g. `71,023`
+fn thousands(n: impl fmt::Display) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// The elves listed by some lines of input, read as they are needed: empty lines separate the
+/// elves, and every other line is the calories of a snack.
+pub struct Elves<'a, I: Iterator<Item = Line<'a>>, C = u32> {
+    lines: I,
+    count: usize,
+    calories: PhantomData<C>,
+}
+
+impl<'a, I: Iterator<Item = Line<'a>>, C> Elves<'a, I, C> {
+    pub fn new(lines: I) -> Self {
+        Elves {
+            lines,
+            count: 0,
+            calories: PhantomData,
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item = Line<'a>>, C: Calories> Iterator for Elves<'a, I, C> {
+    type Item = Result<Elf<C>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the elf being read, if any; the last one is not always followed by an empty line
+        let mut elf: Option<Elf<C>> = None;
+        for line in self.lines.by_ref() {
+            if line.text.is_empty() {
+                if elf.is_some() {
+                    break;
+                }
+                continue;
+            }
+            let calories: C = match line.parse(line.text, "a number of calories") {
+                Ok(calories) => calories,
+                Err(e) => return Some(Err(e)),
+            };
+            let elf = elf.get_or_insert_with(|| Elf {
+                index: self.count + 1,
+                lines: line.number..=line.number,
+                total: C::default(),
+            });
+            elf.lines = *elf.lines.start()..=line.number;
+            elf.total = match elf.total.checked_add(calories) {
+                Some(total) => total,
+                None => {
+                    let expected =
+                        format!("the total of elf #{} to fit in a {}", elf.index, C::NAME);
+                    return Some(Err(line.error(line.text, expected)));
+                }
+            };
+        }
+        self.count += elf.is_some() as usize;
+        elf.map(Ok)
+    }
+}
+
+/// An input checked to list elves. They are read again each time they are needed, rather than
+/// held in memory.
+#[derive(Debug, Clone, Copy)]
+pub struct Inventory<'a, C = u32> {
+    input: &'a str,
+    calories: PhantomData<C>,
+}
+
+impl<'a, C: Calories> Inventory<'a, C> {
+    /// Every elf, in the order they are listed.
+    pub fn elves(&self) -> impl Iterator<Item = Elf<C>> + 'a {
+        // `parse_as` has read every elf without error
+        Elves::new(parse::lines(self.input)).map(Result::unwrap)
+    }
+}
+
+/// Checks that the input lists elves, reading them one at a time.
+pub fn parse(input: &str) -> Result<Inventory<'_>, ParseError> {
+    parse_as(input)
+}
+
+/// [`parse()`], adding calories up in `C`.
+pub fn parse_as<C: Calories>(input: &str) -> Result<Inventory<'_, C>, ParseError> {
+    for elf in Elves::<_, C>::new(parse::lines(input)) {
+        elf?;
+    }
+    Ok(Inventory {
+        input,
+        calories: PhantomData,
+    })
+}
+
+// An elf ordered by its total. Of two elves carrying as much, the one listed first ranks higher.
+#[derive(Debug, PartialEq, Eq)]
+struct Ranked<C>(Elf<C>);
+
+impl<C: Calories> Ord for Ranked<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0.total, Reverse(self.0.index)).cmp(&(other.0.total, Reverse(other.0.index)))
+    }
+}
+
+impl<C: Calories> PartialOrd for Ranked<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `k` elves carrying the most calories of those pushed so far, kept in a min-heap of at most
+/// `k` elves: the one ranking lowest is the first to go when a better one comes along.
+#[derive(Debug)]
+pub struct TopK<C = u32> {
+    k: usize,
+    heap: BinaryHeap<Reverse<Ranked<C>>>,
+}
+
+impl<C: Calories> TopK<C> {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: Elf<C>) {
+        self.heap.push(Reverse(Ranked(elf)));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The elves kept, from the one carrying the most calories down.
+    pub fn into_vec(self) -> Vec<Elf<C>> {
+        // sorting the reversed elves ascending puts the best first
+        let elves = self.heap.into_sorted_vec();
+        elves.into_iter().map(|Reverse(Ranked(elf))| elf).collect()
+    }
+}
+
+impl<C: Calories> Extend<Elf<C>> for TopK<C> {
+    fn extend<T: IntoIterator<Item = Elf<C>>>(&mut self, elves: T) {
+        for elf in elves {
+            self.push(elf);
+        }
+    }
+}
+
+/// The `k` elves carrying the most calories, from the most down.
+pub fn top<C: Calories>(elves: impl IntoIterator<Item = Elf<C>>, k: usize) -> Vec<Elf<C>> {
+    let mut top = TopK::new(k);
+    top.extend(elves);
+    top.into_vec()
+}
+
+/// How the calories of the elves are spread. Unlike [`TopK`], it needs the totals of all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary<C = u32> {
+    pub count: usize,
+    pub min: C,
+    pub max: C,
+    pub mean: f64,
+    /// The first quartile, the median and the third quartile, interpolated between totals.
+    pub quartiles: [f64; 3],
+    // every total, sorted
+    totals: Vec<C>,
+}
+
+impl<C: Calories> Summary<C> {
+    /// None if there are no totals.
+    pub fn new(totals: impl IntoIterator<Item = C>) -> Option<Summary<C>> {
+        let mut totals: Vec<C> = totals.into_iter().collect();
+        totals.sort_unstable();
+        let (&min, &max) = (totals.first()?, totals.last()?);
+        let quantile = |q: f64| {
+            let at = q * (totals.len() - 1) as f64;
+            let (below, above) = (
+                float(totals[at.floor() as usize]),
+                float(totals[at.ceil() as usize]),
+            );
+            below + (above - below) * at.fract()
+        };
+        let quartiles = [quantile(0.25), quantile(0.5), quantile(0.75)];
+        Some(Summary {
+            count: totals.len(),
+            min,
+            max,
+            mean: totals.iter().map(|&total| float(total)).sum::<f64>() / totals.len() as f64,
+            quartiles,
+            totals,
+        })
+    }
+
+    /// Whether a total is more than 1.5 interquartile ranges past either quartile.
+    pub fn is_outlier(&self, total: C) -> bool {
+        let iqr = self.quartiles[2] - self.quartiles[0];
+        let fences = self.quartiles[0] - 1.5 * iqr..=self.quartiles[2] + 1.5 * iqr;
+        !fences.contains(&float(total))
+    }
+
+    pub fn median(&self) -> f64 {
+        self.quartiles[1]
+    }
+
+    /// The number of elves in each of at most `buckets` ranges of totals of equal width, from
+    /// `min` to `max`. There are fewer ranges when the totals span fewer values than `buckets`,
+    /// and the last one ends at `max`.
+    pub fn histogram(&self, buckets: NonZeroUsize) -> Vec<(RangeInclusive<u128>, usize)> {
+        let (min, max): (u128, u128) = (self.min.into(), self.max.into());
+        // `span / width` is below `buckets`, so every total falls in a range
+        let span = max - min;
+        let Some(width) = (span / buckets.get() as u128).checked_add(1) else {
+            // a single range over every value of a `u128`
+            return vec![(min..=max, self.count)];
+        };
+        let mut counts = vec![0; (span / width) as usize + 1];
+        for &total in &self.totals {
+            counts[((total.into() - min) / width) as usize] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let start = min + i as u128 * width;
+                (start..=start.saturating_add(width - 1).min(max), count)
+            })
+            .collect()
+    }
+}
+
+fn float<C: Calories>(calories: C) -> f64 {
+    calories.into() as f64
+}
+
+// logs the spread of the calories, which `-v` shows
+fn log_summary<C: Calories>(inventory: &Inventory<C>) {
+    if !log::log_enabled!(log::Level::Debug) {
+        return;
+    }
+    let Some(summary) = Summary::new(inventory.elves().map(|elf| elf.total)) else {
+        return;
+    };
+    log::debug!(
+        "{} elves, {} to {} calories, mean {:.0}, median {:.0}",
+        summary.count,
+        thousands(summary.min),
+        thousands(summary.max),
+        summary.mean,
+        summary.median()
+    );
+    for (range, count) in summary.histogram(NonZeroUsize::new(10).unwrap()) {
+        log::debug!(
+            "{:>7} - {:>7}: {count:>4} {}",
+            thousands(*range.start()),
+            thousands(*range.end()),
+            "#".repeat(count * 60 / summary.count)
+        );
+    }
+    for elf in inventory
+        .elves()
+        .filter(|elf| summary.is_outlier(elf.total))
+    {
+        log::debug!("outlier: {elf}");
+    }
+}
+
+// The sum of the totals of `elves`, which may not fit in their type, nor in a `u128` if that is
+// their type. An answer is a number while it fits in an `i64`, and its digits past that.
+fn sum<C: Calories>(elves: &[Elf<C>]) -> Answer {
+    let sum = elves
+        .iter()
+        .try_fold(0u128, |sum, elf| sum.checked_add(elf.total.into()));
+    match sum {
+        Some(sum) => i64::try_from(sum).map_or_else(|_| sum.to_string().into(), Answer::Int),
+        None => {
+            log::error!("the sum of the top {} elves overflows a u128", elves.len());
+            Answer::Missing
+        }
+    }
+}
+
+pub fn part1<C: Calories>(inventory: &Inventory<C>) -> Answer {
+    let top = top(inventory.elves(), 1);
+    if top.is_empty() {
+        return Answer::Missing;
+    }
+    sum(&top)
+}
+
+pub fn part2<C: Calories>(inventory: &Inventory<C>) -> Answer {
+    part2_with(inventory, TOP)
+}
+
+/// [`part2`], adding up the calories of the `k` elves carrying the most.
+pub fn part2_with<C: Calories>(inventory: &Inventory<C>, k: usize) -> Answer {
+    let top = top(inventory.elves(), k);
+    for elf in &top {
+        log::debug!("{elf}");
+    }
+    log_summary(inventory);
+    if top.is_empty() {
+        return Answer::Missing;
+    }
+    sum(&top)
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    solve_as::<u32>(input, TOP)
+}
+
+/// [`solve`], adding calories up in `C` and the calories of the `k` elves carrying the most in
+/// part 2.
+pub fn solve_as<C: Calories>(input: &str, k: usize) -> Result<(Answer, Answer), ParseError> {
+    let inventory = parse_as::<C>(input)?;
+    Ok((part1(&inventory), part2_with(&inventory, k)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn test_top() {
+        let inventory = parse(EXAMPLE).unwrap();
+        let top = top(inventory.elves(), 3);
+        let named: Vec<_> = top.iter().map(Elf::to_string).collect();
+        assert_eq!(
+            named,
+            [
+                "elf #4 (lines 10-12): 24,000",
+                "elf #3 (lines 7-8): 11,000",
+                "elf #5 (line 14): 10,000",
+            ]
+        );
+        // ties go to the elf listed first
+        let mut tied = TopK::new(1);
+        tied.extend(parse("5\n\n2\n3\n\n5\n").unwrap().elves());
+        assert_eq!(tied.into_vec()[0].index, 1);
+
+        assert_eq!(part2(&inventory), Answer::Int(45000));
+        assert_eq!(part2_with(&inventory, 5), Answer::Int(55000));
+        assert_eq!(
+            solve_as::<u64>(EXAMPLE, 1),
+            Ok((24000.into(), 24000.into()))
+        );
+        // no elves, no answer to either part
+        assert_eq!(solve("\n\n"), Ok((Answer::Missing, Answer::Missing)));
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = Summary::new([1u32, 2, 3, 4, 100]).unwrap();
+        assert_eq!(summary.quartiles, [2.0, 3.0, 4.0]);
+        assert_eq!(summary.median(), 3.0);
+        assert!(summary.is_outlier(100) && !summary.is_outlier(4));
+        let four = NonZeroUsize::new(4).unwrap();
+        let histogram = summary.histogram(four);
+        assert_eq!(histogram[0], (1..=25, 4));
+        assert_eq!(histogram[3], (76..=100, 1));
+        // the ranges stay within the totals, however few or far apart
+        let summary = Summary::new([5u32, 5]).unwrap();
+        assert_eq!(summary.histogram(four), [(5..=5, 2)]);
+        let summary = Summary::new([0, u128::MAX]).unwrap();
+        let histogram = summary.histogram(four);
+        assert_eq!(histogram.len(), 4);
+        assert_eq!(*histogram[3].0.end(), u128::MAX);
+        assert_eq!((histogram[0].1, histogram[3].1), (1, 1));
+        let one = NonZeroUsize::new(1).unwrap();
+        assert_eq!(summary.histogram(one), [(0..=u128::MAX, 2)]);
+        assert_eq!(Summary::<u32>::new([]), None);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "1\n\n4000000000\n300000000\n\n5\n";
+        let e = parse(input).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "4:1: expected the total of elf #2 to fit in a u32"
+        );
+        let inventory = parse_as::<u64>(input).unwrap();
+        let second = inventory.elves().nth(1).unwrap();
+        assert_eq!(second.to_string(), "elf #2 (lines 3-4): 4,300,000,000");
+        assert_eq!(part1(&inventory), Answer::Int(4_300_000_000));
+
+        let huge = format!("{}\n\n{}\n", u128::MAX, u128::MAX);
+        let inventory = parse_as::<u128>(&huge).unwrap();
+        assert_eq!(part1(&inventory), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(part2(&inventory), Answer::Missing);
+    }
+
+    #[test]
+    fn test_options() {
+        let mut args = vec!["--width".to_string(), "u64".to_string(), "-".to_string()];
+        assert_eq!(Options::extract(&mut args).unwrap().width, Width::U64);
+        assert_eq!(args, ["-"]);
+        assert_eq!(Options::extract(&mut args), Ok(Options::default()));
+        assert!(Options::extract(&mut vec!["--width".to_string()]).is_err());
+        assert!(Options::extract(&mut vec!["--width".to_string(), "i8".to_string()]).is_err());
+        let mut args = vec!["--top".to_string(), "5".to_string()];
+        assert_eq!(Options::extract(&mut args).unwrap().top.get(), 5);
+        assert!(Options::extract(&mut vec!["--top".to_string(), "0".to_string()]).is_err());
+    }
+}
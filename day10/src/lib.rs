@@ -0,0 +1,83 @@
+//! A CPU running a program and drawing on a screen with it.
+//!
+//! [`parse()`] reads the program, and [`register`] runs it.
+
+use aoc_common::{parse, Answer, Line, ParseError};
+use std::iter;
+
+/// An instruction of the CPU.
+pub enum Op {
+    Noop,
+    Addx(i32),
+}
+
+impl Op {
+    fn cycles(&self) -> u32 {
+        match self {
+            Op::Noop => 1,
+            Op::Addx(_) => 2,
+        }
+    }
+}
+
+fn parse_op(input: &Line) -> Result<Op, ParseError> {
+    let parts: Vec<_> = input.text.split(' ').collect();
+    match parts[0] {
+        "noop" => Ok(Op::Noop),
+        "addx" => {
+            let arg = input.require(parts.get(1), "an argument to addx")?;
+            Ok(Op::Addx(input.parse(arg, "an integer")?))
+        }
+        op => Err(input.error(op, "instruction 'noop' or 'addx'")),
+    }
+}
+
+/// The program, one instruction per line.
+pub fn parse(input: &str) -> Result<Vec<Op>, ParseError> {
+    parse::lines(input).map(|line| parse_op(&line)).collect()
+}
+
+/// The value of the X register during each cycle, starting with cycle 1.
+pub fn register(program: &[Op]) -> impl Iterator<Item = i32> + '_ {
+    program
+        .iter()
+        .scan(1i32, |regx, op| {
+            let during = *regx;
+            if let Op::Addx(arg) = op {
+                *regx += arg;
+            }
+            Some(iter::repeat_n(during, op.cycles() as usize))
+        })
+        .flatten()
+}
+
+pub fn part1(program: &[Op]) -> Answer {
+    let signals: i32 = register(program)
+        .zip(1..)
+        .skip(19)
+        .step_by(40)
+        .take(6)
+        .map(|(regx, cycle)| regx * cycle)
+        .sum();
+    signals.into()
+}
+
+pub fn part2(program: &[Op]) -> Answer {
+    let mut crt = String::new();
+    for (regx, pixel) in register(program).zip(0..240i32) {
+        crt.push(if (regx - pixel % 40).abs() <= 1 {
+            '#'
+        } else {
+            '.'
+        });
+        if pixel % 40 == 39 {
+            crt.push('\n');
+        }
+    }
+    crt.trim_end().into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let program = parse(input)?;
+    Ok((part1(&program), part2(&program)))
+}
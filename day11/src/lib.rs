@@ -0,0 +1,201 @@
+//! Monkeys throwing items around by how worried they make you.
+//!
+//! [`parse()`] reads each [`Monkey`] with the [`Item`]s it holds, the [`Operation`] it does to your
+//! worry level and the [`Test`] it decides where to throw by, and [`monkey_business`] plays the
+//! rounds.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::{Answer, ParseError};
+use std::collections::VecDeque;
+
+/// How the worry level of an item changes when a monkey inspects it.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+impl Operation {
+    /// The new worry level of `item`.
+    pub fn apply(&self, item: &Item) -> u64 {
+        match self {
+            Operation::Add(x) => x + item.0,
+            Operation::Mul(x) => x * item.0,
+            Operation::Square => item.0 * item.0,
+        }
+    }
+}
+
+/// Where a monkey throws an item: to one monkey if its worry level is divisible by a number, to
+/// another if it is not.
+#[derive(Debug, Clone)]
+pub struct Test {
+    arg: u64,
+    monkey1: usize,
+    monkey2: usize,
+}
+
+impl Test {
+    pub fn divisor(&self) -> u64 {
+        self.arg
+    }
+
+    /// The monkey that gets the items whose worry level is divisible.
+    pub fn if_true(&self) -> usize {
+        self.monkey1
+    }
+
+    /// The monkey that gets the other items.
+    pub fn if_false(&self) -> usize {
+        self.monkey2
+    }
+
+    /// The monkey `item` is thrown to.
+    pub fn apply(&self, item: &Item) -> usize {
+        if item.0.is_multiple_of(self.arg) {
+            self.monkey1
+        } else {
+            self.monkey2
+        }
+    }
+}
+
+/// An item, known by its worry level.
+#[derive(Debug, Clone)]
+pub struct Item(pub u64);
+
+/// A monkey with the items it holds and the rules it throws them by.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    inspected: u64,
+    items: VecDeque<Item>,
+    operation: Operation,
+    test: Test,
+}
+
+impl Monkey {
+    fn new(items: VecDeque<Item>, operation: Operation, test: Test) -> Self {
+        Monkey {
+            inspected: 0,
+            items,
+            operation,
+            test,
+        }
+    }
+
+    /// The items the monkey holds, the next one to be inspected first.
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.items.iter()
+    }
+
+    pub fn operation(&self) -> &Operation {
+        &self.operation
+    }
+
+    pub fn test(&self) -> &Test {
+        &self.test
+    }
+
+    /// The number of items the monkey has inspected.
+    pub fn inspected(&self) -> u64 {
+        self.inspected
+    }
+}
+
+// the number at the end of "If true: throw to monkey 7" and the like
+fn last_token<'a>(line: &Line<'a>) -> &'a str {
+    line.text.rsplit(' ').next().unwrap_or_default()
+}
+
+fn parse_monkey(lines: &[Line], count: usize) -> Result<Monkey, ParseError> {
+    // "  Starting items: 66, 71, 94",
+    let line = &lines[1];
+    let items = line.literal(line.text.trim_start(), "Starting items: ")?;
+    let items = items
+        .split(',')
+        .map(|i| line.parse(i.trim(), "a worry level").map(Item))
+        .collect::<Result<VecDeque<_>, _>>()?;
+
+    // "  Operation: new = old * 5",
+    let line = &lines[2];
+    let op = line.literal(line.text.trim_start(), "Operation: new = old ")?;
+    let (operator, arg) = line.require(op.split_once(' '), "an operator and an operand")?;
+    let op = match (operator, arg) {
+        ("+", arg) => Operation::Add(line.parse(arg, "a number")?),
+        ("*", "old") => Operation::Square,
+        ("*", arg) => Operation::Mul(line.parse(arg, "a number or 'old'")?),
+        _ => return Err(line.error(operator, "operator '+' or '*'")),
+    };
+
+    // Test: divisible by 3
+    // If true: throw to monkey 7
+    // If false: throw to monkey 4
+    let target = |line: &Line| -> Result<usize, ParseError> {
+        let token = last_token(line);
+        match line.parse(token, "a monkey number")? {
+            monkey if monkey < count => Ok(monkey),
+            _ => Err(line.error(token, format!("a monkey below {count}"))),
+        }
+    };
+    let test = Test {
+        arg: lines[3].parse(last_token(&lines[3]), "a divisor")?,
+        monkey1: target(&lines[4])?,
+        monkey2: target(&lines[5])?,
+    };
+
+    Ok(Monkey::new(items, op, test))
+}
+
+/// Every monkey, in the order of their numbers.
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let lines: Vec<_> = parse::lines(input).filter(|l| !l.text.is_empty()).collect();
+    if lines.len() % 6 != 0 {
+        return Err(ParseError::end_of_input(input, "a complete monkey block"));
+    }
+    let count = lines.len() / 6;
+    lines
+        .chunks(6)
+        .map(|chunk| parse_monkey(chunk, count))
+        .collect()
+}
+
+pub fn part1(monkeys: &[Monkey]) -> Answer {
+    monkey_business(monkeys.to_vec(), 20, |worry| worry / 3).into()
+}
+
+pub fn part2(monkeys: &[Monkey]) -> Answer {
+    // without relief worry levels only need to stay divisible by the same tests
+    let lcm: u64 = monkeys.iter().map(|m| m.test.arg).product();
+    monkey_business(monkeys.to_vec(), 10000, |worry| worry % lcm).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let monkeys = parse(input)?;
+    Ok((part1(&monkeys), part2(&monkeys)))
+}
+
+/// The product of the number of items the two most active monkeys inspect in `rounds` rounds,
+/// with `relief` applied to the worry level of each item after it is inspected.
+pub fn monkey_business(
+    mut monkeys: Vec<Monkey>,
+    rounds: usize,
+    relief: impl Fn(u64) -> u64,
+) -> u64 {
+    for _ in 0..rounds {
+        for m in 0..monkeys.len() {
+            let mut monkey = monkeys[m].clone();
+            for _ in 0..monkey.items.len() {
+                let mut item = monkey.items.pop_front().unwrap();
+                item.0 = relief(monkey.operation.apply(&item));
+                let target = monkey.test.apply(&item);
+                monkeys[target].items.push_back(item);
+                monkey.inspected += 1;
+            }
+            monkeys[m] = monkey;
+        }
+    }
+
+    monkeys.sort_by_key(|m| m.inspected);
+    monkeys.iter().rev().take(2).map(|m| m.inspected).product()
+}
@@ -0,0 +1,126 @@
+//! The fewest steps up a hill to the spot with the best signal.
+//!
+//! [`parse()`] reads the [`Heightmap`], and [`shortest_path`] climbs it from any point.
+//! [`distances`] gives the fewest steps to every point from the closest of several.
+
+use aoc_common::{Answer, Grid, ParseError, Point2};
+use std::collections::VecDeque;
+
+type Map = Grid<u8>;
+
+/// The heights of the area, 0 for `a` to 25 for `z`, with the start and the best signal.
+pub struct Heightmap {
+    map: Map,
+    start: Point2,
+    end: Point2,
+}
+
+impl Heightmap {
+    pub fn map(&self) -> &Grid<u8> {
+        &self.map
+    }
+
+    /// Where you are, marked `S`.
+    pub fn start(&self) -> Point2 {
+        self.start
+    }
+
+    /// The spot with the best signal, marked `E`.
+    pub fn end(&self) -> Point2 {
+        self.end
+    }
+}
+
+fn tile_height(c: char) -> u8 {
+    c as u8 - 97
+}
+
+// Visits the points reachable from `starts` in order of their distance, until `visit` returns
+// true for one of them.
+fn bfs(starts: &[Point2], map: &Map, mut visit: impl FnMut(Point2, u32) -> bool) {
+    let mut visited = Grid::new(map.width(), map.height(), false);
+    let mut q = VecDeque::new();
+    for start in starts {
+        visited[*start] = true;
+        q.push_back((*start, 0));
+    }
+
+    while let Some((p, d)) = q.pop_front() {
+        if visit(p, d) {
+            return;
+        }
+
+        for p1 in map.neighbors4(p) {
+            if map[p1] as i32 - map[p] as i32 <= 1 && !visited[p1] {
+                visited[p1] = true;
+                q.push_back((p1, d + 1));
+            }
+        }
+    }
+}
+
+/// The fewest steps from `start` to `end`, each step going up at most one, if there is a way.
+pub fn shortest_path(start: Point2, end: Point2, map: &Map) -> Option<u32> {
+    let mut distance = None;
+    bfs(&[start], map, |p, d| {
+        if p == end {
+            distance = Some(d);
+        }
+        distance.is_some()
+    });
+    distance
+}
+
+/// The fewest steps to every point from the closest of `starts`, for the points there is a way to.
+pub fn distances(starts: &[Point2], map: &Map) -> Grid<Option<u32>> {
+    let mut distances = Grid::new(map.width(), map.height(), None);
+    bfs(starts, map, |p, d| {
+        distances[p] = Some(d);
+        false
+    });
+    distances
+}
+
+pub fn parse(input: &str) -> Result<Heightmap, ParseError> {
+    let tiles = Grid::try_parse(input, "a height a-z, 'S' or 'E'", |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+    })?;
+    let start = tiles
+        .position(|c| *c == 'S')
+        .ok_or_else(|| ParseError::end_of_input(input, "a start 'S'"))?;
+    let end = tiles
+        .position(|c| *c == 'E')
+        .ok_or_else(|| ParseError::end_of_input(input, "a destination 'E'"))?;
+
+    let map: Map = tiles.map(|c| match c {
+        'S' => 0u8,
+        'E' => tile_height('z'),
+        _ => tile_height(*c),
+    });
+
+    Ok(Heightmap { map, start, end })
+}
+
+pub fn part1(hm: &Heightmap) -> Answer {
+    match shortest_path(hm.start, hm.end, &hm.map) {
+        Some(distance) => distance.into(),
+        None => Answer::Missing,
+    }
+}
+
+pub fn part2(hm: &Heightmap) -> Answer {
+    let mut shortest = u32::MAX;
+    for p in hm.map.points() {
+        if hm.map[p] == 0 {
+            if let Some(distance) = shortest_path(p, hm.end, &hm.map) {
+                shortest = shortest.min(distance);
+            }
+        }
+    }
+    shortest.into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let hm = parse(input)?;
+    Ok((part1(&hm), part2(&hm)))
+}
@@ -0,0 +1,185 @@
+//! Distress signal packets and the order they are in.
+//!
+//! [`parse()`] reads the packets as nested [`Item`]s, in the order they appear, without the empty
+//! lines between pairs.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::{Answer, ParseError};
+use std::cmp::Ordering;
+use std::fmt;
+
+enum Order {
+    Right,
+    Wrong,
+}
+
+/// A packet, or an integer or list inside one.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Item {
+    Int(u32),
+    List(Vec<Item>),
+}
+
+/// Writes the packet notation that `parse` reads, e.g. `[1,[2,3]]`.
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Item::Int(x) => write!(f, "{x}"),
+            Item::List(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+fn singleton(x: u32) -> Item {
+    Item::List(vec![Item::Int(x)])
+}
+
+// what may follow `prev` inside a packet nested `depth` lists deep
+fn expected(prev: Option<char>, depth: usize) -> &'static str {
+    match prev {
+        None => "'['",
+        Some('[') => "a number, '[' or ']'",
+        Some(',') => "a number or '['",
+        Some('0'..='9') => "a digit, ',' or ']'",
+        _ if depth == 0 => "the end of the packet",
+        _ => "',' or ']'",
+    }
+}
+
+fn parse_packet(line: &Line) -> Result<Item, ParseError> {
+    let s = line.text;
+    let mut stack: Vec<Vec<Item>> = vec![];
+    let mut packet = None;
+    // start of the number being read
+    let mut number = None;
+    let mut prev = None;
+
+    for (i, c) in s.char_indices() {
+        let open = !stack.is_empty();
+        let valid = match c {
+            '[' => matches!(prev, None | Some('[' | ',')),
+            '0'..='9' => matches!(prev, Some('[' | ',' | '0'..='9')),
+            ',' => matches!(prev, Some('0'..='9' | ']')) && open,
+            ']' => matches!(prev, Some('[' | ']' | '0'..='9')) && open,
+            _ => false,
+        };
+        if !valid {
+            return Err(line.error(&s[i..], expected(prev, stack.len())));
+        }
+
+        match c {
+            '[' => stack.push(vec![]),
+            '0'..='9' => {
+                number.get_or_insert(i);
+            }
+            _ => {
+                if let Some(start) = number.take() {
+                    let int = line.parse(&s[start..i], "a number")?;
+                    stack.last_mut().unwrap().push(Item::Int(int));
+                }
+                if c == ']' {
+                    let list = Item::List(stack.pop().unwrap());
+                    match stack.last_mut() {
+                        Some(parent) => parent.push(list),
+                        None => packet = Some(list),
+                    }
+                }
+            }
+        }
+        prev = Some(c);
+    }
+
+    packet.ok_or_else(|| line.error(line.end(), expected(prev, stack.len())))
+}
+
+fn compare(a: &Item, b: &Item) -> Option<Order> {
+    match (a, b) {
+        (Item::Int(x), Item::Int(y)) if x < y => Some(Order::Right),
+        (Item::Int(x), Item::Int(y)) if x > y => Some(Order::Wrong),
+        (Item::Int(_), Item::Int(_)) => None,
+
+        (Item::List(_), Item::Int(y)) => compare(a, &singleton(*y)),
+        (Item::Int(x), Item::List(_)) => compare(&singleton(*x), b),
+
+        (Item::List(a), Item::List(b)) => {
+            for (x, y) in a.iter().zip(b.iter()) {
+                if let Some(order) = compare(x, y) {
+                    return Some(order);
+                }
+            }
+
+            if a.len() < b.len() {
+                Some(Order::Right)
+            } else if a.len() > b.len() {
+                Some(Order::Wrong)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Every packet in the order received, so that pairs are at even indices.
+pub fn parse(input: &str) -> Result<Vec<Item>, ParseError> {
+    let packets = parse::lines(input)
+        .filter(|l| !l.text.is_empty())
+        .map(|l| parse_packet(&l))
+        .collect::<Result<Vec<_>, _>>()?;
+    if packets.len() % 2 != 0 {
+        return Err(ParseError::end_of_input(
+            input,
+            "the second packet of the pair",
+        ));
+    }
+    Ok(packets)
+}
+
+pub fn part1(packets: &[Item]) -> Answer {
+    let mut indices = 0;
+    for (i, pair) in packets.chunks(2).enumerate() {
+        match compare(&pair[0], &pair[1]) {
+            Some(Order::Right) => {
+                indices += i + 1;
+            }
+            Some(Order::Wrong) => {}
+            None => unreachable!(),
+        }
+    }
+    indices.into()
+}
+
+pub fn part2(packets: &[Item]) -> Answer {
+    let mut packets = packets.to_vec();
+    let div1 = Item::List(vec![singleton(2)]);
+    let div2 = Item::List(vec![singleton(6)]);
+    packets.push(Item::List(vec![singleton(2)]));
+    packets.push(Item::List(vec![singleton(6)]));
+    packets.sort_by(|a, b| match compare(a, b) {
+        Some(Order::Right) => Ordering::Less,
+        Some(Order::Wrong) => Ordering::Greater,
+        _ => unreachable!(),
+    });
+
+    let mut indices = 1;
+    for (i, item) in packets.iter().enumerate() {
+        if *item == div1 || *item == div2 {
+            indices *= i + 1;
+        }
+    }
+
+    indices.into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let packets = parse(input)?;
+    Ok((part1(&packets), part2(&packets)))
+}
@@ -0,0 +1,248 @@
+//! Sand pouring into a cave from a hole in its ceiling.
+//!
+//! [`parse()`] draws the scanned rock formations into a [`Cave`], and [`pour`] fills a copy of it with
+//! sand, either until sand falls into the abyss (part 1) or piles up to the source (part 2).
+//! [`Cave::drop_sand`] drops a single unit.
+
+use aoc_common::{parse, Answer, ParseError};
+
+/// The scanned slice of the cave with the rock formations drawn in and the floor below them.
+#[derive(Debug, Clone)]
+pub struct Cave {
+    buf: Vec<Vec<char>>,
+    x_range: (usize, usize),
+    y_range: (usize, usize),
+    // below the lowest rock sand would fall forever if there were no floor
+    abyss: usize,
+}
+
+/// A position in the cave as `(x, y)`, with `y` growing downwards from the ceiling.
+pub type Point = (usize, usize);
+
+/// Where the sand pours in from.
+pub const SOURCE: Point = (500, 0);
+
+impl Cave {
+    /// The leftmost and rightmost columns drawn so far.
+    pub fn columns(&self) -> (usize, usize) {
+        self.x_range
+    }
+
+    /// The depth of the floor, two units below the lowest rock.
+    pub fn floor(&self) -> usize {
+        self.y_range.1
+    }
+
+    fn translate(&self, p: Point) -> Point {
+        (p.0 - self.x_range.0, p.1)
+    }
+
+    fn dimensions(&self) -> Point {
+        (self.buf[0].len(), self.buf.len())
+    }
+
+    fn at_left_border(&self, sand: Point) -> bool {
+        self.translate(sand).0 == 0
+    }
+
+    fn beyond_right_border(&self, sand: Point) -> bool {
+        self.translate(sand).0 == self.dimensions().0
+    }
+
+    fn expand_left(&mut self) {
+        self.x_range.0 -= 1;
+        for row in self.buf.iter_mut() {
+            row.insert(0, '.');
+        }
+        let (w, h) = self.dimensions();
+        self.buf[h - 1][w - 1] = '#';
+    }
+
+    fn expand_right(&mut self) {
+        self.x_range.1 += 1;
+        for row in self.buf.iter_mut() {
+            row.push('.');
+        }
+        let (w, h) = self.dimensions();
+        self.buf[h - 1][w - 1] = '#';
+    }
+
+    /// Drops a unit of sand from the [`SOURCE`] and returns where it comes to rest, or `None` if
+    /// `into_abyss` and it falls past the lowest rock.
+    pub fn drop_sand(&mut self, into_abyss: bool) -> Option<Point> {
+        let mut sand = SOURCE;
+        loop {
+            if into_abyss && sand.1 > self.abyss {
+                return None;
+            }
+
+            if self.at_left_border(sand) && can_move_down_left(self, sand) {
+                self.expand_left();
+            } else if self.beyond_right_border(sand) {
+                self.expand_right();
+            }
+
+            if can_move_down(self, sand) {
+                sand = (sand.0, sand.1 + 1);
+            } else if can_move_down_left(self, sand) {
+                sand = (sand.0 - 1, sand.1 + 1);
+            } else if can_move_down_right(self, sand) {
+                sand = (sand.0 + 1, sand.1 + 1);
+            } else {
+                self.place_tile('o', sand);
+                return Some(sand);
+            }
+        }
+    }
+
+    /// The part of the cave drawn so far, as in the puzzle: a row per line from the ceiling down
+    /// to the floor, with `+` for the source.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (y, row) in self.buf.iter().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                out.push(match (x + self.x_range.0, y) {
+                    _ if y == self.y_range.1 => '#',
+                    p if p == SOURCE && *tile == '.' => '+',
+                    _ => *tile,
+                });
+            }
+            out.push('\n');
+        }
+        out.trim_end().to_string()
+    }
+
+    fn place_tile(&mut self, tile: char, p: Point) {
+        let (x, y) = self.translate(p);
+        self.buf[y][x] = tile;
+    }
+
+    /// The tile at `p`: `#` for rock, including the floor, `o` for sand and `.` for air. `None`
+    /// outside the part of the cave drawn so far, which widens as sand pours past its sides.
+    pub fn get_tile(&self, p: Point) -> Option<char> {
+        if p.1 == self.y_range.1 {
+            return Some('#');
+        }
+        if p.0 < self.x_range.0 || p.0 > self.x_range.1 || p.1 > self.y_range.1 {
+            return None;
+        }
+        let (x, y) = self.translate(p);
+        Some(self.buf[y][x])
+    }
+}
+
+fn can_move_down(cave: &Cave, sand: Point) -> bool {
+    if let Some(tile) = cave.get_tile((sand.0, sand.1 + 1)) {
+        tile == '.'
+    } else {
+        unreachable!(); // part 2 - unlimited floor
+    }
+}
+
+fn can_move_down_left(cave: &Cave, sand: Point) -> bool {
+    if let Some(tile) = cave.get_tile((sand.0 - 1, sand.1 + 1)) {
+        tile == '.'
+    } else {
+        true
+    }
+}
+
+fn can_move_down_right(cave: &Cave, sand: Point) -> bool {
+    if let Some(tile) = cave.get_tile((sand.0 + 1, sand.1 + 1)) {
+        tile == '.'
+    } else {
+        true
+    }
+}
+
+pub fn parse(input: &str) -> Result<Cave, ParseError> {
+    let mut formations = vec![];
+
+    let mut x_range = (usize::MAX, 0usize);
+    let mut y_range = (usize::MAX, 0usize);
+
+    for line in parse::lines(input) {
+        let mut points: Vec<Point> = vec![];
+        for p in line.text.split(" -> ") {
+            let (x, y) = p
+                .split_once(',')
+                .ok_or_else(|| line.error(p, "a point 'x,y'"))?;
+            let (x, y) = (line.parse(x, "a number")?, line.parse(y, "a number")?);
+            if let Some(&(px, py)) = points.last() {
+                if px != x && py != y {
+                    return Err(line.error(p, "a point in line with the previous one"));
+                }
+            }
+
+            x_range.0 = x_range.0.min(x);
+            x_range.1 = x_range.1.max(x);
+            y_range.0 = y_range.0.min(y);
+            y_range.1 = y_range.1.max(y);
+
+            points.push((x, y));
+        }
+        formations.push(points);
+    }
+    if formations.is_empty() {
+        return Err(ParseError::end_of_input(
+            input,
+            "a path of rock 'x,y -> x,y'",
+        ));
+    }
+
+    let abyss = y_range.1;
+    y_range.1 += 2;
+    let mut cave = Cave {
+        buf: vec![vec!['.'; x_range.1 - x_range.0 + 1]; y_range.1 + 1],
+        x_range,
+        y_range,
+        abyss,
+    };
+
+    for formation in &formations {
+        formation.as_slice().windows(2).for_each(|w| {
+            let mut p = [w[0], w[1]];
+            if p[0].0 == p[1].0 {
+                p.sort_by_key(|q| q.1);
+                for y in p[0].1..=p[1].1 {
+                    cave.place_tile('#', (p[0].0, y));
+                }
+            } else {
+                p.sort_by_key(|q| q.0);
+                for x in p[0].0..=p[1].0 {
+                    cave.place_tile('#', (x, p[0].1));
+                }
+            }
+        });
+    }
+
+    Ok(cave)
+}
+
+/// Pours sand from `(500, 0)` until a unit falls past the lowest rock if `into_abyss`, or until
+/// the source is blocked otherwise, and returns the number of units that came to rest. Without a
+/// floor sand comes to rest at the same places until the first unit falls past the lowest rock.
+pub fn pour(cave: &Cave, into_abyss: bool) -> u32 {
+    let mut cave = cave.clone();
+    let mut sand_at_rest = 0u32;
+    while let Some(sand) = cave.drop_sand(into_abyss) {
+        sand_at_rest += 1;
+        if sand == SOURCE {
+            break;
+        }
+    }
+    sand_at_rest
+}
+
+pub fn part1(cave: &Cave) -> Answer {
+    pour(cave, true).into()
+}
+
+pub fn part2(cave: &Cave) -> Answer {
+    pour(cave, false).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let cave = parse(input)?;
+    Ok((part1(&cave), part2(&cave)))
+}
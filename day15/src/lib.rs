@@ -0,0 +1,246 @@
+//! Sensors and the beacons closest to them, and where a distress beacon could hide.
+//!
+//! [`parse()`] reads the [`Sensor`]s, and [`coverage`] gives the positions of a row that some
+//! sensor rules out as a [`Set`] of disjoint [`Range`]s.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::{Answer, ParseError, Point2};
+use std::collections::HashSet;
+use std::fmt;
+
+/// The integers from `lo` to `hi`, both included.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct Range {
+    lo: i32,
+    hi: i32,
+}
+
+impl Range {
+    /// # Panics
+    ///
+    /// If `lo` is greater than `hi`.
+    pub fn new(lo: i32, hi: i32) -> Self {
+        assert!(lo <= hi);
+        Range { lo, hi }
+    }
+
+    pub fn lo(&self) -> i32 {
+        self.lo
+    }
+
+    pub fn hi(&self) -> i32 {
+        self.hi
+    }
+
+    /// Grows the range to cover `other` too.
+    ///
+    /// # Panics
+    ///
+    /// If the ranges do not overlap.
+    pub fn extend(&mut self, other: &Range) {
+        assert!(self.overlaps(other));
+        self.lo = self.lo.min(other.lo);
+        self.hi = self.hi.max(other.hi);
+    }
+
+    /// Whether the union of the ranges is a range, which it also is if they are adjacent.
+    pub fn overlaps(&self, r: &Range) -> bool {
+        !(self.hi < r.lo || self.lo > r.hi) || (self.hi - r.lo).abs() == 1
+    }
+}
+
+/// A set of integers, kept as sorted ranges with gaps between them.
+#[derive(Debug, Default)]
+pub struct Set {
+    inner: Vec<Range>,
+}
+
+impl Set {
+    pub fn new() -> Self {
+        Set { inner: vec![] }
+    }
+
+    /// The ranges of the set, from the lowest.
+    pub fn ranges(&self) -> &[Range] {
+        &self.inner
+    }
+
+    /// Whether the set has a gap in it.
+    pub fn disjoint(&self) -> bool {
+        self.inner.len() > 1
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> usize {
+        self.inner.iter().map(|r| (r.hi - r.lo + 1) as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// The integer in the middle of the gap between the two ranges of the set, which is the only
+    /// one missing when they are one apart.
+    ///
+    /// # Panics
+    ///
+    /// If the set is not made of exactly two ranges.
+    pub fn hole(&self) -> i32 {
+        assert_eq!(self.inner.len(), 2);
+        (self.inner[0].hi + self.inner[1].lo) / 2
+    }
+
+    /// Adds the integers from `lo` to `hi` to the set.
+    pub fn union(&mut self, lo: i32, hi: i32) {
+        self.inner.push(Range::new(lo, hi));
+        self.inner.sort();
+        let mut merged: Vec<Range> = vec![];
+        for r in self.inner.drain(..) {
+            match merged.last_mut() {
+                Some(head) if head.overlaps(&r) => head.extend(&r),
+                _ => merged.push(r),
+            }
+        }
+        self.inner = merged;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_overlaps() {
+        assert!(Range::new(-1, 1).overlaps(&Range::new(0, 2)));
+        assert!(Range::new(-1, 1).overlaps(&Range::new(1, 2)));
+        assert!(Range::new(0, 1).overlaps(&Range::new(2, 3))); // [0,1]U[2,3] = [0,3]
+    }
+
+    #[test]
+    fn test_range_extend() {
+        let mut r1 = Range::new(0, 2);
+        r1.extend(&Range::new(2, 4));
+        assert_eq!((r1.lo, r1.hi), (0, 4));
+        let mut r1 = Range::new(0, 2);
+        r1.extend(&Range::new(1, 5));
+        assert_eq!((r1.lo, r1.hi), (0, 5));
+    }
+
+    #[test]
+    fn test_set_union() {
+        let mut set = Set::new();
+        set.union(3, 4);
+        assert_eq!(set.inner[0], Range::new(3, 4));
+        set.union(1, 2);
+        assert_eq!(set.inner.len(), 1); // [1,2]U[3,4] = [1,4]
+        assert_eq!(set.inner, vec![Range::new(1, 4)]);
+        set.union(2, 3);
+        assert_eq!(set.inner.len(), 1);
+        set.union(2, 5);
+        assert_eq!(set.inner.len(), 1);
+        assert_eq!(set.inner[0], Range::new(1, 5));
+        set.union(-1, 0); // [-1,0] U [1,5] = [-1, 5]
+        assert_eq!(set.inner.len(), 1);
+        assert_eq!(set.inner, vec![Range::new(-1, 5)]);
+    }
+}
+
+/// A sensor and the closest beacon it detected.
+#[derive(Debug)]
+pub struct Sensor {
+    pos: Point2,
+    beacon: Point2,
+}
+
+impl Sensor {
+    pub fn pos(&self) -> Point2 {
+        self.pos
+    }
+
+    pub fn beacon(&self) -> Point2 {
+        self.beacon
+    }
+
+    /// The distance to the beacon, within which there are no other beacons.
+    pub fn range(&self) -> i32 {
+        self.pos.manhattan(self.beacon)
+    }
+}
+
+// "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+/// Writes the line of the report that `parse` reads.
+impl fmt::Display for Sensor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            self.pos.x, self.pos.y, self.beacon.x, self.beacon.y
+        )
+    }
+}
+
+fn parse_sensor(line: &Line) -> Result<Sensor, ParseError> {
+    let rest = line.literal(line.text, "Sensor at x=")?;
+    let (x, rest) = line.require(rest.split_once(", y="), "', y='")?;
+    let (y, rest) = line.require(
+        rest.split_once(": closest beacon is at x="),
+        "': closest beacon is at x='",
+    )?;
+    let (bx, by) = line.require(rest.split_once(", y="), "', y='")?;
+    let coord = |token| line.parse(token, "a coordinate");
+    Ok(Sensor {
+        pos: Point2::new(coord(x)?, coord(y)?),
+        beacon: Point2::new(coord(bx)?, coord(by)?),
+    })
+}
+
+// the row checked in part 1, and the side of the square searched in part 2
+const ROW: i32 = 2_000_000;
+const SIZE: i32 = 4_000_000;
+
+/// The positions of row `y` that are at least as close to some sensor as its beacon.
+pub fn coverage(sensors: &[Sensor], y: i32) -> Set {
+    let mut set = Set::new();
+    for sensor in sensors {
+        let range = sensor.range();
+        let dist_row = (sensor.pos.y - y).abs();
+        if dist_row <= range {
+            let dx = range - dist_row;
+            let (lo, hi) = (sensor.pos.x - dx, sensor.pos.x + dx);
+            set.union(lo, hi);
+        }
+    }
+    set
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    parse::lines(input)
+        .map(|line| parse_sensor(&line))
+        .collect()
+}
+
+pub fn part1(sensors: &[Sensor]) -> Answer {
+    let beacons: HashSet<i32> = sensors
+        .iter()
+        .filter(|s| s.beacon.y == ROW)
+        .map(|s| s.beacon.x)
+        .collect();
+    (coverage(sensors, ROW).len() - beacons.len()).into()
+}
+
+pub fn part2(sensors: &[Sensor]) -> Answer {
+    for y in 0..=SIZE {
+        let set = coverage(sensors, y);
+        if set.disjoint() {
+            let x = set.hole() as u64;
+            let frequency: u64 = x * SIZE as u64 + y as u64;
+            return frequency.into();
+        }
+    }
+    Answer::Missing
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let sensors = parse(input)?;
+    Ok((part1(&sensors), part2(&sensors)))
+}
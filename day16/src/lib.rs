@@ -0,0 +1,364 @@
+//! Valves releasing pressure in a network of tunnels, opened against the clock.
+//!
+//! [`parse()`] reduces the scan to a [`Network`] of the valves worth opening, and [`max_released`]
+//! searches for the order to open some of them in. Valves are known by their two-letter names, and
+//! every search starts at `AA`.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::{Answer, ParseError};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// A valve reachable from another, and the minutes it takes to get there.
+#[derive(Debug, Clone)]
+pub struct Neighbor {
+    name: String,
+    distance: u32,
+}
+
+impl Neighbor {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn distance(&self) -> u32 {
+        self.distance
+    }
+}
+
+/// A valve, its flow rate and the valves it leads to.
+#[derive(Debug, Clone)]
+pub struct Valve {
+    name: String,
+    flow: u32,
+    neighbors: Vec<Neighbor>,
+}
+
+impl Valve {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The pressure released every minute once the valve is open.
+    pub fn flow(&self) -> u32 {
+        self.flow
+    }
+
+    pub fn neighbors(&self) -> &[Neighbor] {
+        &self.neighbors
+    }
+}
+
+/// The valves with a non-zero flow rate, preceded by `AA`, and the distances between them.
+pub struct Network {
+    valves: Vec<Valve>,
+    indices: HashMap<String, usize>,
+    distances: Vec<Vec<u32>>,
+}
+
+impl Network {
+    /// The valves, `AA` first.
+    pub fn valves(&self) -> &[Valve] {
+        &self.valves
+    }
+
+    /// The minutes it takes to walk from valve `from` to valve `to`, if both are in the network.
+    pub fn distance(&self, from: &str, to: &str) -> Option<u32> {
+        let (i, j) = (self.indices.get(from)?, self.indices.get(to)?);
+        Some(self.distances[*i][*j])
+    }
+}
+
+// minutes before the volcano erupts, and what is left after teaching an elephant
+const TIME_ALONE: u32 = 30;
+const TIME_WITH_ELEPHANT: u32 = 26;
+
+// "Valve BB has flow rate=13; tunnels lead to valves CC, AA"
+fn parse_valve(line: &Line) -> Result<Valve, ParseError> {
+    let rest = line.literal(line.text, "Valve ")?;
+    let (name, rest) = line.require(rest.split_once(' '), "a valve name")?;
+    let rest = line.literal(rest, "has flow rate=")?;
+    let (flow, rest) = line.require(rest.split_once(';'), "';'")?;
+    let flow = line.parse(flow, "a flow rate")?;
+    let tunnels = rest
+        .strip_prefix(" tunnels lead to valves ")
+        .or_else(|| rest.strip_prefix(" tunnel leads to valve "))
+        .ok_or_else(|| line.error(rest, "'tunnels lead to valves'"))?;
+    let neighbors = tunnels
+        .split(", ")
+        .map(|s| Neighbor {
+            name: s.to_string(),
+            distance: 1,
+        })
+        .collect();
+    Ok(Valve {
+        name: name.to_string(),
+        flow,
+        neighbors,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let mut nz_valves = vec![];
+    let mut valves = vec![];
+    let lines: Vec<_> = parse::lines(input).collect();
+    for line in &lines {
+        let Valve {
+            name,
+            flow,
+            neighbors,
+        } = parse_valve(line)?;
+
+        if name == "AA" {
+            nz_valves.insert(
+                0,
+                Valve {
+                    name: "AA".to_string(),
+                    flow,
+                    neighbors: vec![],
+                },
+            );
+        }
+
+        if flow > 0 {
+            // all >0 valves are neighbors of one another in the condensed graph
+            nz_valves.push(Valve {
+                name: name.clone(),
+                flow,
+                neighbors: vec![],
+            });
+        }
+
+        valves.push(Valve {
+            name,
+            flow,
+            neighbors,
+        });
+    }
+
+    // indices of non-zero valves in the entire vector of valves
+    let mut nz_indices: HashMap<&String, usize> = [].into();
+    // indices of all valves
+    let indices: HashMap<&String, usize> = valves
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            if v.flow > 0 {
+                nz_indices.insert(&v.name, i);
+            }
+            (&v.name, i)
+        })
+        .collect();
+
+    for (line, valve) in lines.iter().zip(&valves) {
+        for neighbor in &valve.neighbors {
+            if !indices.contains_key(&neighbor.name) {
+                let at = line.text.rfind(neighbor.name.as_str()).unwrap();
+                return Err(line.error(&line.text[at..], "a valve listed in the input"));
+            }
+        }
+    }
+    if !indices.contains_key(&"AA".to_string()) {
+        return Err(ParseError::end_of_input(input, "the starting valve 'AA'"));
+    }
+
+    // indices of non-zero valves in the derived vector of non-zero valves
+    let useful_indices: HashMap<String, usize> = nz_valves
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.name.to_string(), i))
+        .collect();
+
+    let dist = floyd_warshall(&valves, &indices);
+    let mut nz_dist = vec![vec![0u32; nz_valves.len()]; nz_valves.len()];
+
+    for valve in &valves {
+        if valve.name != "AA" && valve.flow == 0 {
+            continue;
+        }
+        let idx = indices[&valve.name]; // index into distance matrix
+        let nzidx = useful_indices[&valve.name]; // index of valve in reduced graph
+        let nzvalve = &mut nz_valves[nzidx]; // valve copy in the derived
+                                             // graph
+        for (name, neighbor_idx) in &nz_indices {
+            if **name == valve.name {
+                continue;
+            }
+            let distance = dist[idx][*neighbor_idx];
+
+            let neighbor_nzidx = useful_indices[*name];
+            nz_dist[nzidx][neighbor_nzidx] = distance;
+            nz_dist[neighbor_nzidx][nzidx] = distance;
+
+            nzvalve.neighbors.push(Neighbor {
+                name: name.to_string(),
+                distance,
+            });
+        }
+    }
+
+    Ok(Network {
+        valves: nz_valves,
+        indices: useful_indices,
+        distances: nz_dist,
+    })
+}
+
+/// The most pressure released in `time` minutes opening only the `allowed` valves.
+pub fn max_released(network: &Network, time: u32, allowed: &[String]) -> u32 {
+    dfs(
+        &mut vec!["AA".to_string()],
+        time,
+        time,
+        0,
+        &network.indices,
+        &network.valves,
+        &network.distances,
+        allowed,
+    )
+}
+
+pub fn part1(network: &Network) -> Answer {
+    let all: Vec<_> = network.valves.iter().map(|v| v.name.to_string()).collect();
+    max_released(network, TIME_ALONE, &all).into()
+}
+
+pub fn part2(network: &Network) -> Answer {
+    let nz_valves = &network.valves;
+    let mut presh = 0u32;
+    // I open some of the valves and the elephant the others; the split is the same either way
+    // round, so I never take more than half
+    let mut combinations = (0..=(nz_valves.len() - 1) / 2)
+        .flat_map(|mine| {
+            nz_valves[1..]
+                .iter()
+                .map(|v| v.name.to_string())
+                .combinations(mine)
+        })
+        .collect::<Vec<_>>();
+    for subset in combinations.iter_mut() {
+        let mut other: Vec<_> = nz_valves[1..]
+            .iter()
+            .filter_map(|v| {
+                if !subset.contains(&v.name) {
+                    Some(v.name.to_string())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        subset.insert(0, "AA".to_string());
+        other.insert(0, "AA".to_string());
+
+        let released1 = max_released(network, TIME_WITH_ELEPHANT, subset);
+        let released2 = max_released(network, TIME_WITH_ELEPHANT, &other);
+
+        presh = presh.max(released1 + released2);
+    }
+    presh.into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let network = parse(input)?;
+    Ok((part1(&network), part2(&network)))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn dfs(
+    path: &mut Vec<String>,
+    available: u32,
+    time: u32,
+    mut best_so_far: u32,
+    indices: &HashMap<String, usize>,
+    valves: &[Valve],
+    distances: &[Vec<u32>],
+    allowed: &[String],
+) -> u32 {
+    let name = &path[path.len() - 1];
+    let valve = &valves[indices[name]];
+
+    let not_visited: Vec<&Neighbor> = valve
+        .neighbors
+        .iter()
+        .filter(|n| allowed.contains(&n.name) && !path.contains(&n.name))
+        .collect();
+    if not_visited.is_empty() {
+        return total_released_pressure(path, available, distances, indices, valves);
+    }
+
+    for neighbor in not_visited {
+        if neighbor.distance >= time {
+            best_so_far = best_so_far.max(total_released_pressure(
+                path, available, distances, indices, valves,
+            ));
+            continue;
+        }
+        path.push(neighbor.name.to_string());
+        best_so_far = best_so_far.max(dfs(
+            path,
+            available,
+            time - neighbor.distance - 1,
+            best_so_far,
+            indices,
+            valves,
+            distances,
+            allowed,
+        ));
+        path.pop();
+    }
+    best_so_far
+}
+
+fn total_released_pressure(
+    order: &[String],
+    available: u32,
+    dist: &[Vec<u32>],
+    indices: &HashMap<String, usize>,
+    valves: &[Valve],
+) -> u32 {
+    let mut time = available;
+    let mut total = 0;
+    for pair in order.windows(2) {
+        let (i, j) = (indices[&pair[0]], indices[&pair[1]]);
+        if time > dist[i][j] {
+            time = time - dist[i][j] - 1;
+            total += time * valves[j].flow;
+        }
+    }
+    total
+}
+
+// sounds expensive!
+fn floyd_warshall(g: &[Valve], indices: &HashMap<&String, usize>) -> Vec<Vec<u32>> {
+    let mut adj = vec![vec![false; g.len()]; g.len()];
+    for (i, v) in g.iter().enumerate() {
+        for neighbor in &v.neighbors {
+            let j = indices[&neighbor.name];
+            adj[i][j] = true;
+            adj[j][i] = true;
+        }
+    }
+
+    let mut dist = vec![vec![u32::MAX; g.len()]; g.len()];
+    for i in 0..g.len() {
+        for j in 0..g.len() {
+            if i == j {
+                dist[i][j] = 0;
+            } else if adj[i][j] {
+                dist[i][j] = 1;
+            }
+        }
+    }
+
+    for k in 0..g.len() {
+        for i in 0..g.len() {
+            for j in 0..g.len() {
+                if !&[dist[i][k], dist[k][j]].contains(&u32::MAX) {
+                    dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]);
+                }
+            }
+        }
+    }
+
+    dist
+}
@@ -0,0 +1,491 @@
+//! Rocks falling into a chamber and pushed around by jets of hot gas.
+//!
+//! [`parse()`] reads the jet pattern and [`tower_height`] drops any number of rocks with it, finding
+//! the cycle in the tower to skip over the trillion rocks of part 2. To drive the chamber with
+//! another pattern, create a [`Chamber`] and call [`Chamber::drop_rock`] with each [`Polyomino`]
+//! from [`polyominos`], or step a rock with [`Chamber::move_rock`] yourself.
+
+use aoc_common::{parse, Answer, ParseError, Point2};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::ops::{Add, AddAssign};
+
+/// The direction a jet pushes a rock, or the rock falling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dir {
+    L,
+    R,
+    D,
+}
+
+impl Dir {
+    // the chamber grows upwards, so falling decreases y
+    fn offset(self) -> P {
+        match self {
+            Dir::L => P::new(-1, 0),
+            Dir::R => P::new(1, 0),
+            Dir::D => P::new(0, -1),
+        }
+    }
+}
+
+type P = Point2;
+
+impl Add<Dir> for P {
+    type Output = Self;
+    fn add(self, rhs: Dir) -> Self {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign<Dir> for P {
+    fn add_assign(&mut self, rhs: Dir) {
+        *self = *self + rhs;
+    }
+}
+
+/// The shape of a rock, in the order they fall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polyomino {
+    Dash,
+    Plus,
+    L,
+    Pipe,
+    Square,
+}
+
+impl Polyomino {
+    /// Every shape, in the order the rocks fall.
+    pub const ALL: [Polyomino; 5] = [
+        Polyomino::Dash,
+        Polyomino::Plus,
+        Polyomino::L,
+        Polyomino::Pipe,
+        Polyomino::Square,
+    ];
+
+    /// The number of rows the shape spans.
+    pub fn height(&self) -> i32 {
+        match *self {
+            Polyomino::Dash => 1,
+            Polyomino::Plus => 3,
+            Polyomino::L => 3,
+            Polyomino::Pipe => 4,
+            Polyomino::Square => 2,
+        }
+    }
+
+    /// The number of columns the shape spans.
+    pub fn width(&self) -> i32 {
+        match *self {
+            Polyomino::Dash => 4,
+            Polyomino::Plus => 3,
+            Polyomino::L => 3,
+            Polyomino::Pipe => 1,
+            Polyomino::Square => 2,
+        }
+    }
+}
+
+/// The shapes in the order the rocks fall, repeating forever.
+pub fn polyominos() -> impl Iterator<Item = Polyomino> {
+    Polyomino::ALL.into_iter().cycle()
+}
+
+/// A rock of some shape somewhere in the chamber.
+#[derive(Debug, Clone)]
+pub struct Rock {
+    poly: Polyomino,
+    pos: P, // position of bottom-left tile in the chamber
+}
+
+impl Rock {
+    pub fn poly(&self) -> Polyomino {
+        self.poly
+    }
+
+    /// The position of the bottom-left tile of the rock, which may be empty for the plus.
+    pub fn pos(&self) -> Point2 {
+        self.pos
+    }
+
+    fn valid_move(&self, dir: Dir) -> bool {
+        match dir {
+            Dir::L => self.pos.x > 0,
+            Dir::R => self.pos.x + self.poly.width() < CHAMBER_WIDTH as i32,
+            Dir::D => self.pos.y > 0, // XXX consider highest rock!
+        }
+    }
+
+    /// The tiles the rock takes up in the chamber.
+    pub fn tiles(&self) -> Vec<P> {
+        let P { x, y } = self.pos;
+        match self.poly {
+            Polyomino::Dash => vec![
+                (x, y).into(),
+                (x + 1, y).into(),
+                (x + 2, y).into(),
+                (x + 3, y).into(),
+            ],
+            // .#.
+            // ###
+            // .#.
+            Polyomino::Plus => vec![
+                (x + 1, y).into(),
+                (x, y + 1).into(),
+                (x + 1, y + 1).into(),
+                (x + 2, y + 1).into(),
+                (x + 1, y + 2).into(),
+            ],
+            // ..#
+            // ..#
+            // ###
+            Polyomino::L => vec![
+                (x, y).into(),
+                (x + 1, y).into(),
+                (x + 2, y).into(),
+                (x + 2, y + 1).into(),
+                (x + 2, y + 2).into(),
+            ],
+            Polyomino::Pipe => vec![
+                (x, y).into(),
+                (x, y + 1).into(),
+                (x, y + 2).into(),
+                (x, y + 3).into(),
+            ],
+            Polyomino::Square => vec![
+                (x, y).into(),
+                (x + 1, y).into(),
+                (x, y + 1).into(),
+                (x + 1, y + 1).into(),
+            ],
+        }
+    }
+}
+
+pub const CHAMBER_WIDTH: usize = 7;
+// how far below the top of the tower rocks are considered part of its formation
+const FORMATION_DEPTH: usize = 64;
+
+/// The chamber the rocks fall into, with the rocks at rest and the one falling, if any. Row 0 is
+/// the one above the floor.
+#[derive(Debug)]
+pub struct Chamber {
+    buf: Vec<[u8; CHAMBER_WIDTH]>,
+    rock: Option<Rock>,
+    rock_height: usize,
+}
+
+impl Default for Chamber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Chamber {
+    /// An empty chamber, three rows high.
+    pub fn new() -> Self {
+        Self {
+            buf: vec![[0; CHAMBER_WIDTH]; 3],
+            rock: None,
+            rock_height: 0,
+        }
+    }
+
+    /// The number of rows of the chamber, which grows to fit the falling rock.
+    pub fn height(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// The height of the tower of rocks at rest.
+    pub fn tower_height(&self) -> usize {
+        self.rock_height
+    }
+
+    /// The rows of the chamber from the floor up, with 1 for rock at rest and 0 for air. The
+    /// falling rock is not in them.
+    pub fn rows(&self) -> &[[u8; CHAMBER_WIDTH]] {
+        &self.buf
+    }
+
+    /// The falling rock, if any.
+    pub fn rock(&self) -> Option<&Rock> {
+        self.rock.as_ref()
+    }
+
+    /// Draws the top `rows` rows of the chamber, with the falling rock as `@`, the top of the
+    /// chamber and of the tower labelled with their heights, and the floor if it is in view.
+    pub fn render(&self, rows: usize) -> String {
+        let rock_tiles = if let Some(rock) = &self.rock {
+            rock.tiles()
+        } else {
+            vec![]
+        };
+        let mut out = String::new();
+        for (y, row) in self.buf.iter().enumerate().rev().take(rows) {
+            out.push('|');
+            for (x, tile) in row.iter().enumerate() {
+                out.push(if rock_tiles.contains(&(x, y).into()) {
+                    '@'
+                } else if *tile == 0 {
+                    '.'
+                } else {
+                    '#'
+                });
+            }
+            out.push('|');
+            if y == self.height() - 1 {
+                out += &format!(" {}", self.height());
+            } else if self.rock_height > 0 && y == self.rock_height - 1 {
+                out += &format!(" {}", self.rock_height);
+            }
+            out.push('\n');
+        }
+        if rows >= self.height() {
+            out += "+-------+";
+        }
+        out.trim_end().to_string()
+    }
+
+    /// Makes a rock of shape `poly` appear two units from the left wall and three above the
+    /// tower, growing the chamber to fit it.
+    ///
+    /// # Panics
+    ///
+    /// If a rock is already falling.
+    pub fn materialize_rock(&mut self, poly: Polyomino) {
+        assert!(self.rock.is_none());
+
+        let empty_rows = self.height() - self.rock_height;
+        let poly_height = poly.height() as usize;
+
+        if empty_rows < poly_height + 3 {
+            for _ in 0..(poly_height + 3) - empty_rows {
+                self.buf.push([0; CHAMBER_WIDTH]);
+            }
+        }
+
+        let spawn_y = (self.height() - poly_height).min(self.rock_height + 3);
+        let rock = Rock {
+            poly,
+            pos: P::new(2, spawn_y as i32),
+        };
+
+        self.rock = Some(rock);
+    }
+
+    /// Brings the falling rock to rest where it is, and returns it.
+    ///
+    /// # Panics
+    ///
+    /// If no rock is falling.
+    pub fn ossify_rock(&mut self) -> Rock {
+        assert!(self.rock.is_some());
+        let rock = self.rock.take().unwrap();
+        let tiles = rock.tiles();
+        self.emplace_tiles(&tiles, 1);
+        self.rock_height = self
+            .rock_height
+            .max(1 + tiles.iter().map(|t| t.y as usize).max().unwrap());
+        rock
+    }
+
+    fn emplace_tiles(&mut self, tiles: &[P], value: u8) {
+        for t in tiles {
+            self.buf[t.y as usize][t.x as usize] = value;
+        }
+    }
+
+    fn check_for_collision(&self, tiles: &[P], dir: Dir) -> bool {
+        tiles
+            .iter()
+            .map(|t| *t + dir)
+            .any(|t| self.buf[t.y as usize][t.x as usize] == 1)
+    }
+
+    /// Moves the falling rock one unit in `dir`, unless a wall, the floor or a rock is in the way.
+    ///
+    /// # Panics
+    ///
+    /// If no rock is falling.
+    pub fn move_rock(&mut self, dir: Dir) {
+        assert!(self.rock.is_some());
+        let mut rock = self.rock.clone().unwrap();
+        let tiles = rock.tiles();
+        if rock.valid_move(dir) && !self.check_for_collision(&tiles, dir) {
+            rock.pos += dir;
+        }
+        self.rock = Some(rock);
+    }
+
+    /// Whether the falling rock cannot fall any further.
+    ///
+    /// # Panics
+    ///
+    /// If no rock is falling.
+    pub fn rock_at_rest(&self) -> bool {
+        assert!(self.rock.is_some());
+        let rock = self.rock.as_ref().unwrap();
+        if rock.valid_move(Dir::D) {
+            let tiles = rock.tiles();
+            self.check_for_collision(&tiles, Dir::D)
+        } else {
+            true
+        }
+    }
+
+    /// The height of the highest rock in each column, relative to the highest of them. Columns
+    /// the jets keep rocks out of count as reaching 64 rows down, or the floor, so that the
+    /// formation can repeat.
+    pub fn top_formation(&self) -> [i32; CHAMBER_WIDTH] {
+        let mut res = [i32::MIN; CHAMBER_WIDTH];
+        let mut y = self.rock_height;
+        let bottom = y.saturating_sub(FORMATION_DEPTH);
+        loop {
+            if res.iter().all(|v| *v != i32::MIN) {
+                break;
+            }
+            let row = &self.buf[y];
+            for x in 0..CHAMBER_WIDTH {
+                if row[x] == 1 {
+                    res[x] = res[x].max(y as i32);
+                }
+            }
+            if y > bottom {
+                y -= 1;
+            } else {
+                for v in res.iter_mut() {
+                    if *v == i32::MIN {
+                        *v = bottom as i32;
+                    }
+                }
+                break;
+            }
+        }
+        let max = *res.iter().max().unwrap();
+        for v in res.iter_mut() {
+            *v -= max;
+        }
+        res
+    }
+
+    /// Drops a rock of shape `poly`, pushing it with a jet from `jets` before each unit it falls,
+    /// until it comes to rest, and returns it. Returns `None`, with the rock still falling, if
+    /// `jets` runs out first.
+    pub fn drop_rock(
+        &mut self,
+        poly: Polyomino,
+        jets: &mut impl Iterator<Item = Dir>,
+    ) -> Option<Rock> {
+        self.materialize_rock(poly);
+        for jet in jets {
+            self.move_rock(jet);
+            if self.rock_at_rest() {
+                return Some(self.ossify_rock());
+            }
+            self.move_rock(Dir::D);
+        }
+        None
+    }
+}
+
+/// The jet pattern, which repeats once it runs out.
+pub fn parse(input: &str) -> Result<Vec<Dir>, ParseError> {
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a jet pattern"))?;
+    let jets = line
+        .text
+        .char_indices()
+        .map(|(i, c)| match c {
+            '<' => Ok(Dir::L),
+            '>' => Ok(Dir::R),
+            _ => Err(line.error(&line.text[i..], "a jet '<' or '>'")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if jets.is_empty() {
+        return Err(line.error(line.end(), "a jet '<' or '>'"));
+    }
+
+    Ok(jets)
+}
+
+pub fn part1(jets: &[Dir]) -> Answer {
+    tower_height(jets, 2022).into()
+}
+
+pub fn part2(jets: &[Dir]) -> Answer {
+    tower_height(jets, 1_000_000_000_000).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let jets = parse(input)?;
+    Ok((part1(&jets), part2(&jets)))
+}
+
+// how much of the chamber is drawn when tracing
+const TRACE_ROWS: usize = 12;
+
+/// The height of the tower after `rocks` rocks have come to rest, with the jets repeating.
+pub fn tower_height(jets: &[Dir], rocks: usize) -> usize {
+    let mut chamber = Chamber::new();
+    let mut jet_idx = 0;
+
+    // when a piece lands, if the tops of the columns form an already seen formation
+    // for an already seen jet index and an already seen piece, we have found a cycle.
+    let mut seen = HashMap::new();
+
+    let mut polys = polyominos();
+    let mut i: usize = 0;
+    let mut added_height = 0;
+    while i < rocks {
+        let poly = polys.next().unwrap();
+        chamber.materialize_rock(poly);
+
+        loop {
+            let jet = jets[jet_idx];
+            chamber.move_rock(jet);
+
+            if chamber.rock_at_rest() {
+                i += 1;
+
+                let rock = chamber.ossify_rock();
+                let key = (chamber.top_formation(), rock.poly as u8, jet_idx);
+                jet_idx = (jet_idx + 1) % jets.len();
+
+                log::trace!(
+                    "rock {i} at rest, top of the chamber:\n{}",
+                    chamber.render(TRACE_ROWS)
+                );
+
+                if let Entry::Vacant(e) = seen.entry(key) {
+                    e.insert((i, chamber.rock_height));
+                } else {
+                    let (old_i, old_height) = seen.remove(&key).unwrap();
+                    let height_dif = chamber.rock_height - old_height;
+                    let cycle_len = i - old_i;
+                    let cycles = (rocks - i) / cycle_len;
+                    if cycles > 0 {
+                        log::debug!(
+                            "rock {i} repeats rock {old_i} (jet {}): a cycle of {cycle_len} \
+                             rocks adds {height_dif} to the height, skipping {cycles} cycles",
+                            key.2
+                        );
+                    } else {
+                        log::trace!("rock {i} repeats a formation, but no whole cycle is left");
+                    }
+                    added_height += height_dif * cycles;
+                    i += cycle_len * cycles;
+                }
+
+                break;
+            } else {
+                chamber.move_rock(Dir::D);
+            }
+
+            jet_idx = (jet_idx + 1) % jets.len();
+        }
+    }
+
+    chamber.rock_height + added_height
+}
@@ -0,0 +1,145 @@
+//! The surface of a droplet of lava made of cubes.
+//!
+//! [`parse()`] reads the cubes, and [`surface_area`] counts their faces that do not touch another
+//! cube. Part 2 leaves out the faces of the air pockets [`trapped`] inside the droplet.
+
+use aoc_common::{parse, Answer, ParseError, Point3};
+use std::collections::{HashSet, VecDeque};
+
+type Pos = Point3;
+
+const BOUND: i32 = 20;
+
+fn in_bounds(pos: &Pos) -> bool {
+    [pos.x, pos.y, pos.z]
+        .iter()
+        .all(|c| (0..=BOUND).contains(c))
+}
+
+fn adjacent_positions(pos: Pos) -> Vec<Pos> {
+    pos.neighbors6().into_iter().filter(in_bounds).collect()
+}
+
+fn neighbors(grid: &HashSet<Pos>, pos: Pos) -> Vec<Pos> {
+    adjacent_positions(pos)
+        .into_iter()
+        .filter(|n| grid.contains(n))
+        .collect()
+}
+
+/// The cubes of the scanned droplet.
+pub fn parse(input: &str) -> Result<HashSet<Pos>, ParseError> {
+    let mut grid = HashSet::new();
+
+    for line in parse::lines(input) {
+        let mut coords = line.text.split(',');
+        let mut coord = || {
+            let token = line.require(coords.next(), "a coordinate")?;
+            line.parse(token, "a coordinate")
+        };
+        let cube = Pos::new(coord()?, coord()?, coord()?);
+        if let Some(extra) = coords.next() {
+            return Err(line.error(extra, "three coordinates"));
+        }
+        grid.insert(cube);
+    }
+    Ok(grid)
+}
+
+/// The faces of cubes that do not touch another cube.
+pub fn surface_area(grid: &HashSet<Pos>) -> usize {
+    grid.iter().map(|qb| 6 - neighbors(grid, *qb).len()).sum()
+}
+
+pub fn part1(grid: &HashSet<Pos>) -> Answer {
+    surface_area(grid).into()
+}
+
+/// The air cubes that are trapped inside the droplet, out of reach of the water.
+pub fn trapped(grid: &HashSet<Pos>) -> HashSet<Pos> {
+    // 3D flood fill
+    let adjacent: HashSet<Pos> = HashSet::from_iter(grid.iter().flat_map(|q| {
+        adjacent_positions(*q)
+            .into_iter()
+            .filter(|adj| !grid.contains(adj))
+    }));
+    let mut trapped = HashSet::new();
+    let mut all_visited = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    loop {
+        let mut outside = false;
+        let mut visited = HashSet::new();
+
+        // if adjacent - all_visited is empty, there are no more adjacents to check, break.
+        if adjacent.difference(&all_visited).next().is_none() {
+            break;
+        }
+
+        // take arbitrary cube from adjacent that is not `all_visited` and queue it
+        let cube = adjacent.iter().find(|q| !all_visited.contains(*q)).unwrap();
+        queue.push_back(*cube);
+        while !queue.is_empty() {
+            let qb = queue.pop_front().unwrap();
+
+            visited.insert(qb);
+            all_visited.insert(qb);
+
+            // if it touches the boundaries at 0 or 20
+            if [qb.x, qb.y, qb.z]
+                .iter()
+                .any(|xyz| *xyz == 0 || *xyz >= BOUND)
+            {
+                outside = true;
+            }
+
+            // take every non-visited+non-all_visited neighbor that is not in the grid, queue them
+            let ns = adjacent_positions(qb);
+            for n in ns {
+                if !queue.contains(&n) // why is this even necessary :S
+                    && !visited.contains(&n)
+                    && !all_visited.contains(&n)
+                    && !grid.contains(&n)
+                {
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        if !outside {
+            visited.drain().for_each(|q| {
+                trapped.insert(q);
+            });
+        }
+    }
+
+    trapped
+}
+
+pub fn part2(grid: &HashSet<Pos>) -> Answer {
+    let total = surface_area(grid);
+    // count faces of `trapped` that touch cubes in `grid`, subtract from `total`, report. The end
+    let closed: usize = trapped(grid)
+        .iter()
+        .map(|qb| neighbors(grid, *qb).len())
+        .sum();
+    (total - closed).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let grid = parse(input)?;
+    Ok((part1(&grid), part2(&grid)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbors() {
+        let grid = HashSet::from([Pos::new(0, 0, 0), Pos::new(1, 0, 0)]);
+        let p0 = Pos::new(0, 0, 0);
+        let ns = neighbors(&grid, p0);
+        dbg!(ns);
+    }
+}
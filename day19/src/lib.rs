@@ -0,0 +1,317 @@
+//! Robots that collect ore, clay, obsidian and geodes, and build more robots out of them.
+//!
+//! [`parse()`] reads the [`Blueprint`]s, and [`max_geodes`] searches for the most geodes one of
+//! them can open in some number of minutes, starting from a [`Ctx`] with a single ore robot.
+//!
+//! With the `parallel` feature, on by default, the blueprints and the first builds of each are
+//! searched on rayon's thread pool; without it, one after the other.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::{Answer, ParseError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::fmt;
+
+// Without the `parallel` feature, `par_iter` is a plain iterator over the slice.
+#[cfg(not(feature = "parallel"))]
+trait ParIter<T> {
+    fn par_iter(&self) -> std::slice::Iter<'_, T>;
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> ParIter<T> for [T] {
+    fn par_iter(&self) -> std::slice::Iter<'_, T> {
+        self.iter()
+    }
+}
+
+/// The costs of each kind of robot, in the resources named next to them.
+#[derive(Debug, Clone, Copy)]
+pub struct Blueprint {
+    id: u32,
+    ore: u32,             // ore
+    clay: u32,            // ore
+    obsidian: (u32, u32), // ore, clay
+    geode: (u32, u32),    // ore, obsidian
+}
+
+impl Blueprint {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The cost of an ore robot, in ore.
+    pub fn ore_robot(&self) -> u32 {
+        self.ore
+    }
+
+    /// The cost of a clay robot, in ore.
+    pub fn clay_robot(&self) -> u32 {
+        self.clay
+    }
+
+    /// The cost of an obsidian robot, in ore and clay.
+    pub fn obsidian_robot(&self) -> (u32, u32) {
+        self.obsidian
+    }
+
+    /// The cost of a geode robot, in ore and obsidian.
+    pub fn geode_robot(&self) -> (u32, u32) {
+        self.geode
+    }
+}
+
+/// A quantity of each resource, or a number of robots of each kind.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Counters {
+    pub ore: u32,
+    pub clay: u32,
+    pub obsidian: u32,
+    pub geode: u32,
+}
+
+/// The state of the factory at some point of the search.
+#[derive(Debug, Clone)]
+pub struct Ctx {
+    pub blueprint: Blueprint,
+    pub robots: Counters,
+    pub resources: Counters,
+    pub time_left: u32,
+}
+
+impl Ctx {
+    /// The factory when it starts, with one ore robot, no resources and `minutes` to go.
+    pub fn new(blueprint: Blueprint, minutes: u32) -> Self {
+        Ctx {
+            blueprint,
+            robots: Counters {
+                ore: 1,
+                ..Default::default()
+            },
+            resources: Default::default(),
+            time_left: minutes,
+        }
+    }
+}
+
+const NEXT_BUILDS: [[u32; 4]; 4] = [[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]];
+
+fn recurse(mut ctx: Ctx, next: [u32; 4]) -> u32 {
+    if ctx.time_left == 0 {
+        return ctx.resources.geode;
+    }
+
+    let mut built = false;
+    if enough_resources(&ctx.resources, &ctx.blueprint, &next) {
+        built = true;
+        start_build(&mut ctx, &next);
+    } else if !enough_time(&ctx, &next) {
+        let last_geodes = ctx.robots.geode * ctx.time_left;
+        return ctx.resources.geode + last_geodes;
+    }
+
+    collect_resources(&mut ctx);
+
+    if built {
+        update_robots(&mut ctx, &next);
+        let mut max_geodes = 0;
+        for next in &NEXT_BUILDS {
+            let mut new_ctx = ctx.clone();
+            new_ctx.time_left -= 1;
+            let geodes = recurse(new_ctx, *next);
+            max_geodes = max_geodes.max(geodes);
+        }
+
+        max_geodes
+    } else {
+        let mut new_ctx = ctx.clone();
+        new_ctx.time_left -= 1;
+        recurse(new_ctx, next)
+    }
+}
+
+fn enough_time(ctx: &Ctx, next: &[u32; 4]) -> bool {
+    match *next {
+        [1, 0, 0, 0] => {
+            let needed_ore = ctx.blueprint.ore - ctx.resources.ore;
+            let needed_time = 1 + (needed_ore as f32 / ctx.robots.ore as f32).ceil() as u32;
+            needed_time <= ctx.time_left
+        }
+        [0, 1, 0, 0] => {
+            let needed_ore = ctx.blueprint.clay - ctx.resources.ore;
+            let needed_time = 1 + (needed_ore as f32 / ctx.robots.ore as f32).ceil() as u32;
+            needed_time <= ctx.time_left
+        }
+        [0, 0, 1, 0] => {
+            let needed_ore = ctx.blueprint.obsidian.0.saturating_sub(ctx.resources.ore);
+            let needed_clay = ctx.blueprint.obsidian.1.saturating_sub(ctx.resources.clay);
+            let ore_time = (needed_ore as f32 / ctx.robots.ore as f32).ceil() as u32;
+            let clay_time = (needed_clay as f32 / ctx.robots.clay as f32).ceil() as u32;
+            let needed_time = 1u32.saturating_add(ore_time.max(clay_time));
+            needed_time <= ctx.time_left
+        }
+        [0, 0, 0, 1] => {
+            let needed_ore = ctx.blueprint.geode.0.saturating_sub(ctx.resources.ore);
+            let needed_obs = ctx.blueprint.geode.1.saturating_sub(ctx.resources.obsidian);
+            let ore_time = (needed_ore as f32 / ctx.robots.ore as f32).ceil() as u32;
+            let obs_time = (needed_obs as f32 / ctx.robots.obsidian as f32).ceil() as u32;
+            let needed_time = 1u32.saturating_add(ore_time.max(obs_time));
+            needed_time <= ctx.time_left
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn update_robots(ctx: &mut Ctx, next: &[u32; 4]) {
+    match *next {
+        [1, 0, 0, 0] => {
+            ctx.robots.ore += 1;
+        }
+        [0, 1, 0, 0] => {
+            ctx.robots.clay += 1;
+        }
+        [0, 0, 1, 0] => {
+            ctx.robots.obsidian += 1;
+        }
+        [0, 0, 0, 1] => {
+            ctx.robots.geode += 1;
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn collect_resources(ctx: &mut Ctx) {
+    ctx.resources.ore += ctx.robots.ore;
+    ctx.resources.clay += ctx.robots.clay;
+    ctx.resources.obsidian += ctx.robots.obsidian;
+    ctx.resources.geode += ctx.robots.geode;
+}
+
+fn start_build(ctx: &mut Ctx, next: &[u32; 4]) {
+    match *next {
+        [1, 0, 0, 0] => {
+            ctx.resources.ore -= ctx.blueprint.ore;
+        }
+        [0, 1, 0, 0] => {
+            ctx.resources.ore -= ctx.blueprint.clay;
+        }
+        [0, 0, 1, 0] => {
+            ctx.resources.ore -= ctx.blueprint.obsidian.0;
+            ctx.resources.clay -= ctx.blueprint.obsidian.1;
+        }
+        [0, 0, 0, 1] => {
+            ctx.resources.ore -= ctx.blueprint.geode.0;
+            ctx.resources.obsidian -= ctx.blueprint.geode.1;
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn enough_resources(resources: &Counters, blueprint: &Blueprint, next: &[u32; 4]) -> bool {
+    match *next {
+        [1, 0, 0, 0] => resources.ore >= blueprint.ore,
+        [0, 1, 0, 0] => resources.ore >= blueprint.clay,
+        [0, 0, 1, 0] => {
+            resources.ore >= blueprint.obsidian.0 && resources.clay >= blueprint.obsidian.1
+        }
+        [0, 0, 0, 1] => {
+            resources.ore >= blueprint.geode.0 && resources.obsidian >= blueprint.geode.1
+        }
+        _ => unreachable!(),
+    }
+}
+
+// "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot
+// costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian."
+fn parse_blueprint(line: &Line) -> Result<Blueprint, ParseError> {
+    let cost = |token| line.parse(token, "a cost");
+    let rest = line.literal(line.text, "Blueprint ")?;
+    let (id, rest) = line.require(rest.split_once(": "), "': '")?;
+
+    let rest = line.literal(rest, "Each ore robot costs ")?;
+    let (ore, rest) = line.require(rest.split_once(" ore. "), "' ore. '")?;
+    let rest = line.literal(rest, "Each clay robot costs ")?;
+    let (clay, rest) = line.require(rest.split_once(" ore. "), "' ore. '")?;
+    let rest = line.literal(rest, "Each obsidian robot costs ")?;
+    let (obsidian_ore, rest) = line.require(rest.split_once(" ore and "), "' ore and '")?;
+    let (obsidian_clay, rest) = line.require(rest.split_once(" clay. "), "' clay. '")?;
+    let rest = line.literal(rest, "Each geode robot costs ")?;
+    let (geode_ore, rest) = line.require(rest.split_once(" ore and "), "' ore and '")?;
+    let geode_obsidian = line.require(rest.strip_suffix(" obsidian."), "' obsidian.'")?;
+
+    Ok(Blueprint {
+        id: line.parse(id, "a blueprint number")?,
+        ore: cost(ore)?,
+        clay: cost(clay)?,
+        obsidian: (cost(obsidian_ore)?, cost(obsidian_clay)?),
+        geode: (cost(geode_ore)?, cost(geode_obsidian)?),
+    })
+}
+
+/// Writes the line that `parse` reads.
+impl fmt::Display for Blueprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            self.id,
+            self.ore,
+            self.clay,
+            self.obsidian.0,
+            self.obsidian.1,
+            self.geode.0,
+            self.geode.1
+        )
+    }
+}
+
+/// The most geodes the factory can open in `minutes` following `blueprint`.
+pub fn max_geodes(blueprint: Blueprint, minutes: u32) -> u32 {
+    let ctx = Ctx::new(blueprint, minutes);
+
+    let max_geodes: u32 = NEXT_BUILDS
+        .par_iter()
+        .map(|next| {
+            let new_ctx = ctx.clone();
+            recurse(new_ctx, *next)
+        })
+        .max()
+        .unwrap();
+
+    log::info!(
+        "Ran blueprint {} for {minutes} minutes. Max geodes: {max_geodes}",
+        blueprint.id
+    );
+    max_geodes
+}
+
+pub fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    parse::lines(input)
+        .map(|line| parse_blueprint(&line))
+        .collect()
+}
+
+pub fn part1(blueprints: &[Blueprint]) -> Answer {
+    let quality: u32 = blueprints
+        .par_iter()
+        .map(|blueprint| blueprint.id * max_geodes(*blueprint, 24))
+        .sum();
+    quality.into()
+}
+
+pub fn part2(blueprints: &[Blueprint]) -> Answer {
+    // the elephants ate all but the first three blueprints
+    let geodes: u32 = blueprints
+        .par_iter()
+        .take(3)
+        .map(|blueprint| max_geodes(*blueprint, 32))
+        .product();
+    geodes.into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let blueprints = parse(input)?;
+    Ok((part1(&blueprints), part2(&blueprints)))
+}
@@ -0,0 +1,778 @@
+//! A strategy guide for rock paper scissors, and for the other games played in a cycle.
+//!
+//! A [`CyclicGame`] has an odd number of moves, each beating the half of the others listed just
+//! before it, round the cycle: rock paper scissors, or rock paper scissors lizard Spock listed as
+//! rock, Spock, paper, lizard, scissors. The game is rock paper scissors unless rules are given
+//! (see [`CyclicGame::from_str`] for their format and [`Options`] for the command line).
+//!
+//! [`parse()`] reads each round as the move of the opponent, then the second column read both as
+//! a move to play and as the [`Outcome`] to aim for. [`CyclicGame::report`] tries every other
+//! meaning the letters of the second column could have, for the best and worst totals.
+
+use aoc_common::options::{self, Mode, Reporter};
+use aoc_common::{parse, Answer, Line, ParseError};
+use std::cmp::Reverse;
+use std::fmt::{self, Write};
+use std::fs;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Tie,
+    Win,
+}
+
+impl Outcome {
+    const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Tie, Outcome::Win];
+}
+
+/// `loss`, `tie` or `win`.
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Outcome::Loss => "loss",
+            Outcome::Tie => "tie",
+            Outcome::Win => "win",
+        })
+    }
+}
+
+/// The moves of a game played in a cycle, the letters naming them in the strategy guide and what
+/// playing them is worth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CyclicGame {
+    /// The moves in order, each beating the `(len - 1) / 2` before it.
+    pub moves: Vec<String>,
+    /// The letter of each move in the first column.
+    pub opponent: Vec<char>,
+    /// The letter of each move in the second column, read as the move to play.
+    pub player: Vec<char>,
+    /// The letters of a loss, a tie and a win in the second column, read as the outcome to aim
+    /// for. Without them, the second column is only read as a move.
+    pub outcomes: Option<[char; 3]>,
+    /// The points for playing each move.
+    pub points: Vec<u32>,
+    /// The points for a loss, a tie and a win.
+    pub outcome_points: [u32; 3],
+}
+
+/// Rock paper scissors, as the puzzle scores it.
+impl Default for CyclicGame {
+    fn default() -> Self {
+        CyclicGame {
+            moves: ["rock", "paper", "scissors"].map(String::from).to_vec(),
+            opponent: vec!['A', 'B', 'C'],
+            player: vec!['X', 'Y', 'Z'],
+            outcomes: Some(['X', 'Y', 'Z']),
+            points: vec![1, 2, 3],
+            outcome_points: [0, 3, 6],
+        }
+    }
+}
+
+// the single character of a token
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+// single letters, each at most once
+fn letters<'a>(line: &Line<'a>, value: &'a str) -> Result<Vec<char>, ParseError> {
+    let mut letters = vec![];
+    for token in value.split_whitespace() {
+        let letter = single_char(token)
+            .filter(|c| !letters.contains(c))
+            .ok_or_else(|| line.error(token, "a letter not given yet"))?;
+        letters.push(letter);
+    }
+    Ok(letters)
+}
+
+fn numbers<'a>(line: &Line<'a>, value: &'a str) -> Result<Vec<u32>, ParseError> {
+    value
+        .split_whitespace()
+        .map(|token| line.parse(token, "a number of points"))
+        .collect()
+}
+
+// `items` if there are `count` of them, or an error at the end of the line
+fn count<T>(line: &Line, items: Vec<T>, count: usize, what: &str) -> Result<Vec<T>, ParseError> {
+    if items.len() == count {
+        Ok(items)
+    } else {
+        Err(line.error(line.end(), format!("{count} {what}")))
+    }
+}
+
+const PER_OUTCOME: &str = "for a loss, a tie and a win";
+
+/// Reads rules such as these, one `key: value` per line, with `#` starting a comment:
+///
+/// ```text
+/// moves: rock spock paper lizard scissors
+/// opponent: A B C D E
+/// player: V W X Y Z
+/// outcomes: X Y Z
+/// points: 1 5 2 4 3
+/// outcome points: 0 3 6
+/// ```
+///
+/// `moves`, `opponent` and `player` are required. `outcomes` gives the letters of a loss, a tie
+/// and a win, without which the second column is never read as an outcome. `points` defaults to 1
+/// for the first move, 2 for the second and so on, and `outcome points` to 0, 3 and 6.
+impl FromStr for CyclicGame {
+    type Err = ParseError;
+
+    fn from_str(rules: &str) -> Result<Self, Self::Err> {
+        let mut moves = None;
+        let (mut opponent, mut player, mut outcomes) = (None, None, None);
+        let (mut points, mut outcome_points) = (None, None);
+        for line in parse::lines(rules) {
+            let text = line
+                .text
+                .split_once('#')
+                .map_or(line.text, |(text, _)| text);
+            if text.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = line.require(text.split_once(':'), "`key: value`")?;
+            match key.trim() {
+                "moves" => {
+                    let names: Vec<String> = value.split_whitespace().map(String::from).collect();
+                    if names.len() < 3 || names.len().is_multiple_of(2) {
+                        return Err(line.error(value.trim(), "an odd number of moves, at least 3"));
+                    }
+                    moves = Some(names);
+                }
+                "opponent" => opponent = Some((line, letters(&line, value)?)),
+                "player" => player = Some((line, letters(&line, value)?)),
+                "outcomes" => {
+                    let letters = count(
+                        &line,
+                        letters(&line, value)?,
+                        3,
+                        &format!("letters {PER_OUTCOME}"),
+                    )?;
+                    outcomes = Some([letters[0], letters[1], letters[2]]);
+                }
+                "points" => points = Some((line, numbers(&line, value)?)),
+                "outcome points" => {
+                    let numbers = count(
+                        &line,
+                        numbers(&line, value)?,
+                        3,
+                        &format!("numbers {PER_OUTCOME}"),
+                    )?;
+                    outcome_points = Some([numbers[0], numbers[1], numbers[2]]);
+                }
+                _ => {
+                    return Err(line.error(
+                        key,
+                        "`moves`, `opponent`, `player`, `outcomes`, `points` or `outcome points`",
+                    ))
+                }
+            }
+        }
+
+        let moves = moves.ok_or_else(|| ParseError::end_of_input(rules, "`moves`"))?;
+        let n = moves.len();
+        let column = |key: &str, letters: Option<(Line, Vec<char>)>| match letters {
+            Some((line, letters)) => count(&line, letters, n, "letters, one per move"),
+            None => Err(ParseError::end_of_input(rules, format!("`{key}`"))),
+        };
+        let opponent = column("opponent", opponent)?;
+        let player = column("player", player)?;
+        let points = match points {
+            Some((line, points)) => count(&line, points, n, "numbers, one per move")?,
+            None => (1..=n as u32).collect(),
+        };
+        Ok(CyclicGame {
+            moves,
+            opponent,
+            player,
+            outcomes,
+            points,
+            outcome_points: outcome_points.unwrap_or([0, 3, 6]),
+        })
+    }
+}
+
+/// The options of day 2 on the command line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// The rules loaded from the file given with `--rules <path>`, rock paper scissors without
+    /// it.
+    pub game: CyclicGame,
+    /// `--analyze`, for the report of [`CyclicGame::report`].
+    pub analyze: bool,
+}
+
+impl Options {
+    /// Takes the options out of the command line arguments, loading the rules they name.
+    pub fn extract(args: &mut Vec<String>) -> Result<Options, String> {
+        let game = match options::extract::<String>(args, "--rules", "a path")? {
+            Some(path) => {
+                let rules = fs::read_to_string(&path).map_err(|e| format!("{path}: {e}"))?;
+                rules.parse().map_err(|e| format!("{path}:{e}"))?
+            }
+            None => CyclicGame::default(),
+        };
+        let analyze = options::extract_flag(args, "--analyze");
+        if analyze && game.moves.len() > MAX_ANALYZED_MOVES {
+            return Err(format!(
+                "--analyze takes games of at most {MAX_ANALYZED_MOVES} moves, not {}",
+                game.moves.len()
+            ));
+        }
+        Ok(Options { game, analyze })
+    }
+}
+
+/// A round of the strategy guide, by the indices of the moves in the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    /// The second column read as the move to play, if it names one.
+    pub own: Option<usize>,
+    /// The second column read as the outcome to aim for, if it names one.
+    pub outcome: Option<Outcome>,
+}
+
+impl CyclicGame {
+    /// How playing `own` against `opponent` ends.
+    pub fn outcome(&self, opponent: usize, own: usize) -> Outcome {
+        let n = self.moves.len();
+        match (own + n - opponent) % n {
+            0 => Outcome::Tie,
+            d if d <= (n - 1) / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// The move that ends a round against `opponent` with `outcome`. Of several, the one worth the
+    /// most points, and of those the first.
+    pub fn play_for(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.moves.len())
+            .filter(|own| self.outcome(opponent, *own) == outcome)
+            .max_by_key(|own| (self.points[*own], Reverse(*own)))
+            .unwrap()
+    }
+
+    /// The points for playing `own` against `opponent`, in a `u64` that any points of the rules
+    /// add up in.
+    pub fn score(&self, opponent: usize, own: usize) -> u64 {
+        let outcome = self.outcome(opponent, own);
+        u64::from(self.points[own]) + u64::from(self.outcome_points[outcome as usize])
+    }
+
+    // The second column is read both as the move to play (part 1) and as the outcome (part 2), and
+    // must be at least one of them.
+    fn parse_round(&self, line: &Line) -> Result<Round, ParseError> {
+        let (opponent, second) = line.require(line.text.split_once(' '), "two columns")?;
+        let opponent = single_char(opponent)
+            .and_then(|c| self.opponent.iter().position(|&letter| letter == c))
+            .ok_or_else(|| line.error(opponent, self.expected(&self.opponent, "opponent move")))?;
+        let c = single_char(second);
+        let own = c.and_then(|c| self.player.iter().position(|&letter| letter == c));
+        let outcome = c
+            .zip(self.outcomes)
+            .and_then(|(c, outcomes)| outcomes.iter().position(|&letter| letter == c))
+            .map(|i| Outcome::ALL[i]);
+        if own.is_none() && outcome.is_none() {
+            let mut letters = self.player.clone();
+            let outcomes = self.outcomes.iter().flatten();
+            letters.extend(outcomes.filter(|c| !self.player.contains(c)));
+            return Err(line.error(second, self.expected(&letters, "move or outcome")));
+        }
+        Ok(Round {
+            opponent,
+            own,
+            outcome,
+        })
+    }
+
+    // e.g. `opponent move 'A', 'B' or 'C'`
+    fn expected(&self, letters: &[char], what: &str) -> String {
+        let quoted: Vec<_> = letters.iter().map(|c| format!("'{c}'")).collect();
+        let (last, rest) = quoted.split_last().unwrap();
+        format!("{what} {} or {last}", rest.join(", "))
+    }
+
+    /// The rounds of a strategy guide for this game.
+    pub fn parse(&self, input: &str) -> Result<Vec<Round>, ParseError> {
+        parse::lines(input)
+            .map(|line| self.parse_round(&line))
+            .collect()
+    }
+
+    /// The total score, playing the move of the second column. Missing if a round has none.
+    pub fn part1(&self, rounds: &[Round]) -> Answer {
+        total(
+            rounds
+                .iter()
+                .map(|round| Some(self.score(round.opponent, round.own?))),
+        )
+    }
+
+    /// The total score, playing for the outcome of the second column. Missing if a round has none.
+    pub fn part2(&self, rounds: &[Round]) -> Answer {
+        total(rounds.iter().map(|round| {
+            let own = self.play_for(round.opponent, round.outcome?);
+            Some(self.score(round.opponent, own))
+        }))
+    }
+
+    pub fn solve(&self, input: &str) -> Result<(Answer, Answer), ParseError> {
+        let rounds = self.parse(input)?;
+        Ok((self.part1(&rounds), self.part2(&rounds)))
+    }
+}
+
+// The sum of the scores of the rounds, missing if a round has no score. An answer is a number while
+// it fits in an `i64`, and its digits past that; a total overflowing a `u64` is logged as an error,
+// with no answer.
+fn total(scores: impl Iterator<Item = Option<u64>>) -> Answer {
+    let mut total = 0u64;
+    for (i, score) in scores.enumerate() {
+        let Some(score) = score else {
+            return Answer::Missing;
+        };
+        total = match total.checked_add(score) {
+            Some(total) => total,
+            None => {
+                log::error!("the total score overflows a u64 at round {}", i + 1);
+                return Answer::Missing;
+            }
+        };
+    }
+    i64::try_from(total).map_or_else(|_| total.to_string().into(), Answer::Int)
+}
+
+/// What the letters of the second column mean, in the order the game lists them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mapping {
+    /// The move each letter of `player` plays.
+    Moves(Vec<usize>),
+    /// The outcome each letter of `outcomes` aims for.
+    Outcomes([Outcome; 3]),
+}
+
+/// How a round goes under some mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub opponent: usize,
+    pub own: usize,
+    pub outcome: Outcome,
+    pub points: u64,
+}
+
+/// A mapping of the second column, with the total it scores and how each round goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scored {
+    pub mapping: Mapping,
+    pub total: u64,
+    pub plays: Vec<Play>,
+}
+
+/// The best and worst totals one reading of the second column can score, over every mapping of
+/// its letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub best: Scored,
+    pub worst: Scored,
+}
+
+/// The most moves [`CyclicGame::analyze_moves`] takes, as it tries each of the `n!` ways to map
+/// the letters to the moves: 5,040 for 7 moves, but 39,916,800 for 11.
+pub const MAX_ANALYZED_MOVES: usize = 7;
+
+/// Why a reading of the second column has no [`Analysis`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoAnalysis {
+    /// A round's letter does not name a move, or an outcome.
+    Unreadable,
+    /// The total of some mapping overflows a `u64`.
+    Overflow,
+}
+
+// every order of 0..n, one at a time in lexicographic order, starting with 0..n itself
+fn permutations(n: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut next = Some((0..n).collect::<Vec<_>>());
+    std::iter::from_fn(move || {
+        let order = next.take()?;
+        // the next order raises the element before the longest descending tail to the smallest
+        // larger one in the tail, then puts the tail back in ascending order
+        if let Some(i) = (1..n).rev().find(|&i| order[i - 1] < order[i]) {
+            let mut following = order.clone();
+            let j = (i..n)
+                .rev()
+                .find(|&j| following[j] > following[i - 1])
+                .unwrap();
+            following.swap(i - 1, j);
+            following[i..].reverse();
+            next = Some(following);
+        }
+        Some(order)
+    })
+}
+
+impl CyclicGame {
+    /// How `round` goes if its second column means what `mapping` says, if it is one of the
+    /// letters mapped.
+    pub fn play(&self, round: &Round, mapping: &Mapping) -> Option<Play> {
+        let opponent = round.opponent;
+        let (own, outcome) = match mapping {
+            Mapping::Moves(moves) => {
+                let own = moves[round.own?];
+                (own, self.outcome(opponent, own))
+            }
+            Mapping::Outcomes(outcomes) => {
+                let outcome = outcomes[round.outcome? as usize];
+                (self.play_for(opponent, outcome), outcome)
+            }
+        };
+        Some(Play {
+            opponent,
+            own,
+            outcome,
+            points: self.score(opponent, own),
+        })
+    }
+
+    // the mapping of `mappings` scoring the most, and the one scoring the least; the first of
+    // those tied. Every round's letter is mapped, as the callers check.
+    fn best_and_worst(
+        &self,
+        rounds: &[Round],
+        mappings: impl Iterator<Item = Mapping>,
+    ) -> Result<Analysis, NoAnalysis> {
+        let mut best: Option<(u64, Mapping)> = None;
+        let mut worst: Option<(u64, Mapping)> = None;
+        for mapping in mappings {
+            let total = rounds.iter().try_fold(0u64, |total, round| {
+                total.checked_add(self.play(round, &mapping).unwrap().points)
+            });
+            let total = total.ok_or(NoAnalysis::Overflow)?;
+            if best.as_ref().is_none_or(|(best, _)| total > *best) {
+                best = Some((total, mapping.clone()));
+            }
+            if worst.as_ref().is_none_or(|(worst, _)| total < *worst) {
+                worst = Some((total, mapping));
+            }
+        }
+        let scored = |(total, mapping): (u64, Mapping)| Scored {
+            plays: rounds
+                .iter()
+                .map(|round| self.play(round, &mapping).unwrap())
+                .collect(),
+            mapping,
+            total,
+        };
+        // there is always a mapping, if only of no letters
+        Ok(Analysis {
+            best: scored(best.unwrap()),
+            worst: scored(worst.unwrap()),
+        })
+    }
+
+    /// Reads the second column as moves, mapping its letters to the moves every possible way.
+    ///
+    /// # Panics
+    ///
+    /// If the game has more than [`MAX_ANALYZED_MOVES`] moves.
+    pub fn analyze_moves(&self, rounds: &[Round]) -> Result<Analysis, NoAnalysis> {
+        let n = self.moves.len();
+        assert!(n <= MAX_ANALYZED_MOVES, "{n} moves are too many to analyze");
+        if rounds.iter().any(|round| round.own.is_none()) {
+            return Err(NoAnalysis::Unreadable);
+        }
+        self.best_and_worst(rounds, permutations(n).map(Mapping::Moves))
+    }
+
+    /// Reads the second column as outcomes, mapping its letters to the outcomes every possible
+    /// way.
+    pub fn analyze_outcomes(&self, rounds: &[Round]) -> Result<Analysis, NoAnalysis> {
+        if rounds.iter().any(|round| round.outcome.is_none()) {
+            return Err(NoAnalysis::Unreadable);
+        }
+        let mappings =
+            permutations(3).map(|p| Mapping::Outcomes([p[0], p[1], p[2]].map(|i| Outcome::ALL[i])));
+        self.best_and_worst(rounds, mappings)
+    }
+
+    /// `X=rock Y=paper Z=scissors` or `X=loss Y=tie Z=win`.
+    pub fn describe(&self, mapping: &Mapping) -> String {
+        let pairs: Vec<String> = match mapping {
+            Mapping::Moves(moves) => self
+                .player
+                .iter()
+                .zip(moves)
+                .map(|(letter, own)| format!("{letter}={}", self.moves[*own]))
+                .collect(),
+            Mapping::Outcomes(outcomes) => self
+                .outcomes
+                .iter()
+                .flatten()
+                .zip(outcomes)
+                .map(|(letter, outcome)| format!("{letter}={outcome}"))
+                .collect(),
+        };
+        pairs.join(" ")
+    }
+
+    /// Parses an input and [reports](Self::report) on it, for `--analyze`.
+    pub fn reporter(self) -> Reporter {
+        Box::new(move |input| Ok(self.report(&self.parse(input)?)))
+    }
+
+    /// Both readings of the second column analyzed, the best and worst mapping of each with how
+    /// every round goes under them. Panics like [`analyze_moves`](Self::analyze_moves).
+    pub fn report(&self, rounds: &[Round]) -> String {
+        let mut report = String::new();
+        let readings = [
+            ("the move to play", self.analyze_moves(rounds)),
+            ("the outcome to aim for", self.analyze_outcomes(rounds)),
+        ];
+        for (reading, analysis) in readings {
+            let Analysis { best, worst } = match analysis {
+                Ok(analysis) => analysis,
+                Err(NoAnalysis::Unreadable) => {
+                    writeln!(report, "The second column cannot be read as {reading}.\n").unwrap();
+                    continue;
+                }
+                Err(NoAnalysis::Overflow) => {
+                    let overflow = "a total overflows a u64";
+                    writeln!(report, "The second column as {reading}: {overflow}.\n").unwrap();
+                    continue;
+                }
+            };
+            writeln!(report, "The second column as {reading}:").unwrap();
+            writeln!(
+                report,
+                "best  {:>6}  {}",
+                best.total,
+                self.describe(&best.mapping)
+            )
+            .unwrap();
+            writeln!(
+                report,
+                "worst {:>6}  {}",
+                worst.total,
+                self.describe(&worst.mapping)
+            )
+            .unwrap();
+            let w = self.moves.iter().map(String::len).max().unwrap_or(0);
+            let o = w.max("opponent".len());
+            // a move, how the round ends and the points, e.g. `paper    win    8`
+            let play = |play: &Play| {
+                let own = &self.moves[play.own];
+                format!(
+                    "{own:<w$} {:<4} {:>3}",
+                    play.outcome.to_string(),
+                    play.points
+                )
+            };
+            let column = w + 9;
+            writeln!(
+                report,
+                "round  {:<o$}  {:<column$}  worst",
+                "opponent", "best"
+            )
+            .unwrap();
+            for (i, (best, worst)) in best.plays.iter().zip(&worst.plays).enumerate() {
+                let opponent = &self.moves[best.opponent];
+                let (best, worst) = (play(best), play(worst));
+                writeln!(report, "{:>5}  {opponent:<o$}  {best}  {worst}", i + 1).unwrap();
+            }
+            report.push('\n');
+        }
+        report
+    }
+}
+
+/// Takes the options out of the command line arguments, and returns what they ask for: the
+/// game's answers, or with `--analyze` the report of [`CyclicGame::report`] instead.
+pub fn mode(args: &mut Vec<String>) -> Result<Mode, String> {
+    let Options { game, analyze } = Options::extract(args)?;
+    if analyze {
+        return Ok(Mode::Report(game.reporter()));
+    }
+    Ok(Mode::Solve(Box::new(move |input| game.solve(input))))
+}
+
+/// The rounds of a strategy guide for rock paper scissors.
+pub fn parse(input: &str) -> Result<Vec<Round>, ParseError> {
+    CyclicGame::default().parse(input)
+}
+
+pub fn part1(rounds: &[Round]) -> Answer {
+    CyclicGame::default().part1(rounds)
+}
+
+pub fn part2(rounds: &[Round]) -> Answer {
+    CyclicGame::default().part2(rounds)
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    CyclicGame::default().solve(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RPSLS: &str = "\
+# rock paper scissors lizard Spock
+moves: rock spock paper lizard scissors
+opponent: A B C D E
+player: V W X Y Z
+outcomes: X Y Z
+points: 1 5 2 4 3
+";
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        assert_eq!(
+            solve("A Y\nB X\nC Z\n"),
+            Ok((Answer::Int(15), Answer::Int(12)))
+        );
+        let game = CyclicGame::default();
+        // paper beats rock, which beats scissors
+        assert_eq!(game.outcome(0, 1), Outcome::Win);
+        assert_eq!(game.outcome(2, 0), Outcome::Win);
+        assert_eq!(game.outcome(0, 2), Outcome::Loss);
+        assert_eq!(
+            parse("A W").unwrap_err().to_string(),
+            "1:3: expected move or outcome 'X', 'Y' or 'Z'"
+        );
+    }
+
+    #[test]
+    fn test_analyze() {
+        let orders: Vec<_> = permutations(3).collect();
+        assert_eq!(orders.len(), 6);
+        assert_eq!(orders[0], [0, 1, 2]);
+        assert_eq!(orders[1], [0, 2, 1]);
+        assert_eq!(orders[5], [2, 1, 0]);
+        assert_eq!(permutations(5).count(), 120);
+        assert_eq!(permutations(0).collect::<Vec<_>>(), [vec![]]);
+
+        let game = CyclicGame::default();
+        let rounds = parse("A Y\nB X\nC Z\n").unwrap();
+        let moves = game.analyze_moves(&rounds).unwrap();
+        assert_eq!(
+            game.describe(&moves.best.mapping),
+            "X=scissors Y=paper Z=rock"
+        );
+        assert_eq!((moves.best.total, moves.worst.total), (24, 6));
+        let outcomes = game.analyze_outcomes(&rounds).unwrap();
+        assert_eq!(game.describe(&outcomes.best.mapping), "X=win Y=loss Z=tie");
+        // the puzzle's own mapping is the worst here
+        assert_eq!(
+            outcomes.worst.mapping,
+            Mapping::Outcomes([Outcome::Loss, Outcome::Tie, Outcome::Win])
+        );
+        assert_eq!(outcomes.worst.total, 12);
+        // `V` is a move but not an outcome
+        let game: CyclicGame = RPSLS.parse().unwrap();
+        let rounds = game.parse("A V\n").unwrap();
+        assert!(game.analyze_moves(&rounds).is_ok());
+        assert_eq!(game.analyze_outcomes(&rounds), Err(NoAnalysis::Unreadable));
+        assert_eq!(
+            outcomes.best.plays[0],
+            Play {
+                opponent: 0,
+                own: 2,
+                outcome: Outcome::Loss,
+                points: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_rules() {
+        let game: CyclicGame = RPSLS.parse().unwrap();
+        assert_eq!(game.moves.len(), 5);
+        assert_eq!(game.outcome_points, [0, 3, 6]);
+        // Spock smashes scissors and vaporizes rock
+        assert_eq!(game.outcome(4, 1), Outcome::Win);
+        assert_eq!(game.outcome(0, 1), Outcome::Win);
+        assert_eq!(game.outcome(3, 1), Outcome::Loss);
+        // both Spock and paper beat rock, and Spock is worth more
+        assert_eq!(game.play_for(0, Outcome::Win), 1);
+
+        let rounds = game.parse("A W\nC V\nA Z\n").unwrap();
+        assert_eq!(rounds[0].outcome, None);
+        // Spock beats rock, rock loses to paper and scissors lose to rock
+        assert_eq!(game.part1(&rounds), Answer::Int(11 + 1 + 3));
+        assert_eq!(game.part2(&rounds), Answer::Missing);
+
+        assert_eq!(
+            "moves: a b c d\n"
+                .parse::<CyclicGame>()
+                .unwrap_err()
+                .to_string(),
+            "1:8: expected an odd number of moves, at least 3"
+        );
+        assert_eq!(
+            "moves: a b c\nopponent: A B\n"
+                .parse::<CyclicGame>()
+                .unwrap_err()
+                .to_string(),
+            "2:14: expected 3 letters, one per move"
+        );
+        assert_eq!(
+            "moves: a b c\nopponent: A B C\n"
+                .parse::<CyclicGame>()
+                .unwrap_err()
+                .to_string(),
+            "3:1: expected `player`"
+        );
+
+        // scores add up past a `u32`, and a total past a `u64` has no answer
+        let game = CyclicGame {
+            points: vec![u32::MAX; 3],
+            ..CyclicGame::default()
+        };
+        let rounds = game.parse("A X\nB Z\n").unwrap();
+        assert_eq!(
+            game.part1(&rounds),
+            Answer::Int(2 * u32::MAX as i64 + 3 + 6)
+        );
+        assert_eq!(
+            total([Some(u64::MAX), Some(1)].into_iter()),
+            Answer::Missing
+        );
+        assert_eq!(
+            total([Some(u64::MAX)].into_iter()),
+            Answer::Text(u64::MAX.to_string())
+        );
+
+        let mut args = vec!["--analyze".to_string(), "-".to_string()];
+        let options = Options::extract(&mut args).unwrap();
+        assert!(options.analyze && options.game == CyclicGame::default());
+        assert_eq!(args, ["-"]);
+        assert_eq!(
+            Options::extract(&mut vec!["--rules".to_string()]),
+            Err("--rules expects a path".to_string())
+        );
+
+        // nine moves map 362,880 ways, too many to analyze
+        let path = std::env::temp_dir().join(format!("day2-rules-{}.txt", std::process::id()));
+        let rules = "moves: a b c d e f g h i\n\
+                     opponent: A B C D E F G H I\n\
+                     player: R S T U V W X Y Z\n";
+        fs::write(&path, rules).unwrap();
+        let args = ["--rules", path.to_str().unwrap(), "--analyze"].map(String::from);
+        let extracted = Options::extract(&mut args.to_vec());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            extracted,
+            Err("--analyze takes games of at most 7 moves, not 9".to_string())
+        );
+    }
+}
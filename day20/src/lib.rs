@@ -0,0 +1,87 @@
+//! Decrypting the coordinates of the grove by mixing a file of numbers.
+//!
+//! [`parse()`] reads the numbers of the file, and [`grove_coordinates`] mixes them.
+
+use aoc_common::{parse, Answer, ParseError};
+
+/// The decryption key of part 2.
+pub const KEY: isize = 811589153;
+
+struct N {
+    n: isize,
+    ix: usize,
+}
+
+/// The encrypted file, which must contain a 0.
+pub fn parse(input: &str) -> Result<Vec<isize>, ParseError> {
+    let numbers = parse::lines(input)
+        .map(|line| line.parse(line.text, "a number"))
+        .collect::<Result<Vec<isize>, ParseError>>()?;
+
+    if !numbers.contains(&0) {
+        return Err(ParseError::end_of_input(input, "a 0 in the list"));
+    }
+
+    Ok(numbers)
+}
+
+pub fn part1(numbers: &[isize]) -> Answer {
+    grove_coordinates(numbers, 1, 1).into()
+}
+
+pub fn part2(numbers: &[isize]) -> Answer {
+    grove_coordinates(numbers, KEY, 10).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let numbers = parse(input)?;
+    Ok((part1(&numbers), part2(&numbers)))
+}
+
+/// The sum of the numbers 1000, 2000 and 3000 after 0, once each number is multiplied by `key` and
+/// the file is mixed `rounds` times.
+pub fn grove_coordinates(numbers: &[isize], key: isize, rounds: usize) -> isize {
+    let mut numbers: Vec<N> = numbers
+        .iter()
+        .enumerate()
+        .map(|(ix, n)| N { ix, n: key * n })
+        .collect();
+    let len = numbers.len();
+
+    for _ in 0..rounds {
+        for i in 0..len {
+            // find N with original ix == i
+            let ix = numbers.iter().position(|N { ix, .. }| *ix == i).unwrap();
+            // get new location based on N.n
+            let new_ix = match numbers[ix].n {
+                0 => {
+                    continue;
+                }
+                n => ((ix as isize + n).rem_euclid(len as isize - 1)) as usize,
+            };
+            // move item
+            let n = numbers.remove(ix);
+            numbers.insert(new_ix, n);
+        }
+    }
+
+    let zero = numbers.iter().position(|N { n, .. }| *n == 0).unwrap();
+    let n1 = numbers[(zero + 1000) % len].n;
+    let n2 = numbers[(zero + 2000) % len].n;
+    let n3 = numbers[(zero + 3000) % len].n;
+    n1 + n2 + n3
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_mod() {
+        // [0, 1, 2, 3]
+        // pos ^
+        let len = 4;
+        let n = -3;
+        let pos = 1isize;
+        let target = (pos + n).rem_euclid(len);
+        dbg!(target);
+    }
+}
@@ -0,0 +1,285 @@
+//! Monkeys yelling numbers, or the results of operations on what others yell.
+//!
+//! [`parse()`] reads every [`Monkey`] by name. Part 2 works back from `root` to the number
+//! `humn` must yell for both operands of `root` to be equal.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::{Answer, ParseError};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+/// The job of a monkey: yell a number, or the result of an operation on what two others yell.
+#[derive(Debug)]
+pub enum Monkey {
+    K(u64),
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+}
+
+impl Monkey {
+    fn is_k(&self) -> bool {
+        matches!(self, Monkey::K(_))
+    }
+
+    fn needs(&self, name: &str) -> bool {
+        match self {
+            Monkey::K(_) => false,
+            Monkey::Add(a, b) | Monkey::Sub(a, b) | Monkey::Mul(a, b) | Monkey::Div(a, b) => {
+                a == name || b == name
+            }
+        }
+    }
+
+    fn operands(&self) -> [&String; 2] {
+        assert!(!self.is_k());
+        match self {
+            Monkey::Add(a, b) | Monkey::Sub(a, b) | Monkey::Mul(a, b) | Monkey::Div(a, b) => [a, b],
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Writes the job as it follows the name of the monkey in the input, e.g. `pppw + sjmn` or `5`.
+impl fmt::Display for Monkey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Monkey::K(k) => write!(f, "{k}"),
+            Monkey::Add(a, b) => write!(f, "{a} + {b}"),
+            Monkey::Sub(a, b) => write!(f, "{a} - {b}"),
+            Monkey::Mul(a, b) => write!(f, "{a} * {b}"),
+            Monkey::Div(a, b) => write!(f, "{a} / {b}"),
+        }
+    }
+}
+
+// "root: pppw + sjmn" or "dbpl: 5", where the operands must be among `names`
+fn parse_monkey(line: &Line, names: &HashSet<&str>) -> Result<(String, Monkey), ParseError> {
+    let (name, task) = line.require(line.text.split_once(": "), "': '")?;
+    if let Ok(k) = task.parse::<u64>() {
+        return Ok((name.to_string(), Monkey::K(k)));
+    }
+
+    let mut parts = task.split(' ');
+    let operand = |token: Option<&str>| {
+        let token = line.require(token, "a monkey name")?;
+        if names.contains(token) {
+            Ok(token.to_string())
+        } else {
+            Err(line.error(token, "a number or a monkey name"))
+        }
+    };
+    let a = operand(parts.next())?;
+    let op = line.require(parts.next(), "operator '+', '-', '*' or '/'")?;
+    let b = operand(parts.next())?;
+    let monkey = match op {
+        "+" => Monkey::Add(a, b),
+        "-" => Monkey::Sub(a, b),
+        "*" => Monkey::Mul(a, b),
+        "/" => Monkey::Div(a, b),
+        _ => return Err(line.error(op, "operator '+', '-', '*' or '/'")),
+    };
+    if let Some(extra) = parts.next() {
+        return Err(line.error(extra, "the end of the line"));
+    }
+    Ok((name.to_string(), monkey))
+}
+
+/// Every monkey by name. `root` and `humn` are always present.
+pub fn parse(input: &str) -> Result<HashMap<String, Monkey>, ParseError> {
+    let lines: Vec<_> = parse::lines(input).collect();
+    let names: HashSet<&str> = lines
+        .iter()
+        .filter_map(|line| line.text.split_once(": ").map(|(name, _)| name))
+        .collect();
+    for name in ["root", "humn"] {
+        if !names.contains(name) {
+            return Err(ParseError::end_of_input(
+                input,
+                format!("a monkey named '{name}'"),
+            ));
+        }
+    }
+    lines
+        .iter()
+        .map(|line| parse_monkey(line, &names))
+        .collect()
+}
+
+pub fn part1(monkeys: &HashMap<String, Monkey>) -> Answer {
+    let starters: HashSet<String> = monkeys
+        .iter()
+        .filter_map(|(name, op)| op.is_k().then_some(name.to_string()))
+        .collect();
+    let queue = toposort(monkeys, starters, "root");
+    yell(monkeys, queue).into()
+}
+
+pub fn part2(monkeys: &HashMap<String, Monkey>) -> Answer {
+    let (lq, rq) = split_branches(monkeys, "root");
+    let (human_branch, monkey_branch) = if lq.contains(&"humn".to_string()) {
+        (lq, rq)
+    } else {
+        (rq, lq)
+    };
+
+    reverse_engineer(monkeys, human_branch, yell(monkeys, monkey_branch)).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let monkeys = parse(input)?;
+    Ok((part1(&monkeys), part2(&monkeys)))
+}
+
+fn reverse_engineer(monkeys: &HashMap<String, Monkey>, queue: Vec<String>, target: u64) -> u64 {
+    // Which of the two branches is the known operand
+    enum KnownOp {
+        L,
+        R,
+    }
+
+    assert!(!queue.is_empty());
+    let root = queue.last().unwrap();
+    let (lq, rq) = split_branches(monkeys, root);
+    let (human_branch, monkey_branch, known) = if lq.contains(&"humn".to_string()) {
+        (lq, rq, KnownOp::R)
+    } else {
+        (rq, lq, KnownOp::L)
+    };
+
+    let known_operand = yell(monkeys, monkey_branch);
+    let root_monkey = &monkeys[root];
+    assert!(!root_monkey.is_k());
+
+    let op = match root_monkey {
+        Monkey::Add(_, _) => Op::Add,
+        Monkey::Sub(_, _) => match known {
+            KnownOp::R => Op::Subr,
+            KnownOp::L => Op::Subl,
+        },
+        Monkey::Mul(_, _) => Op::Mul,
+        Monkey::Div(_, _) => match known {
+            KnownOp::R => Op::Divr,
+            KnownOp::L => Op::Divl,
+        },
+        _ => unreachable!(),
+    };
+
+    log::debug!("{root} is {op:?} of the human branch and {known_operand}, and must yell {target}");
+    let new_target = reverse_op(op, target, known_operand);
+    if human_branch.len() == 1 {
+        // only humn left
+        log::debug!("humn must yell {new_target}");
+        new_target
+    } else {
+        reverse_engineer(monkeys, human_branch, new_target)
+    }
+}
+
+fn split_branches(monkeys: &HashMap<String, Monkey>, root: &str) -> (Vec<String>, Vec<String>) {
+    let [left, right] = monkeys[root].operands();
+    let lq = toposort(monkeys, find_starters(monkeys, left), left);
+    let rq = toposort(monkeys, find_starters(monkeys, right), right);
+    (lq, rq)
+}
+
+#[derive(Debug)]
+enum Op {
+    Add,
+    Mul,
+    Divl, // known operand on left
+    Divr, // known operand on right
+    Subl, // known operand on left
+    Subr, // known operand on right
+}
+
+fn reverse_op(op: Op, target: u64, operand: u64) -> u64 {
+    match op {
+        Op::Add => target - operand,  // Add(x, 3) = 10 -> 10 - 3 = 7
+        Op::Mul => target / operand,  // Mul(2, x) = 16 -> 16 / 2 = 8
+        Op::Divl => operand / target, // Divl(12, x) = 3 -> 12 / 3 = 4
+        Op::Divr => target * operand, // Divr(x, 4) = 3  -> 3 * 4 = 12
+        Op::Subl => operand - target, // Subl(5, x) = 2  -> 5 - 2 = 3
+        Op::Subr => target + operand, // Subr(x, 3) = 9  -> 9 + 3 = 12
+    }
+}
+
+fn yell(monkeys: &HashMap<String, Monkey>, queue: Vec<String>) -> u64 {
+    let root = &queue.last().unwrap();
+    let mut numbers: HashMap<&String, u64> = [].into();
+    for name in &queue {
+        let monkey = &monkeys[name];
+        if let Monkey::K(k) = monkey {
+            numbers.insert(name, *k);
+        } else {
+            let [a, b] = monkey.operands();
+            let (a, b) = (numbers[a], numbers[b]);
+            let k = match monkey {
+                Monkey::Add(_, _) => a + b,
+                Monkey::Sub(_, _) => a - b,
+                Monkey::Mul(_, _) => a * b,
+                Monkey::Div(_, _) => a / b,
+                _ => unreachable!(),
+            };
+            numbers.insert(name, k);
+        }
+    }
+    numbers[*root]
+}
+
+fn find_starters(monkeys: &HashMap<String, Monkey>, root: &str) -> HashSet<String> {
+    let mut starters: HashSet<String> = [].into();
+    let mut queue: VecDeque<&str> = [root].into();
+    while !queue.is_empty() {
+        let name = queue.pop_front().unwrap().to_string();
+        let monkey = &monkeys[&name];
+
+        if monkey.is_k() {
+            starters.insert(name);
+            continue;
+        }
+
+        for op in &monkey.operands() {
+            if monkeys[*op].is_k() {
+                starters.insert(op.to_string());
+            } else {
+                queue.push_back(*op);
+            }
+        }
+    }
+    starters
+}
+
+fn toposort(
+    monkeys: &HashMap<String, Monkey>,
+    mut starters: HashSet<String>,
+    root: &str,
+) -> Vec<String> {
+    let mut queue = vec![];
+    loop {
+        let ready = starters.iter().next().cloned().unwrap();
+        starters.remove(&ready);
+        queue.push(ready.clone());
+
+        if ready == root {
+            break;
+        }
+
+        let (name, monkey) = monkeys
+            .iter()
+            .find(|(_, monkey)| monkey.needs(&ready))
+            .unwrap();
+
+        assert!(!monkey.is_k());
+        match monkey {
+            Monkey::Add(a, b) | Monkey::Sub(a, b) | Monkey::Mul(a, b) | Monkey::Div(a, b) => {
+                if queue.contains(a) && queue.contains(b) {
+                    starters.insert(name.to_string());
+                }
+            }
+            Monkey::K(_) => unreachable!(),
+        }
+    }
+    queue
+}
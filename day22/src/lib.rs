@@ -0,0 +1,418 @@
+//! A path to follow on a strange board, first flat and then folded into a cube.
+//!
+//! [`parse()`] reads the [`Notes`]: the [`Map`] of the board and the [`Ins`]tructions of the path.
+//! [`walk`] moves a [`P`] forward on the flat board and [`walk_cube`] on the cube, and
+//! [`password`] is where it ends up. Positions are `(row, column)`, from the top left of the
+//! board.
+
+use aoc_common::{parse, Answer, Dir4, Grid, Line, ParseError, Point2};
+use std::fmt;
+
+/// The board, with the column each row starts at and its length.
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<char>,
+    offsets: Vec<usize>,
+    lengths: Vec<usize>,
+}
+
+impl Map {
+    /// The leftmost open tile of the top row.
+    pub fn start(&self) -> (usize, usize) {
+        let mut x = self.offsets[0];
+        while self.tile((0, x)) == '#' {
+            x += 1;
+        }
+        (0, x)
+    }
+
+    /// The tile at a position: `.` for open, `#` for wall and ` ` off the board.
+    ///
+    /// # Panics
+    ///
+    /// If the position is beyond the longest row or the last one.
+    pub fn tile(&self, (y, x): (usize, usize)) -> char {
+        self.tiles[(x, y)]
+    }
+
+    /// The board as in the input, a row per line, without the spaces after the end of each row.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (y, (offset, len)) in self.offsets.iter().zip(&self.lengths).enumerate() {
+            let row: String = (*offset..offset + len).map(|x| self.tile((y, x))).collect();
+            out += &format!("{:offset$}{row}\n", "");
+        }
+        out
+    }
+
+    /// Whether a position is on the board, the space around it being padded with ' '.
+    pub fn on_board(&self, (y, x): (usize, usize)) -> bool {
+        self.tiles
+            .get(Point2::from((x, y)))
+            .is_some_and(|c| *c != ' ')
+    }
+}
+
+/// The board and the path to follow on it.
+#[derive(Debug)]
+pub struct Notes {
+    map: Map,
+    instructions: Vec<Ins>,
+}
+
+/// Writes the board, an empty line and the path, as `parse` reads them.
+impl fmt::Display for Notes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.map.render())?;
+        for ins in &self.instructions {
+            match ins {
+                Ins::Walk(n) => write!(f, "{n}")?,
+                Ins::L => f.write_str("L")?,
+                Ins::R => f.write_str("R")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Notes {
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+
+    pub fn instructions(&self) -> &[Ins] {
+        &self.instructions
+    }
+}
+
+/// A step of the path: walk some number of tiles, or turn left or right.
+#[derive(Debug, Clone, Copy)]
+pub enum Ins {
+    Walk(usize),
+    L,
+    R,
+}
+
+/// Where you are on the board and which way you are facing.
+#[derive(Debug, PartialEq, Eq)]
+pub struct P {
+    pub pos: (usize, usize),
+    pub dir: Dir4,
+}
+
+impl P {
+    fn south(&self) -> bool {
+        matches!(self.dir, Dir4::S)
+    }
+    fn north(&self) -> bool {
+        matches!(self.dir, Dir4::N)
+    }
+    fn west(&self) -> bool {
+        matches!(self.dir, Dir4::W)
+    }
+    fn east(&self) -> bool {
+        matches!(self.dir, Dir4::E)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Notes, ParseError> {
+    let mut lines = parse::lines(input).collect::<Vec<_>>();
+    // the path is the last line that is not blank
+    while lines.last().is_some_and(|line| line.text.trim().is_empty()) {
+        lines.pop();
+    }
+    let (path, board) = match lines.as_slice() {
+        [board @ .., blank, path] if blank.text.trim().is_empty() => {
+            (Line::new(path.number, path.text.trim_end()), board)
+        }
+        [.., blank, _] => return Err(blank.error(blank.text, "an empty line before the path")),
+        _ => return Err(ParseError::end_of_input(input, "a board and a path")),
+    };
+
+    let mut offsets = vec![];
+    let mut lengths = vec![];
+    for line in board {
+        if let Some(i) = line.text.find(|c| !matches!(c, ' ' | '.' | '#')) {
+            return Err(line.error(&line.text[i..], "a tile ' ', '.' or '#'"));
+        }
+        let x = line.text.len() - line.text.trim_start_matches(' ').len();
+        offsets.push(x);
+        lengths.push(line.text.len() - x);
+    }
+
+    let board = board.iter().map(|line| line.text).collect::<Vec<_>>();
+    let map = Map {
+        tiles: Grid::parse(&board.join("\n"), |c| c),
+        offsets,
+        lengths,
+    };
+
+    // parse path instructions: numbers of steps separated by turns
+    let mut instructions = vec![];
+    let mut start = 0;
+    for (i, c) in path.text.char_indices() {
+        match c {
+            '0'..='9' => {}
+            'L' | 'R' => {
+                let steps = &path.text[start..i];
+                instructions.push(Ins::Walk(path.parse(steps, "a number of steps")?));
+                instructions.push(if c == 'L' { Ins::L } else { Ins::R });
+                start = i + 1;
+            }
+            _ => return Err(path.error(&path.text[i..], "a number of steps, 'L' or 'R'")),
+        }
+    }
+    let steps = &path.text[start..];
+    instructions.push(Ins::Walk(path.parse(steps, "a number of steps")?));
+
+    Ok(Notes { map, instructions })
+}
+
+// the password at the end of the path, moving forward with `walk`
+fn follow(notes: &Notes, walk: fn(&Map, &mut P, usize)) -> usize {
+    let mut p = P {
+        pos: notes.map.start(),
+        dir: Dir4::E,
+    };
+
+    for ins in &notes.instructions {
+        match *ins {
+            Ins::Walk(n) => walk(&notes.map, &mut p, n),
+            side => {
+                p.dir = turn(p.dir, side);
+            }
+        }
+    }
+
+    password(&p)
+}
+
+pub fn part1(notes: &Notes) -> Answer {
+    follow(notes, walk).into()
+}
+
+pub fn part2(notes: &Notes) -> Answer {
+    follow(notes, walk_cube).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let notes = parse(input)?;
+    Ok((part1(&notes), part2(&notes)))
+}
+
+/// The password for ending up at `p`.
+pub fn password(p: &P) -> usize {
+    let (y, x) = p.pos;
+    1000 * (y + 1)
+        + 4 * (x + 1)
+        + match p.dir {
+            Dir4::E => 0,
+            Dir4::S => 1,
+            Dir4::W => 2,
+            Dir4::N => 3,
+        }
+}
+
+//          +--G--+--F--+       A turns W->E
+//         A|     |     |D      B turns N->E, W->S
+//          +-----+--C--+       C turns E->N, S->W
+//         B|     |C            D turns E->W
+//    +--B--+-----+             E turns S->W, E->N
+//   A|     |     |D            F turns S->S, N->N
+//    +-----+--E--+             G turns W->S, N->E
+//   G|     |E
+//    +--F--+
+
+fn next_pos_cube(p: &P) -> P {
+    let (y, x) = p.pos;
+    // A, facing W: x = 50, 0 <= y < 50
+    let (y0, x0, d0) = if p.west() && x == 50 && y < 50 {
+        (150 - (y + 1), 0, Dir4::E)
+    }
+    // A, facing W: x = 0, 100 <= y <= 150
+    else if p.west() && x == 0 && (100..150).contains(&y) {
+        (50 - (y - 100 + 1), 50, Dir4::E)
+    }
+    // B, facing W: x = 50, 50 <= y < 100
+    else if p.west() && x == 50 && (50..100).contains(&y) {
+        (100, y - 50, Dir4::S)
+    }
+    // B, facing N: y = 100, 0 <= x < 50
+    else if p.north() && y == 100 && x < 50 {
+        (50 + x, 50, Dir4::E)
+    }
+    // C, facing S: y = 49, 100 <= x < 150
+    else if p.south() && y == 49 && (100..150).contains(&x) {
+        (x - 50, 99, Dir4::W)
+    }
+    // C, facing E: x = 99, 50 <= y < 100
+    else if p.east() && x == 99 && (50..100).contains(&y) {
+        (49, y + 50, Dir4::N)
+    }
+    // D, facing E: x = 99, 100 <= y < 150
+    else if p.east() && x == 99 && (100..150).contains(&y) {
+        (50 - (y - 100 + 1), 149, Dir4::W)
+    }
+    // D, facing E: x = 149, 0 <= y < 50
+    else if p.east() && x == 149 && y < 50 {
+        (150 - (y + 1), 99, Dir4::W)
+    }
+    // E, facing S: y = 150, 50 <= x < 100
+    else if p.south() && y == 149 && (50..100).contains(&x) {
+        (150 + (x - 50), 49, Dir4::W)
+    }
+    // E, facing E: x = 50, 150 <= y < 200
+    else if p.east() && x == 49 && (150..200).contains(&y) {
+        (149, 50 + (y - 150), Dir4::N)
+    }
+    // F, facing N: y = 0, 100 <= x < 150
+    else if p.north() && y == 0 && (100..150).contains(&x) {
+        (199, x - 100, Dir4::N)
+    }
+    // F, facing S: y = 200, 0 <= x < 50
+    else if p.south() && y == 199 && x < 50 {
+        (0, x + 100, Dir4::S)
+    }
+    // G, facing W: x = 0, 150 <= y < 200
+    else if p.west() && x == 0 && (150..200).contains(&y) {
+        (0, 50 + (y - 150), Dir4::S)
+    }
+    // G, facing N: y = 0, 50 <= x < 150
+    else if p.north() && y == 0 && (50..150).contains(&x) {
+        (150 + (x - 50), 0, Dir4::E)
+    } else {
+        let d = p.dir.offset();
+        ((y as i32 + d.y) as usize, (x as i32 + d.x) as usize, p.dir)
+    };
+
+    P {
+        pos: (y0, x0),
+        dir: d0,
+    }
+}
+
+/// Walks `n` tiles forward on the cube, stopping at a wall. Only the net of the puzzle input
+/// folds: faces 50 tiles wide, laid out as in the drawing above.
+pub fn walk_cube(map: &Map, p: &mut P, n: usize) {
+    for _ in 0..n {
+        let target = next_pos_cube(p);
+        if map.tile(target.pos) == '.' {
+            *p = target;
+        } else {
+            return;
+        }
+    }
+}
+
+/// Walks `n` tiles forward on the flat board, stopping at a wall and wrapping around to the
+/// other side of the board when leaving it.
+pub fn walk(map: &Map, p: &mut P, n: usize) {
+    for _ in 0..n {
+        let target = next_pos(map, p);
+        if map.tile(target) == '.' {
+            p.pos = target;
+        } else {
+            return;
+        }
+    }
+}
+
+fn next_pos(map: &Map, p: &P) -> (usize, usize) {
+    // let (y, x) be the current position
+    let (y, x) = p.pos;
+    // direction maps to one of (1, 0), (-1, 0), (0, 1), (0, -1); let's call that d
+    let d = p.dir.offset();
+    // if y == 0, or (y-1, x) not in the map, and we are facing north, find the lowest row
+    // with offset <= x < offset + lenght
+    if p.north() && (y == 0 || !map.on_board((y - 1, x))) {
+        let mut y0 = map.offsets.len() - 1;
+        while map.offsets[y0] > x || x >= map.offsets[y0] + map.lengths[y0] {
+            y0 -= 1;
+        }
+        (y0, x)
+    }
+    // if (y+1, x) is not in the map, and we are facing south, find the highest row with
+    // offset <= x < offset + length
+    else if p.south() && !map.on_board((y + 1, x)) {
+        let mut y0 = 0;
+        while map.offsets[y0] > x || x >= map.offsets[y0] + map.lengths[y0] {
+            y0 += 1;
+        }
+        (y0, x)
+    }
+    // if x-offset[y] == 0, and we are facing west, wrap around to offset + row_len - 1
+    else if p.west() && x - map.offsets[y] == 0 {
+        (y, map.offsets[y] + map.lengths[y] - 1)
+    }
+    // if x-offset[y] = row_len[y], and we are facing east, wrap around to offset[y]
+    else if p.east() && x - map.offsets[y] == map.lengths[y] - 1 {
+        (y, map.offsets[y])
+    }
+    // otherwise return (y, x) + d
+    else {
+        ((y as i32 + d.y) as usize, (x as i32 + d.x) as usize)
+    }
+}
+
+fn turn(facing: Dir4, side: Ins) -> Dir4 {
+    match side {
+        Ins::L => facing.turn_left(),
+        Ins::R => facing.turn_right(),
+        Ins::Walk(_) => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(y: usize, x: usize, dir: Dir4) -> P {
+        P { pos: (y, x), dir }
+    }
+
+    #[test]
+    fn test_cube_pos() {
+        // A1
+        assert_eq!(next_pos_cube(&p(0, 50, Dir4::W)), p(149, 0, Dir4::E));
+        assert_eq!(next_pos_cube(&p(49, 50, Dir4::W)), p(100, 0, Dir4::E));
+        // A2
+        assert_eq!(next_pos_cube(&p(100, 0, Dir4::W)), p(49, 50, Dir4::E));
+        assert_eq!(next_pos_cube(&p(149, 0, Dir4::W)), p(0, 50, Dir4::E));
+        // B1
+        assert_eq!(next_pos_cube(&p(50, 50, Dir4::W)), p(100, 0, Dir4::S));
+        assert_eq!(next_pos_cube(&p(99, 50, Dir4::W)), p(100, 49, Dir4::S));
+        // B2
+        assert_eq!(next_pos_cube(&p(100, 0, Dir4::N)), p(50, 50, Dir4::E));
+        assert_eq!(next_pos_cube(&p(100, 49, Dir4::N)), p(99, 50, Dir4::E));
+        // C1
+        assert_eq!(next_pos_cube(&p(49, 100, Dir4::S)), p(50, 99, Dir4::W));
+        assert_eq!(next_pos_cube(&p(49, 149, Dir4::S)), p(99, 99, Dir4::W));
+        // C2
+        assert_eq!(next_pos_cube(&p(50, 99, Dir4::E)), p(49, 100, Dir4::N));
+        assert_eq!(next_pos_cube(&p(99, 99, Dir4::E)), p(49, 149, Dir4::N));
+        // D1
+        assert_eq!(next_pos_cube(&p(100, 99, Dir4::E)), p(49, 149, Dir4::W));
+        assert_eq!(next_pos_cube(&p(149, 99, Dir4::E)), p(0, 149, Dir4::W));
+        // D2
+        assert_eq!(next_pos_cube(&p(0, 149, Dir4::E)), p(149, 99, Dir4::W));
+        assert_eq!(next_pos_cube(&p(49, 149, Dir4::E)), p(100, 99, Dir4::W));
+        // E1
+        assert_eq!(next_pos_cube(&p(149, 50, Dir4::S)), p(150, 49, Dir4::W));
+        assert_eq!(next_pos_cube(&p(149, 99, Dir4::S)), p(199, 49, Dir4::W));
+        // E2
+        assert_eq!(next_pos_cube(&p(150, 49, Dir4::E)), p(149, 50, Dir4::N));
+        assert_eq!(next_pos_cube(&p(199, 49, Dir4::E)), p(149, 99, Dir4::N));
+        // F1
+        assert_eq!(next_pos_cube(&p(0, 100, Dir4::N)), p(199, 0, Dir4::N));
+        assert_eq!(next_pos_cube(&p(0, 149, Dir4::N)), p(199, 49, Dir4::N));
+        // F2
+        assert_eq!(next_pos_cube(&p(199, 0, Dir4::S)), p(0, 100, Dir4::S));
+        assert_eq!(next_pos_cube(&p(199, 49, Dir4::S)), p(0, 149, Dir4::S));
+        // G1
+        assert_eq!(next_pos_cube(&p(150, 0, Dir4::W)), p(0, 50, Dir4::S));
+        assert_eq!(next_pos_cube(&p(199, 0, Dir4::W)), p(0, 99, Dir4::S));
+        // G2
+        assert_eq!(next_pos_cube(&p(0, 50, Dir4::N)), p(150, 0, Dir4::E));
+        assert_eq!(next_pos_cube(&p(0, 99, Dir4::N)), p(199, 0, Dir4::E));
+    }
+}
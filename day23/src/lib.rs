@@ -0,0 +1,138 @@
+//! Elves spreading out to plant a grove.
+//!
+//! [`parse()`] numbers the elves and gives their positions, with `y` growing downwards, and
+//! [`round`] moves them, proposing directions in the order kept by [`proposal_order`].
+
+use aoc_common::{parse, Answer, Dir4, Dir8, ParseError, Point2};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// the proposed direction and its two diagonals
+fn adjacent3(pos: Point2, dir: Dir4) -> [Point2; 3] {
+    let d = Dir8::from(dir);
+    [d.turn_left(), d, d.turn_right()].map(|d| pos + d)
+}
+
+/// The position of every elf, keyed by an id.
+pub fn parse(input: &str) -> Result<HashMap<i32, Point2>, ParseError> {
+    let mut elves = HashMap::new();
+    let mut i = 0;
+    for (y, line) in parse::lines(input).enumerate() {
+        for (x, c) in line.text.char_indices() {
+            match c {
+                '#' => {
+                    elves.insert(i, Point2::from((x, y)));
+                    i += 1;
+                }
+                '.' => {}
+                _ => return Err(line.error(&line.text[x..], "an elf '#' or ground '.'")),
+            }
+        }
+    }
+    Ok(elves)
+}
+
+/// Moves every elf that has a neighbor and can propose a move, then rotates the proposals.
+/// Returns whether any elf moved.
+pub fn round(elves: &mut HashMap<i32, Point2>, proposal_order: &mut VecDeque<Dir4>) -> bool {
+    let mut target_positions: HashMap<Point2, Vec<i32>> = [].into();
+    let current_positions = elves.values().collect::<HashSet<_>>();
+    for (id, pos) in elves.iter() {
+        if pos
+            .neighbors8()
+            .iter()
+            .all(|p| !current_positions.contains(p))
+        {
+            continue;
+        }
+
+        for proposed_dir in proposal_order.iter() {
+            let adjacent = adjacent3(*pos, *proposed_dir);
+            if adjacent.iter().all(|p| !current_positions.contains(p)) {
+                let target_dir = *pos + *proposed_dir;
+                target_positions.entry(target_dir).or_default().push(*id);
+                break;
+            }
+        }
+    }
+
+    let mut moves = 0;
+    for (target, ids) in target_positions {
+        if ids.len() > 1 {
+            continue;
+        }
+        elves.insert(ids[0], target);
+        moves += 1;
+    }
+    log::debug!(
+        "{moves} elves moved, proposing {:?} first",
+        proposal_order[0]
+    );
+    log::trace!("elves after the round:\n{}", render(elves));
+
+    proposal_order.rotate_left(1);
+    moves > 0
+}
+
+/// The directions the elves propose in the first round, in order.
+pub fn proposal_order() -> VecDeque<Dir4> {
+    VecDeque::from([Dir4::N, Dir4::S, Dir4::W, Dir4::E])
+}
+
+pub fn part1(elves: &HashMap<i32, Point2>) -> Answer {
+    let mut elves = elves.clone();
+    let mut proposal_order = proposal_order();
+    for _ in 0..10 {
+        round(&mut elves, &mut proposal_order);
+    }
+    (total_area(&elves) - elves.len()).into()
+}
+
+pub fn part2(elves: &HashMap<i32, Point2>) -> Answer {
+    let mut elves = elves.clone();
+    let mut proposal_order = proposal_order();
+    let mut rounds = 1;
+    while round(&mut elves, &mut proposal_order) {
+        rounds += 1;
+    }
+    rounds.into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let elves = parse(input)?;
+    Ok((part1(&elves), part2(&elves)))
+}
+
+fn boundaries(elves: &HashMap<i32, Point2>) -> [i32; 4] {
+    let mut b = [0i32; 4];
+    b[0] = elves.values().map(|p| p.x).min().unwrap();
+    b[1] = elves.values().map(|p| p.y).min().unwrap();
+    b[2] = elves.values().map(|p| p.x).max().unwrap();
+    b[3] = elves.values().map(|p| p.y).max().unwrap();
+    b
+}
+
+fn total_area(elves: &HashMap<i32, Point2>) -> usize {
+    let [minx, miny, maxx, maxy] = boundaries(elves);
+    let (w, h) = ((maxx - minx).abs() + 1, (maxy - miny).abs() + 1);
+    (w * h) as usize
+}
+
+/// The smallest rectangle containing every elf, with `#` for the elves and `.` for the ground.
+pub fn render(elves: &HashMap<i32, Point2>) -> String {
+    let [minx, miny, maxx, maxy] = boundaries(elves);
+    let positions = elves.values().collect::<HashSet<_>>();
+    let mut out = String::new();
+    for y in miny..=maxy {
+        if y > miny {
+            out.push('\n');
+        }
+        for x in minx..=maxx {
+            out.push(if positions.contains(&Point2::new(x, y)) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+    }
+    out
+}
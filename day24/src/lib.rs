@@ -0,0 +1,276 @@
+//! An expedition crossing a valley swept by blizzards.
+//!
+//! [`parse()`] reads the valley into a [`Time`], which holds a [`TimeSlice`] with the position of
+//! every [`Blizzard`] for each minute computed so far, and [`crossings`] finds the fastest way
+//! across. Positions are `(x, y)` inside the walls, with the entrance at `(0, -1)` and the exit
+//! at `(width - 1, height)`.
+
+use aoc_common::{parse, Answer, Dir4, ParseError, Point2};
+use std::collections::HashSet;
+
+/// A blizzard, which moves one tile in its direction every minute.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Blizzard {
+    pos: Point2,
+    dir: Dir4,
+}
+
+impl Blizzard {
+    pub fn pos(&self) -> Point2 {
+        self.pos
+    }
+
+    pub fn dir(&self) -> Dir4 {
+        self.dir
+    }
+}
+
+/// The blizzards in the valley at some minute.
+#[derive(Debug, Clone)]
+pub struct TimeSlice {
+    blizzards: HashSet<Blizzard>,
+}
+
+impl TimeSlice {
+    pub fn blizzards(&self) -> impl Iterator<Item = &Blizzard> {
+        self.blizzards.iter()
+    }
+
+    /// Some blizzard at `(x, y)`, if any.
+    pub fn blizzard_at(&self, x: i32, y: i32) -> Option<&Blizzard> {
+        self.blizzards
+            .iter()
+            .find(|Blizzard { pos, .. }| *pos == Point2::new(x, y))
+    }
+}
+
+/// The valley, with the blizzards at every minute computed so far.
+#[derive(Debug, Clone)]
+pub struct Time {
+    width: i32,
+    height: i32,
+    slices: Vec<TimeSlice>,
+}
+
+impl Time {
+    /// The width of the valley inside the walls.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// The height of the valley inside the walls.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// The blizzards at minute `t`, moving them on as far as needed.
+    pub fn slice(&mut self, t: usize) -> &TimeSlice {
+        while self.slices.len() <= t {
+            self.tick();
+        }
+        &self.slices[t]
+    }
+
+    /// Moves the blizzards on to the minute after the last one computed.
+    pub fn tick(&mut self) {
+        let blizzards = self
+            .slices
+            .last()
+            .unwrap()
+            .blizzards
+            .iter()
+            .map(|b| {
+                let pos = b.pos + b.dir;
+                Blizzard {
+                    // blizzards wrap around the valley
+                    pos: Point2::new(pos.x.rem_euclid(self.width), pos.y.rem_euclid(self.height)),
+                    dir: b.dir,
+                }
+            })
+            .collect::<HashSet<_>>();
+        self.slices.push(TimeSlice { blizzards });
+        let t = self.slices.len() - 1;
+        log::trace!("minute {t}:\n{}", self.render(t));
+    }
+
+    /// The valley with its walls at minute `t`, as drawn in the puzzle.
+    ///
+    /// # Panics
+    ///
+    /// If minute `t` has not been computed yet.
+    pub fn render(&self, t: usize) -> String {
+        assert!(t < self.slices.len());
+        let slice = &self.slices[t];
+        let wall = "#".repeat(self.width as usize);
+        let mut out = format!("#.{wall}\n");
+        for y in 0..self.height {
+            out.push('#');
+            for x in 0..self.width {
+                out.push(match slice.blizzard_at(x, y) {
+                    Some(Blizzard { dir, .. }) => match dir {
+                        Dir4::N => '^',
+                        Dir4::S => 'v',
+                        Dir4::W => '<',
+                        Dir4::E => '>',
+                    },
+                    None => '.',
+                });
+            }
+            out += "#\n";
+        }
+        out + &wall + ".#"
+    }
+}
+
+pub fn parse(input: &str) -> Result<Time, ParseError> {
+    let lines = parse::lines(input).collect::<Vec<_>>();
+    // the walls at the top and bottom only matter for their gaps, which are always in the corners
+    let rows = match lines.as_slice() {
+        [_, rows @ .., _] if !rows.is_empty() => rows,
+        _ => {
+            return Err(ParseError::end_of_input(
+                input,
+                "a valley between two walls",
+            ))
+        }
+    };
+
+    let len = rows[0].text.len();
+    if len < 3 {
+        return Err(rows[0].error(rows[0].end(), "a valley at least one tile wide"));
+    }
+    let width = (len - 2) as i32;
+    let height = rows.len() as i32;
+
+    let mut blizzards = HashSet::new();
+    for (y, row) in rows.iter().enumerate() {
+        if row.text.len() != len {
+            return Err(row.error(row.end(), format!("a row of {len} tiles")));
+        }
+        for (i, c) in row.text.char_indices() {
+            if i == 0 || i == len - 1 {
+                if c != '#' {
+                    return Err(row.error(&row.text[i..], "a wall '#'"));
+                }
+                continue;
+            }
+            let bliz = Blizzard {
+                pos: Point2::from((i - 1, y)),
+                dir: match c {
+                    '>' => Dir4::E,
+                    '^' => Dir4::N,
+                    '<' => Dir4::W,
+                    'v' => Dir4::S,
+                    '.' => continue,
+                    _ => return Err(row.error(&row.text[i..], "a blizzard '<>^v' or ground '.'")),
+                },
+            };
+            blizzards.insert(bliz);
+        }
+    }
+
+    Ok(Time {
+        width,
+        height,
+        slices: vec![TimeSlice { blizzards }],
+    })
+}
+
+/// The minute the expedition reaches the goal, leaving the entrance at minute 0 and going back
+/// and forth `trips` times.
+pub fn crossings(time: &Time, trips: usize) -> usize {
+    let mut time = time.clone();
+    let (width, height) = (time.width, time.height);
+    let mut seen = HashSet::new();
+
+    let mut do_it = |start: (i32, i32), target: (i32, i32), t: usize| -> usize {
+        seen.clear();
+        dfs(&mut time, start, start, target, t, &mut seen)
+    };
+
+    let mut t = 0;
+    for trip in 0..trips {
+        t = if trip % 2 == 0 {
+            do_it((0, -1), (width - 1, height - 1), t)
+        } else {
+            do_it((width - 1, height), (0, 0), t)
+        };
+    }
+    t
+}
+
+pub fn part1(time: &Time) -> Answer {
+    crossings(time, 1).into()
+}
+
+pub fn part2(time: &Time) -> Answer {
+    crossings(time, 3).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let time = parse(input)?;
+    Ok((part1(&time), part2(&time)))
+}
+
+fn dfs(
+    time: &mut Time,
+    start: (i32, i32),
+    pos: (i32, i32),
+    target: (i32, i32),
+    t: usize,
+    seen: &mut HashSet<(i32, i32, usize)>,
+) -> usize {
+    let (x, y) = pos;
+    assert!(time.slices[t].blizzard_at(x, y).is_none());
+    seen.insert((x, y, t));
+
+    // hard stop at t = 2000 not to blow the stack
+    if t == 2000 {
+        return usize::MAX;
+    }
+
+    if pos == target {
+        return t + 1;
+    }
+
+    if t == time.slices.len() - 1 {
+        time.tick();
+    }
+
+    // dfs on each available neighbor at t + 1
+    let next_slice = &time.slices[t + 1];
+    let neighbors = if pos == start {
+        let (x0, y0) = match start {
+            (0, -1) => (0, 0),
+            _ => (time.width - 1, time.height - 1),
+        };
+        match next_slice.blizzard_at(x0, y0) {
+            None => vec![(x0, y0), start],
+            Some(_) => vec![start],
+        }
+    } else {
+        [(-1, 0), (0, -1), (0, 1), (1, 0), (0, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let (x0, y0) = (x + dx, y + dy);
+                if x0 < 0
+                    || x0 >= time.width
+                    || y0 < 0
+                    || y0 >= time.height
+                    || seen.contains(&(x0, y0, t + 1))
+                    || next_slice.blizzard_at(x0, y0).is_some()
+                {
+                    None
+                } else {
+                    Some((x0, y0))
+                }
+            })
+            .collect()
+    };
+
+    neighbors
+        .into_iter()
+        .map(|(x0, y0)| dfs(time, start, (x0, y0), target, t + 1, seen))
+        .min()
+        .unwrap_or(usize::MAX)
+}
@@ -0,0 +1,124 @@
+//! Fuel requirements written in SNAFU, balanced base five with the digits `=-012`.
+//!
+//! [`parse()`] reads the numbers, and [`add_snafu`] adds two of them without leaving SNAFU.
+
+use aoc_common::{parse, Answer, ParseError};
+use itertools::EitherOrBoth::{Both, Left, Right};
+use itertools::Itertools;
+
+fn digitize(s: &str) -> Vec<i32> {
+    s.chars()
+        .rev()
+        .map(|c| match c {
+            '=' => -2,
+            '-' => -1,
+            d => d.to_digit(10).unwrap().try_into().unwrap(),
+        })
+        .collect()
+}
+
+fn snafu(ds: &[i32]) -> String {
+    ds.iter()
+        .map(|d| match d {
+            -2 => '=',
+            -1 => '-',
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// The sum of two SNAFU numbers, in SNAFU.
+pub fn add_snafu(r: &str, s: &str) -> String {
+    let mut carry = 0;
+    let mut result = vec![];
+    for it in digitize(r).iter().zip_longest(digitize(s).iter()) {
+        let sum = match it {
+            Both(a, b) => a + b + carry,
+            Left(a) | Right(a) => a + carry,
+        };
+        let (q, r) = (sum.div_euclid(5), sum.rem_euclid(5));
+        let (q, r) = if r > 2 { (q + 1, r - 5) } else { (q, r) };
+        result.push(r);
+        carry = q;
+    }
+    if carry != 0 {
+        result.push(carry);
+    }
+    result[..].reverse();
+    snafu(&result)
+}
+
+/// The fuel requirements, in SNAFU.
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    for line in parse::lines(input) {
+        if let Some(i) = line.text.find(|c| !matches!(c, '=' | '-' | '0'..='2')) {
+            return Err(line.error(&line.text[i..], "a SNAFU digit '=', '-', 0, 1 or 2"));
+        }
+        if line.text.is_empty() {
+            return Err(line.error(line.end(), "a SNAFU number"));
+        }
+    }
+    if input.lines().next().is_none() {
+        return Err(ParseError::end_of_input(input, "a SNAFU number"));
+    }
+    Ok(input.lines().collect())
+}
+
+pub fn part1(numbers: &[&str]) -> Answer {
+    numbers
+        .iter()
+        .map(|n| n.to_string())
+        .reduce(|acc, e| add_snafu(&acc, &e))
+        .map_or(Answer::Missing, Answer::from)
+}
+
+// day 25 has no second puzzle
+pub fn part2(_: &[&str]) -> Answer {
+    Answer::Missing
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let numbers = parse(input)?;
+    Ok((part1(&numbers), part2(&numbers)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snafu_to_i32(value: &str) -> i32 {
+        value
+            .chars()
+            .rev()
+            .enumerate()
+            .map(|(i, d)| {
+                num::pow(5, i)
+                    * match d {
+                        '=' => -2,
+                        '-' => -1,
+                        c => c.to_digit(10).unwrap().try_into().unwrap(),
+                    }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_snafu() {
+        let s = "2=-01";
+        let mut n = digitize(s);
+        n[..].reverse();
+        let t = snafu(&n);
+        assert_eq!(s, t);
+    }
+
+    #[test]
+    fn test_divrem() {
+        let t = add_snafu("1=-0-2", "2=-01");
+        assert_eq!(snafu_to_i32(&t), 976 + 1747);
+        // the sum can be a digit longer than either number
+        assert_eq!(add_snafu("2", "2"), "1-");
+    }
+}
@@ -0,0 +1,302 @@
+//! Items packed in the wrong compartment of a rucksack, and the badges of groups of elves.
+//!
+//! [`parse()`] reads the contents of each rucksack into a [`Rucksack`], a set of items per
+//! compartment held as a bit mask, so that the items two sets share are a bitwise AND.
+//! [`common_items`] finds the item shared by the compartments of each rucksack, or by the
+//! rucksacks of each group of elves, of any size (`--group-size` on the command line, see
+//! [`Options`]). A rucksack or group sharing no item or several is reported as a
+//! [`Diagnostic`].
+
+use aoc_common::options::{self, Mode};
+use aoc_common::{parse, Answer, ParseError};
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The size of the groups of elves in the puzzle.
+pub const GROUP_SIZE: usize = 3;
+
+/// A set of items, with the bit of each item's priority set: bit 1 for `a` up to bit 52 for `Z`.
+pub type Items = u64;
+
+fn priority(c: char) -> u32 {
+    ITEMS.find(c).unwrap() as u32 + 1
+}
+
+// the items of `text`, all of which are in `ITEMS`
+fn items(text: &str) -> Items {
+    text.chars().fold(0, |items, c| items | 1 << priority(c))
+}
+
+// the item of a priority
+fn item(priority: u32) -> char {
+    ITEMS.as_bytes()[priority as usize - 1] as char
+}
+
+// the priorities of `items`, lowest first
+fn priorities(mut items: Items) -> impl Iterator<Item = u32> {
+    std::iter::from_fn(move || {
+        let priority = items.trailing_zeros();
+        (items != 0).then(|| {
+            items &= items - 1;
+            priority
+        })
+    })
+}
+
+/// A rucksack, by the items of each compartment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    /// The line listing its items, counting from 1.
+    pub line: usize,
+    pub compartments: [Items; 2],
+}
+
+impl Rucksack {
+    /// The items in either compartment.
+    pub fn items(&self) -> Items {
+        self.compartments[0] | self.compartments[1]
+    }
+}
+
+/// The rucksacks, each with its items split evenly between its compartments.
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse::lines(input)
+        .map(|line| match line.text.find(|c| !ITEMS.contains(c)) {
+            Some(i) => Err(line.error(&line.text[i..], "an item a-z or A-Z")),
+            // both compartments hold the same number of items
+            None if line.text.len() % 2 != 0 => {
+                Err(line.error(line.end(), "an even number of items"))
+            }
+            None => {
+                let (left, right) = line.text.split_at(line.text.len() / 2);
+                Ok(Rucksack {
+                    line: line.number,
+                    compartments: [items(left), items(right)],
+                })
+            }
+        })
+        .collect()
+}
+
+/// What is looked for in common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Split {
+    /// The item in both compartments of each rucksack.
+    Compartments,
+    /// The item in every rucksack of each group of this many elves, listed one after the other.
+    Groups(usize),
+}
+
+/// A rucksack or group that does not share exactly one item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub split: Split,
+    /// The lines listing the rucksacks involved.
+    pub lines: RangeInclusive<usize>,
+    /// The number of rucksacks involved, fewer than the size of a group for the last one if the
+    /// rucksacks do not split evenly into groups.
+    pub rucksacks: usize,
+    /// The items shared.
+    pub common: Items,
+}
+
+/// e.g. `lines 4-6 share no item` or `line 3: the compartments share 2 items, a and B`.
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (start, end) = (self.lines.start(), self.lines.end());
+        match self.split {
+            Split::Compartments => write!(f, "line {start}: the compartments share")?,
+            Split::Groups(size) if self.rucksacks < size => {
+                return write!(f, "lines {start}-{end} are a group of fewer than {size}")
+            }
+            Split::Groups(_) if start == end => write!(f, "line {start} shares")?,
+            Split::Groups(_) => write!(f, "lines {start}-{end} share")?,
+        }
+        let common: Vec<_> = priorities(self.common).map(item).collect();
+        match common.as_slice() {
+            [] => write!(f, " no item"),
+            [rest @ .., last] => {
+                let rest: Vec<_> = rest.iter().map(char::to_string).collect();
+                write!(f, " {} items, {} and {last}", common.len(), rest.join(", "))
+            }
+        }
+    }
+}
+
+/// The priority of the item shared by each rucksack or group, as split. Fails with a diagnostic
+/// for each one sharing no item or several, and for a last group with too few rucksacks.
+pub fn common_items(rucksacks: &[Rucksack], split: Split) -> Result<Vec<u32>, Vec<Diagnostic>> {
+    // the lines, number of rucksacks and items in common of each set of rucksacks
+    let sets: Vec<(RangeInclusive<usize>, usize, Items)> = match split {
+        Split::Compartments => rucksacks
+            .iter()
+            .map(|r| (r.line..=r.line, 1, r.compartments[0] & r.compartments[1]))
+            .collect(),
+        Split::Groups(size) => rucksacks
+            .chunks(size.max(1))
+            .map(|group| {
+                let lines = group[0].line..=group[group.len() - 1].line;
+                let common = group.iter().fold(!0, |common, r| common & r.items());
+                (lines, group.len(), common)
+            })
+            .collect(),
+    };
+
+    let mut found = vec![];
+    let mut diagnostics = vec![];
+    for (lines, count, common) in sets {
+        let short = matches!(split, Split::Groups(size) if count < size);
+        if !short && common.count_ones() == 1 {
+            found.push(common.trailing_zeros());
+        } else {
+            diagnostics.push(Diagnostic {
+                split,
+                lines,
+                rucksacks: count,
+                common,
+            });
+        }
+    }
+    if diagnostics.is_empty() {
+        Ok(found)
+    } else {
+        Err(diagnostics)
+    }
+}
+
+// The sum of the priorities shared, or no answer with the diagnostics logged.
+fn sum(rucksacks: &[Rucksack], split: Split) -> Answer {
+    match common_items(rucksacks, split) {
+        Ok(priorities) => priorities.iter().sum::<u32>().into(),
+        Err(diagnostics) => {
+            for diagnostic in diagnostics {
+                log::warn!("{diagnostic}");
+            }
+            Answer::Missing
+        }
+    }
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> Answer {
+    sum(rucksacks, Split::Compartments)
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Answer {
+    sum(rucksacks, Split::Groups(GROUP_SIZE))
+}
+
+/// [`part2`], with groups of `group_size` elves.
+pub fn part2_with(rucksacks: &[Rucksack], group_size: usize) -> Answer {
+    sum(rucksacks, Split::Groups(group_size))
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    solve_with(input, GROUP_SIZE)
+}
+
+/// [`solve`], with groups of `group_size` elves in part 2.
+pub fn solve_with(input: &str, group_size: usize) -> Result<(Answer, Answer), ParseError> {
+    let rucksacks = parse(input)?;
+    Ok((part1(&rucksacks), part2_with(&rucksacks, group_size)))
+}
+
+/// The options of day 3 on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// `--group-size <n>`, [`GROUP_SIZE`] without it.
+    pub group_size: NonZeroUsize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            group_size: NonZeroUsize::new(GROUP_SIZE).unwrap(),
+        }
+    }
+}
+
+impl Options {
+    /// Takes the options out of the command line arguments.
+    pub fn extract(args: &mut Vec<String>) -> Result<Options, String> {
+        let group_size = options::extract(args, "--group-size", "a positive number")?;
+        Ok(group_size.map_or_else(Options::default, |group_size| Options { group_size }))
+    }
+}
+
+/// Takes the options out of the command line arguments, and returns the solver they ask for.
+pub fn mode(args: &mut Vec<String>) -> Result<Mode, String> {
+    let group_size = Options::extract(args)?.group_size.get();
+    Ok(Mode::Solve(Box::new(move |input| {
+        solve_with(input, group_size)
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn test_items() {
+        assert_eq!(items("aZ"), 1 << 1 | 1 << 52);
+        assert_eq!(priorities(items("Zab")).collect::<Vec<_>>(), [1, 2, 52]);
+        assert_eq!(item(52), 'Z');
+    }
+
+    #[test]
+    fn test_common_items() {
+        let rucksacks = parse(EXAMPLE).unwrap();
+        assert_eq!(
+            common_items(&rucksacks, Split::Compartments),
+            Ok(vec![16, 38, 42, 22, 20, 19])
+        );
+        assert_eq!(solve(EXAMPLE), Ok((Answer::Int(157), Answer::Int(70))));
+
+        // a group of six shares no item, and a group of four leaves two rucksacks
+        let Err(diagnostics) = common_items(&rucksacks, Split::Groups(6)) else {
+            panic!("the rucksacks share no item");
+        };
+        assert_eq!(diagnostics[0].to_string(), "lines 1-6 share no item");
+        let Err(diagnostics) = common_items(&rucksacks, Split::Groups(4)) else {
+            panic!("the last group is short");
+        };
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "lines 5-6 are a group of fewer than 4"
+        );
+
+        let rucksacks = parse("abab\n").unwrap();
+        let Err(diagnostics) = common_items(&rucksacks, Split::Compartments) else {
+            panic!("the compartments share two items");
+        };
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "line 1: the compartments share 2 items, a and b"
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let mut args = vec!["--group-size".to_string(), "4".to_string()];
+        assert_eq!(Options::extract(&mut args).unwrap().group_size.get(), 4);
+        assert!(args.is_empty());
+        assert_eq!(
+            Options::extract(&mut args).unwrap().group_size.get(),
+            GROUP_SIZE
+        );
+        let mut args = vec!["--group-size".to_string(), "0".to_string()];
+        assert!(Options::extract(&mut args).is_err());
+    }
+}
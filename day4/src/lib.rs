@@ -0,0 +1,52 @@
+//! Pairs of elves cleaning up overlapping ranges of sections.
+//!
+//! [`parse()`] reads each pair as two inclusive ranges of section numbers, `[first, last]`.
+
+use aoc_common::{parse, Answer, Line, ParseError};
+
+fn contains(a: &[u32], b: &[u32]) -> bool {
+    a[0] <= b[0] && b[1] <= a[1] || b[0] <= a[0] && a[1] <= b[1]
+}
+
+fn overlaps(a: &[u32], b: &[u32]) -> bool {
+    let between = |x, a, b| a <= x && x <= b;
+    between(a[0], b[0], b[1])
+        || between(a[1], b[0], b[1])
+        || between(b[0], a[0], a[1])
+        || between(b[1], a[0], a[1])
+}
+
+// "2-4,6-8"
+fn parse_pair(line: &Line) -> Result<[[u32; 2]; 2], ParseError> {
+    let (a, b) = line.require(line.text.split_once(','), "',' between the two ranges")?;
+    let range = |r: &str| -> Result<[u32; 2], ParseError> {
+        let (lo, hi) = r
+            .split_once('-')
+            .ok_or_else(|| line.error(r, "a range like '2-4'"))?;
+        Ok([
+            line.parse(lo, "a section number")?,
+            line.parse(hi, "a section number")?,
+        ])
+    };
+    Ok([range(a)?, range(b)?])
+}
+
+/// The two section ranges of each pair of elves, as inclusive `[lo, hi]`.
+pub fn parse(input: &str) -> Result<Vec<[[u32; 2]; 2]>, ParseError> {
+    parse::lines(input).map(|line| parse_pair(&line)).collect()
+}
+
+pub fn part1(pairs: &[[[u32; 2]; 2]]) -> Answer {
+    let contained = pairs.iter().filter(|p| contains(&p[0], &p[1])).count();
+    contained.into()
+}
+
+pub fn part2(pairs: &[[[u32; 2]; 2]]) -> Answer {
+    let overlapping = pairs.iter().filter(|p| overlaps(&p[0], &p[1])).count();
+    overlapping.into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let pairs = parse(input)?;
+    Ok((part1(&pairs), part2(&pairs)))
+}
@@ -0,0 +1,161 @@
+//! Crates rearranged between stacks by a crane, one at a time or several at once.
+//!
+//! [`parse()`] reads the [`Supplies`], and [`exec`] and [`exec2`] carry out a step of the procedure
+//! with each crane. Each stack is a string of crate letters, bottom crate first.
+
+use aoc_common::{parse, Answer, Line, ParseError};
+
+/// A step of the procedure: move a number of crates from one stack to another, the stacks
+/// counted from 1.
+#[derive(Debug)]
+pub struct Op(u32, usize, usize);
+
+/// The stacks in the drawing, bottom crate first, and the rearrangement procedure.
+#[derive(Debug)]
+pub struct Supplies {
+    stacks: Vec<String>,
+    ops: Vec<Op>,
+}
+
+impl Supplies {
+    pub fn stacks(&self) -> &[String] {
+        &self.stacks
+    }
+
+    pub fn ops(&self) -> &[Op] {
+        &self.ops
+    }
+}
+
+impl Op {
+    /// The number of crates to move.
+    pub fn count(&self) -> u32 {
+        self.0
+    }
+
+    /// The stack the crates are taken from.
+    pub fn source(&self) -> usize {
+        self.1
+    }
+
+    /// The stack the crates are put on.
+    pub fn target(&self) -> usize {
+        self.2
+    }
+
+    // "move 1 from 2 to 1"
+    fn parse(line: &Line, stacks: usize) -> Result<Self, ParseError> {
+        let mut words = line.text.split(' ');
+        let mut field = |keyword: &str| {
+            let expected = format!("'{keyword}'");
+            let word = line.require(words.next(), &expected)?;
+            if word != keyword {
+                return Err(line.error(word, expected));
+            }
+            line.require(words.next(), "a number")
+        };
+        let (n, from, to) = (field("move")?, field("from")?, field("to")?);
+        if let Some(word) = words.next() {
+            return Err(line.error(word, "end of line"));
+        }
+
+        let stack = |s| match line.parse(s, "a stack number")? {
+            i if (1..=stacks).contains(&i) => Ok(i),
+            _ => Err(line.error(s, format!("a stack number between 1 and {stacks}"))),
+        };
+        Ok(Op(
+            line.parse(n, "a number of crates")?,
+            stack(from)?,
+            stack(to)?,
+        ))
+    }
+}
+
+/// Carries out `op` with the CrateMover 9000, one crate at a time.
+pub fn exec(stacks: &mut [String], op: &Op) {
+    for _ in 0..op.0 {
+        let c = stacks[op.1 - 1].pop().unwrap();
+        stacks[op.2 - 1].push(c);
+    }
+}
+
+/// Carries out `op` with the CrateMover 9001, all crates at once.
+pub fn exec2(stacks: &mut [String], op: &Op) {
+    let from = &mut stacks[op.1 - 1];
+    let crates = from.split_off(from.len() - op.0 as usize);
+    stacks[op.2 - 1].push_str(&crates);
+}
+
+// each column of the drawing, read bottom-up, is a stack; the numbers under the drawing say how
+// many there are
+fn stacks(rows: &[String], count: usize) -> Vec<String> {
+    let mut stacks = vec![String::new(); count];
+    for row in rows.iter().rev() {
+        for (stack, c) in stacks.iter_mut().zip(row.chars()) {
+            if c != ' ' {
+                stack.push(c);
+            }
+        }
+    }
+    stacks
+}
+
+pub fn parse(input: &str) -> Result<Supplies, ParseError> {
+    let mut lines = parse::lines(input);
+
+    // parse stacks initial state
+    let mut rows = vec![];
+    loop {
+        let row = match lines.next() {
+            Some(line) if line.text.is_empty() => break,
+            Some(line) => line.text,
+            None => {
+                return Err(ParseError::end_of_input(
+                    input,
+                    "an empty line after the drawing",
+                ))
+            }
+        };
+        // [D] [W] [W] [F] [T] [H] [Z] [W] [R]
+        //  1   5   9  13  17  21  25  29  33
+        rows.push(row.chars().skip(1).step_by(4).collect::<String>());
+    }
+    let count = rows.pop().map_or(0, |numbers| numbers.len());
+    let stacks = stacks(&rows, count);
+    let ops = lines
+        .map(|line| Op::parse(&line, stacks.len()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Supplies { stacks, ops })
+}
+
+pub fn part1(supplies: &Supplies) -> Answer {
+    let mut stacks = supplies.stacks.clone();
+    for op in &supplies.ops {
+        exec(&mut stacks, op);
+    }
+    top_crates(&stacks).into()
+}
+
+pub fn part2(supplies: &Supplies) -> Answer {
+    let mut stacks = supplies.stacks.clone();
+    for op in &supplies.ops {
+        exec2(&mut stacks, op);
+    }
+    top_crates(&stacks).into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let supplies = parse(input)?;
+    Ok((part1(&supplies), part2(&supplies)))
+}
+
+fn top_crates(stacks: &[String]) -> String {
+    stacks
+        .iter()
+        .map(|s| match s {
+            _ if s.is_empty() => ' ',
+            _ => s.chars().nth(s.len() - 1).unwrap(),
+        })
+        .collect()
+}
@@ -0,0 +1,43 @@
+//! Finding the start of a packet and of a message in the stream of a device.
+//!
+//! [`parse()`] checks that the stream is ASCII.
+
+use aoc_common::{Answer, Line, ParseError};
+use ascii::{AsciiChar, AsciiStr};
+use std::collections::HashSet;
+
+// the number of characters read when the last `len` of them are all different
+fn marker(stream: &AsciiStr, len: usize) -> Answer {
+    for (i, w) in stream.as_slice().windows(len).enumerate() {
+        let set: HashSet<&AsciiChar> = HashSet::from_iter(w.iter());
+        if set.len() == len {
+            return (i + len).into();
+        }
+    }
+    Answer::Missing
+}
+
+/// The first line of the input, which holds the whole datastream.
+pub fn parse(input: &str) -> Result<&AsciiStr, ParseError> {
+    let text = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a datastream"))?;
+    AsciiStr::from_ascii(text).map_err(|e| {
+        let line = Line::new(1, text);
+        line.error(&text[e.valid_up_to()..], "an ASCII character")
+    })
+}
+
+pub fn part1(stream: &AsciiStr) -> Answer {
+    marker(stream, 4)
+}
+
+pub fn part2(stream: &AsciiStr) -> Answer {
+    marker(stream, 14)
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let stream = parse(input)?;
+    Ok((part1(stream), part2(stream)))
+}
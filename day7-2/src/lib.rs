@@ -0,0 +1,94 @@
+//! The directories of a device, like `day7`, but keyed by their full path.
+//!
+//! [`parse()`] replays the terminal session into the total size of every directory, including
+//! those below it, keyed by paths like `/a/e`.
+
+use aoc_common::{parse, Answer, ParseError};
+use std::collections::HashMap;
+
+const LIMIT: u32 = 100000;
+const NEEDED: u32 = 70000000 - 30000000;
+
+fn make_path(components: &[String]) -> String {
+    components.join("/")
+}
+
+struct Prefixes {
+    i: usize,
+    components: Vec<String>,
+}
+
+impl Iterator for Prefixes {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i <= self.components.len() {
+            let path = make_path(&self.components[..self.i]);
+            self.i += 1;
+            return Some(path);
+        }
+        None
+    }
+}
+
+fn prefixes(components: &[String]) -> Prefixes {
+    Prefixes {
+        i: 0,
+        components: components.to_vec(),
+    }
+}
+
+/// The total size of every directory, keyed by its path without the leading `/`.
+pub fn parse(input: &str) -> Result<HashMap<String, u32>, ParseError> {
+    let mut sizes: HashMap<String, u32> = HashMap::new();
+    let mut cwd: Vec<String> = vec![];
+
+    for line in parse::lines(input) {
+        let tokens = line.text.split(' ').collect::<Vec<_>>();
+        match tokens[0] {
+            "$" => match tokens.get(1).copied() {
+                Some("cd") => match line.require(tokens.get(2).copied(), "a directory name")? {
+                    "/" => {}
+                    ".." => {
+                        cwd.pop();
+                    }
+                    _ => {
+                        cwd.push(tokens[2].to_string());
+                    }
+                },
+                Some("ls") => {}
+                cmd => return Err(line.error(cmd.unwrap_or(line.end()), "command 'cd' or 'ls'")),
+            },
+            "dir" => {}
+            size => {
+                let size = line.parse::<u32>(size, "a file size or 'dir'")?;
+                for prefix in prefixes(&cwd) {
+                    *sizes.entry(prefix).or_default() += size;
+                }
+            }
+        }
+    }
+
+    Ok(sizes)
+}
+
+pub fn part1(sizes: &HashMap<String, u32>) -> Answer {
+    let total: u32 = sizes.values().filter(|sz| **sz <= LIMIT).sum();
+    total.into()
+}
+
+pub fn part2(sizes: &HashMap<String, u32>) -> Answer {
+    let used = *sizes.get("").unwrap();
+    let mut sizes: Vec<u32> = sizes
+        .values()
+        .copied()
+        .filter(|sz| *sz >= used.saturating_sub(NEEDED))
+        .collect();
+    sizes.sort();
+    sizes[0].into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let sizes = parse(input)?;
+    Ok((part1(&sizes), part2(&sizes)))
+}
@@ -0,0 +1,108 @@
+//! The directories of a device, found by browsing it in a terminal, to free up space.
+//!
+//! [`parse()`] replays the terminal session into the tree of [`Dir`]s, each with the size of its
+//! files.
+
+use aoc_common::{parse, Answer, ParseError};
+
+const LIMIT: u32 = 100000;
+const DISK: u32 = 70000000;
+const UNUSED: u32 = 30000000;
+
+/// A directory with the total size of the files directly in it.
+#[derive(Debug)]
+pub struct Dir {
+    size: u32,
+    subd: Vec<Dir>,
+}
+
+impl Dir {
+    fn new() -> Self {
+        Dir {
+            size: 0,
+            subd: vec![],
+        }
+    }
+
+    /// The total size of the files directly in the directory.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn subdirs(&self) -> &[Dir] {
+        &self.subd
+    }
+}
+
+/// The root directory of the filesystem explored by the terminal session.
+pub fn parse(input: &str) -> Result<Dir, ParseError> {
+    let mut stack: Vec<Dir> = vec![];
+    let mut current = Dir::new();
+
+    for line in parse::lines(input) {
+        let mut tokens = line.text.split(' ');
+        match tokens.next().unwrap_or_default() {
+            "$" => match tokens.next() {
+                Some("cd") => match line.require(tokens.next(), "a directory name")? {
+                    "/" => {}
+                    dir @ ".." => {
+                        let mut parent = stack
+                            .pop()
+                            .ok_or_else(|| line.error(dir, "a directory to leave"))?;
+                        parent.subd.push(current);
+                        current = parent;
+                    }
+                    _ => {
+                        stack.push(current);
+                        current = Dir::new();
+                    }
+                },
+                Some("ls") => {}
+                cmd => return Err(line.error(cmd.unwrap_or(line.end()), "command 'cd' or 'ls'")),
+            },
+            "dir" => {}
+            size => {
+                current.size += line.parse::<u32>(size, "a file size or 'dir'")?;
+            }
+        }
+    }
+
+    while let Some(mut parent) = stack.pop() {
+        parent.subd.push(current);
+        current = parent;
+    }
+    Ok(current)
+}
+
+pub fn part1(root: &Dir) -> Answer {
+    let (_, sizes) = dive(root, |sz| sz <= LIMIT);
+    let total: u32 = sizes.iter().sum();
+    total.into()
+}
+
+pub fn part2(root: &Dir) -> Answer {
+    let (used, _) = dive(root, |_| false);
+    let needed = used.saturating_sub(DISK - UNUSED);
+    let (_, mut sizes) = dive(root, |sz| sz >= needed);
+    sizes.sort();
+    sizes[0].into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let root = parse(input)?;
+    Ok((part1(&root), part2(&root)))
+}
+
+fn dive(dir: &Dir, pred: impl Fn(u32) -> bool + Copy) -> (u32, Vec<u32>) {
+    let mut total = dir.size;
+    let mut sizes = vec![];
+    for subd in &dir.subd {
+        let (subtotal, subsizes) = dive(subd, pred);
+        total += subtotal;
+        sizes.extend(subsizes.into_iter().filter(|s| pred(*s)));
+    }
+    if pred(total) {
+        sizes.push(total);
+    }
+    (total, sizes)
+}
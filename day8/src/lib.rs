@@ -0,0 +1,58 @@
+//! Trees visible from outside a grid of them, and the view from each.
+//!
+//! [`parse()`] reads the height of every tree.
+
+use aoc_common::{Answer, Grid, ParseError};
+use take_until::TakeUntilExt;
+
+/// The height of every tree in the patch.
+pub fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::try_parse(input, "a tree height 0-9", |c| c.to_digit(10))
+}
+
+pub fn part1(map: &Grid<u32>) -> Answer {
+    let width = map.width();
+    let height = map.height();
+    let guaranteed = 2 * (width + height) as u32 - 4;
+
+    let mut total = 0u32;
+    for x in 1..width - 1 {
+        for y in 1..height - 1 {
+            let tree = map[(x, y)];
+            if (0..x).all(|i| map[(i, y)] < tree)
+                || (x + 1..width).all(|i| map[(i, y)] < tree)
+                || (0..y).all(|i| map[(x, i)] < tree)
+                || (y + 1..height).all(|i| map[(x, i)] < tree)
+            {
+                total += 1;
+            }
+        }
+    }
+
+    (total + guaranteed).into()
+}
+
+pub fn part2(map: &Grid<u32>) -> Answer {
+    let width = map.width();
+    let height = map.height();
+
+    let mut best = 0u32;
+    for x in 1..width - 1 {
+        for y in 1..height - 1 {
+            let tree = map[(x, y)];
+            let left = (0..x).rev().take_until(|i| map[(*i, y)] >= tree).count() as u32;
+            let right = (x + 1..width).take_until(|i| map[(*i, y)] >= tree).count() as u32;
+            let top = (0..y).rev().take_until(|i| map[(x, *i)] >= tree).count() as u32;
+            let bottom = (y + 1..height).take_until(|i| map[(x, *i)] >= tree).count() as u32;
+
+            best = best.max(left * right * top * bottom);
+        }
+    }
+
+    best.into()
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let map = parse(input)?;
+    Ok((part1(&map), part2(&map)))
+}
@@ -0,0 +1,70 @@
+//! A rope whose knots follow its head around a grid.
+//!
+//! [`parse()`] reads the moves of the head, each a direction and a number of steps, and
+//! [`pull_rope`] moves the head one step and the knots after it.
+
+use aoc_common::{parse, Answer, Dir4, Line, ParseError, Point2};
+use std::collections::HashSet;
+
+type P = Point2;
+
+fn parse_move(cmd: &Line) -> Result<(Dir4, i32), ParseError> {
+    let (d, n) = cmd.require(
+        cmd.text.split_once(' '),
+        "a direction and a number of steps",
+    )?;
+    let d = match d {
+        "L" => Dir4::W,
+        "R" => Dir4::E,
+        "U" => Dir4::N,
+        "D" => Dir4::S,
+        _ => return Err(cmd.error(d, "direction 'L', 'R', 'U' or 'D'")),
+    };
+    Ok((d, cmd.parse(n, "a number of steps")?))
+}
+
+/// Moves the head of `rope`, its first knot, one step in `d`, and every other knot towards the
+/// one before it if they are no longer touching.
+pub fn pull_rope(rope: &mut [P], d: Dir4) {
+    rope[0] += d;
+    for i in 0..rope.len() - 1 {
+        let (h, t) = rope.split_at_mut(i + 1);
+        let h = &h[i];
+        let t = &mut t[0];
+        // a covering b counts as adjacent
+        if h.chebyshev(*t) > 1 {
+            *t += (*h - *t).signum();
+        }
+    }
+}
+
+/// The moves of the head of the rope, as a direction and a number of steps.
+pub fn parse(input: &str) -> Result<Vec<(Dir4, i32)>, ParseError> {
+    parse::lines(input).map(|line| parse_move(&line)).collect()
+}
+
+// the number of positions the tail of a rope of `knots` knots visits
+fn tail_visits(moves: &[(Dir4, i32)], knots: usize) -> Answer {
+    let mut rope = vec![P::ORIGIN; knots];
+    let mut visited: HashSet<P> = HashSet::new();
+    for (d, n) in moves {
+        for _ in 0..*n {
+            pull_rope(&mut rope, *d);
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len().into()
+}
+
+pub fn part1(moves: &[(Dir4, i32)]) -> Answer {
+    tail_visits(moves, 2)
+}
+
+pub fn part2(moves: &[(Dir4, i32)]) -> Answer {
+    tail_visits(moves, 10)
+}
+
+pub fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let moves = parse(input)?;
+    Ok((part1(&moves), part2(&moves)))
+}
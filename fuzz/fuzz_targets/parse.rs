@@ -0,0 +1,18 @@
+//! Feeds arbitrary text to the parser of a day, chosen by the first byte. Parsers must reject
+//! what they don't understand with an error, never panic.
+
+#![no_main]
+
+use aoc::days::DAYS;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((day, input)) = data.split_first() else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return;
+    };
+    let day = &DAYS[*day as usize % DAYS.len()];
+    let _ = (day.parse)(input);
+});